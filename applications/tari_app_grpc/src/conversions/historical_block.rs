@@ -28,9 +28,12 @@ impl TryFrom<HistoricalBlock> for grpc::HistoricalBlock {
     type Error = ChainStorageError;
 
     fn try_from(hb: HistoricalBlock) -> Result<Self, Self::Error> {
+        let confirmations = hb.confirmations;
+        let block = hb.try_into_block()?;
         Ok(Self {
-            confirmations: hb.confirmations,
-            block: Some(hb.try_into_block()?.into()),
+            confirmations,
+            weight: block.body.calculate_weight(),
+            block: Some(block.into()),
         })
     }
 }
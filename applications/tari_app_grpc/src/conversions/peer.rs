@@ -21,9 +21,36 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::{conversions::datetime_to_timestamp, tari_rpc as grpc};
-use tari_comms::{connectivity::ConnectivityStatus, net_address::MutliaddrWithStats, peer_manager::Peer};
+use tari_comms::{
+    connection_manager::ConnectionDirection,
+    connectivity::ConnectivityStatus,
+    net_address::MutliaddrWithStats,
+    peer_manager::{Peer, PeerFeatures},
+};
 use tari_crypto::tari_utilities::ByteArray;
 
+impl grpc::ListConnectedPeersResponse {
+    /// Counts the communication nodes and clients among all the connected peers and then, if `features` is given, keeps
+    /// only the peers with exactly those features. Each peer is paired with the direction of its connection.
+    pub fn from_connected_peers(peers: Vec<(Peer, ConnectionDirection)>, features: Option<PeerFeatures>) -> Self {
+        let num_communication_nodes = peers.iter().filter(|(p, _)| p.features.is_node()).count() as u64;
+        let num_communication_clients = peers.iter().filter(|(p, _)| p.features.is_client()).count() as u64;
+        let connected_peers = peers
+            .into_iter()
+            .filter(|(p, _)| features.map(|f| p.features == f).unwrap_or(true))
+            .map(|(peer, direction)| grpc::Peer {
+                direction: grpc::ConnectionDirection::from(direction) as i32,
+                ..peer.into()
+            })
+            .collect();
+        Self {
+            connected_peers,
+            num_communication_nodes,
+            num_communication_clients,
+        }
+    }
+}
+
 impl From<Peer> for grpc::Peer {
     fn from(peer: Peer) -> Self {
         let public_key = peer.public_key.to_vec();
@@ -67,6 +94,16 @@ impl From<Peer> for grpc::Peer {
             last_connected_at,
             supported_protocols,
             user_agent,
+            direction: grpc::ConnectionDirection::Unknown as i32,
+        }
+    }
+}
+
+impl From<ConnectionDirection> for grpc::ConnectionDirection {
+    fn from(direction: ConnectionDirection) -> Self {
+        match direction {
+            ConnectionDirection::Inbound => grpc::ConnectionDirection::Inbound,
+            ConnectionDirection::Outbound => grpc::ConnectionDirection::Outbound,
         }
     }
 }
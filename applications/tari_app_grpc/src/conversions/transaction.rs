@@ -29,6 +29,7 @@ use tari_wallet::{output_manager_service::TxId, transaction_service::storage::mo
 impl From<Transaction> for grpc::Transaction {
     fn from(source: Transaction) -> Self {
         Self {
+            weight: source.calculate_weight(),
             offset: Vec::from(source.offset.as_bytes()),
             body: Some(source.body.into()),
             script_offset: Vec::from(source.script_offset.as_bytes()),
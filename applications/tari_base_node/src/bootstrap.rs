@@ -88,7 +88,13 @@ where B: BlockchainBackend + 'static
             pubsub_connector(runtime::Handle::current(), buf_size, config.buffer_rate_limit_base_node);
         let peer_message_subscriptions = Arc::new(peer_message_subscriptions);
 
-        let node_config = BaseNodeServiceConfig::default(); // TODO - make this configurable
+        // TODO - make the rest of this configurable
+        let node_config = BaseNodeServiceConfig {
+            max_reorg_depth: config.max_reorg_depth,
+            empty_template_on_mempool_error: config.empty_template_on_mempool_error,
+            block_propagation_fanout: config.block_propagation_fanout,
+            ..Default::default()
+        };
         let mempool_config = MempoolServiceConfig::default(); // TODO - make this configurable
 
         let comms_config = self.create_comms_config();
@@ -264,5 +270,6 @@ where B: BlockchainBackend + 'static
             dns_seeds_name_server: self.config.dns_seeds_name_server,
             dns_seeds_use_dnssec: self.config.dns_seeds_use_dnssec,
         }
+        .with_network_test_address_policy()
     }
 }
@@ -41,6 +41,8 @@ use tari_core::{
             TxConsensusValidator,
             TxInputAndMaturityValidator,
             TxInternalConsistencyValidator,
+            TxMinFeePerGramValidator,
+            TxValueRangeValidator,
         },
         DifficultyCalculator,
     },
@@ -237,9 +239,23 @@ async fn build_node_context(
         cleanup_orphans_at_startup,
     )?;
     let mempool_validator = MempoolValidator::new(vec![
-        Box::new(TxInternalConsistencyValidator::new(factories.clone())),
-        Box::new(TxInputAndMaturityValidator::new(blockchain_db.clone())),
-        Box::new(TxConsensusValidator::new(blockchain_db.clone())),
+        (
+            "value_range",
+            Box::new(TxValueRangeValidator::new(rules.clone(), blockchain_db.clone())),
+        ),
+        (
+            "min_fee_per_gram",
+            Box::new(TxMinFeePerGramValidator::new(config.mempool_min_fee_per_gram.into())),
+        ),
+        (
+            "internal_consistency",
+            Box::new(TxInternalConsistencyValidator::new(factories.clone())),
+        ),
+        (
+            "input_and_maturity",
+            Box::new(TxInputAndMaturityValidator::new(blockchain_db.clone())),
+        ),
+        ("consensus", Box::new(TxConsensusValidator::new(blockchain_db.clone()))),
     ]);
     let mempool = Mempool::new(MempoolConfig::default(), Arc::new(mempool_validator));
 
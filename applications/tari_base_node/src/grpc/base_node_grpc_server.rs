@@ -23,38 +23,55 @@ use crate::{
     builder::BaseNodeContext,
     grpc::{
         blocks::{block_fees, block_heights, block_size, GET_BLOCKS_MAX_HEIGHTS, GET_BLOCKS_PAGE_SIZE},
-        helpers::{mean, median},
+        helpers::{ascending_pages, dedup, fee_per_gram, mean, median, std_deviation, TimestampAnomalyDetector},
+        submit_block_cache::SubmitBlockCache,
     },
 };
 use log::*;
 use std::{
     cmp,
     convert::{TryFrom, TryInto},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
 use tari_app_grpc::{
+    conversions::datetime_to_timestamp,
     tari_rpc,
     tari_rpc::{CalcType, Sorting},
 };
 use tari_app_utilities::consts;
-use tari_comms::{Bytes, CommsNode};
+use tari_comms::{
+    peer_manager::{NodeId, Peer, PeerFeatures, PeerQuery},
+    Bytes,
+    CommsNode,
+};
 use tari_core::{
     base_node::{
         comms_interface::{Broadcast, CommsInterfaceError},
-        state_machine_service::states::BlockSyncInfo,
+        state_machine_service::states::{BlockSyncInfo, StateInfo},
         LocalNodeCommsInterface,
         StateMachineHandle,
     },
     blocks::{Block, BlockHeader, NewBlockTemplate},
-    chain_storage::ChainStorageError,
+    chain_storage::{BlockAddResult, ChainStorageError, UtxoStatus},
     consensus::{emission::Emission, ConsensusManager, NetworkConsensus},
     crypto::tari_utilities::{hex::Hex, ByteArray},
     mempool::{service::LocalMempoolService, TxStorageResponse},
     proof_of_work::PowAlgorithm,
-    transactions::{transaction::Transaction, types::Signature},
+    transactions::{
+        transaction::{OutputFlags, Transaction},
+        types::{Commitment, Signature},
+    },
+};
+use tari_crypto::{
+    script::TariScript,
+    tari_utilities::{epoch_time::EpochTime, message_format::MessageFormat, Hashable},
 };
-use tari_crypto::tari_utilities::{message_format::MessageFormat, Hashable};
 use tari_p2p::{auto_update::SoftwareUpdaterHandle, services::liveness::LivenessHandle};
-use tokio::{sync::mpsc, task};
+use tokio::{
+    sync::{broadcast, mpsc, OwnedSemaphorePermit, Semaphore},
+    task,
+};
 use tonic::{Request, Response, Status};
 
 const LOG_TARGET: &str = "tari::base_node::grpc";
@@ -64,15 +81,63 @@ const GET_TOKENS_IN_CIRCULATION_PAGE_SIZE: usize = 1_000;
 // client, so memory is not really a concern here, but a malicious client could request a large
 // number here to keep the node busy
 const GET_DIFFICULTY_MAX_HEIGHTS: usize = 10_000;
+// The default number of difficulty values to request from the base node at a time
 const GET_DIFFICULTY_PAGE_SIZE: usize = 1_000;
+// The maximum number of heights that can be requested from the difficulty curve at a time. If the client requests more
+// than this, this is the maximum that will be returned.
+const GET_DIFFICULTY_CURVE_MAX_HEIGHTS: usize = 10_000;
+// The number of difficulty curve values that are fetched from the base node and buffered for the client at a time
+const GET_DIFFICULTY_CURVE_PAGE_SIZE: usize = 100;
 // The maximum number of headers a client can request at a time. If the client requests more than
 // this, this is the maximum that will be returned.
 const LIST_HEADERS_MAX_NUM_HEADERS: u64 = 10_000;
-// The number of headers to request via the local interface at a time. These are then streamed to
+// The default number of headers to request via the local interface at a time. These are then streamed to
 // client.
 const LIST_HEADERS_PAGE_SIZE: usize = 10;
 // The `num_headers` value if none is provided.
 const LIST_HEADERS_DEFAULT_NUM_HEADERS: u64 = 10;
+// The default maximum number of streaming requests that are served at the same time
+const MAX_CONCURRENT_STREAMS: usize = 100;
+// The number of blocks to scan for unspent outputs at a time when streaming the UTXO set
+const STREAM_UTXO_SET_BLOCK_PAGE_SIZE: u64 = 10;
+// The number of outputs that are buffered for the client when streaming the UTXO set
+const STREAM_UTXO_SET_BUFFER_SIZE: usize = 100;
+// A warning is logged if a UTXO set stream has to scan more than this many outputs
+const STREAM_UTXO_SET_LARGE_SET_WARNING: u64 = 1_000_000;
+// The number of recently submitted blocks for which the result is remembered
+const SUBMIT_BLOCK_CACHE_SIZE: usize = 100;
+// The time for which the result of a submitted block is remembered
+const SUBMIT_BLOCK_CACHE_TTL: Duration = Duration::from_secs(60);
+// The maximum number of peers that GetPeers fetches from the peer database at a time
+const GET_PEERS_PAGE_SIZE: usize = 100;
+// A synced node whose tip is older than this is reported as unhealthy by HealthCheck, since it has most likely stopped
+// receiving blocks
+const HEALTH_CHECK_MAX_TIP_AGE: Duration = Duration::from_secs(30 * 60);
+
+/// Tuning parameters for the streaming methods of the base node gRPC server
+#[derive(Debug, Clone, Copy)]
+pub struct GrpcServerConfig {
+    /// The number of difficulty values to request from the base node at a time
+    pub get_difficulty_page_size: usize,
+    /// The number of headers to request from the base node at a time
+    pub list_headers_page_size: usize,
+    /// The number of blocks to request from the base node at a time
+    pub get_blocks_page_size: usize,
+    /// The maximum number of streaming requests that are served at the same time. Further streaming requests are
+    /// rejected until a stream completes.
+    pub max_concurrent_streams: usize,
+}
+
+impl Default for GrpcServerConfig {
+    fn default() -> Self {
+        Self {
+            get_difficulty_page_size: GET_DIFFICULTY_PAGE_SIZE,
+            list_headers_page_size: LIST_HEADERS_PAGE_SIZE,
+            get_blocks_page_size: GET_BLOCKS_PAGE_SIZE,
+            max_concurrent_streams: MAX_CONCURRENT_STREAMS,
+        }
+    }
+}
 
 pub struct BaseNodeGrpcServer {
     node_service: LocalNodeCommsInterface,
@@ -83,10 +148,15 @@ pub struct BaseNodeGrpcServer {
     software_updater: SoftwareUpdaterHandle,
     comms: CommsNode,
     liveness: LivenessHandle,
+    config: GrpcServerConfig,
+    stream_semaphore: Arc<Semaphore>,
+    submit_block_cache: Arc<Mutex<SubmitBlockCache>>,
+    started_at: EpochTime,
+    start_instant: Instant,
 }
 
 impl BaseNodeGrpcServer {
-    pub fn from_base_node_context(ctx: &BaseNodeContext) -> Self {
+    pub fn from_base_node_context(ctx: &BaseNodeContext, config: GrpcServerConfig) -> Self {
         Self {
             node_service: ctx.local_node(),
             mempool_service: ctx.local_mempool(),
@@ -96,8 +166,24 @@ impl BaseNodeGrpcServer {
             software_updater: ctx.software_updater(),
             comms: ctx.base_node_comms().clone(),
             liveness: ctx.liveness(),
+            config,
+            stream_semaphore: Arc::new(Semaphore::new(config.max_concurrent_streams)),
+            submit_block_cache: Arc::new(Mutex::new(SubmitBlockCache::new(
+                SUBMIT_BLOCK_CACHE_SIZE,
+                SUBMIT_BLOCK_CACHE_TTL,
+            ))),
+            started_at: EpochTime::now(),
+            start_instant: Instant::now(),
         }
     }
+
+    /// Reserves one of the available concurrent streams. The permit must be held until the stream is complete.
+    fn acquire_stream_permit(&self) -> Result<OwnedSemaphorePermit, Status> {
+        self.stream_semaphore.clone().try_acquire_owned().map_err(|_| {
+            warn!(target: LOG_TARGET, "Rejecting GRPC stream request: too many concurrent streams");
+            Status::resource_exhausted("Too many concurrent streams. Try again later.")
+        })
+    }
 }
 
 pub async fn get_heights(
@@ -111,17 +197,23 @@ pub async fn get_heights(
 impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
     type FetchMatchingUtxosStream = mpsc::Receiver<Result<tari_rpc::FetchMatchingUtxosResponse, Status>>;
     type GetBlocksStream = mpsc::Receiver<Result<tari_rpc::HistoricalBlock, Status>>;
+    type GetDifficultyCurveStream = mpsc::Receiver<Result<tari_rpc::DifficultyCurveResponse, Status>>;
+    type GetLatestBlocksStream = mpsc::Receiver<Result<tari_rpc::HistoricalBlock, Status>>;
     type GetMempoolTransactionsStream = mpsc::Receiver<Result<tari_rpc::GetMempoolTransactionsResponse, Status>>;
     type GetNetworkDifficultyStream = mpsc::Receiver<Result<tari_rpc::NetworkDifficultyResponse, Status>>;
+    type GetOrphanBlocksStream = mpsc::Receiver<Result<tari_rpc::OrphanBlockResponse, Status>>;
     type GetPeersStream = mpsc::Receiver<Result<tari_rpc::GetPeersResponse, Status>>;
     type GetTokensInCirculationStream = mpsc::Receiver<Result<tari_rpc::ValueAtHeightResponse, Status>>;
     type ListHeadersStream = mpsc::Receiver<Result<tari_rpc::BlockHeader, Status>>;
     type SearchKernelsStream = mpsc::Receiver<Result<tari_rpc::HistoricalBlock, Status>>;
+    type StreamConnectivityStatusStream = mpsc::Receiver<Result<tari_rpc::ConnectivityStatusUpdate, Status>>;
+    type StreamUtxoSetStream = mpsc::Receiver<Result<tari_rpc::FetchMatchingUtxosResponse, Status>>;
 
     async fn get_network_difficulty(
         &self,
         request: Request<tari_rpc::HeightRequest>,
     ) -> Result<Response<Self::GetNetworkDifficultyStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
         let request = request.into_inner();
         debug!(
             target: LOG_TARGET,
@@ -135,14 +227,17 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         heights = heights
             .drain(..cmp::min(heights.len(), GET_DIFFICULTY_MAX_HEIGHTS))
             .collect();
+        heights = dedup(heights);
+        let page_size = self.config.get_difficulty_page_size;
         let (mut tx, rx) = mpsc::channel(GET_DIFFICULTY_MAX_HEIGHTS);
 
         task::spawn(async move {
-            let mut page: Vec<u64> = heights
-                .drain(..cmp::min(heights.len(), GET_DIFFICULTY_PAGE_SIZE))
-                .collect();
-            while !page.is_empty() {
-                let mut difficulties = match handler.get_headers(page.clone()).await {
+            let _permit = permit;
+            // Pages run from the lowest to the highest height, so that the detector can be kept across pages and
+            // anomalies at page boundaries are detected too
+            let mut anomaly_detector = TimestampAnomalyDetector::default();
+            for page in ascending_pages(heights, page_size) {
+                let difficulties = match handler.get_headers(page).await {
                     Err(err) => {
                         warn!(
                             target: LOG_TARGET,
@@ -183,12 +278,14 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
                                             } else {
                                                 0
                                             };
+                                            let timestamp_anomaly = anomaly_detector.check(pow_algo, current_timestamp);
                                             result.push((
                                                 current_difficulty,
                                                 estimated_hash_rate,
                                                 current_height,
                                                 current_timestamp,
                                                 pow_algo,
+                                                timestamp_anomaly,
                                             ))
                                         },
                                         None => {
@@ -202,40 +299,30 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
                     },
                 };
 
-                difficulties.sort_by(|a, b| b.2.cmp(&a.2));
                 let result_size = difficulties.len();
                 for difficulty in difficulties {
-                    match tx
-                        .send(Ok({
+                    if !send_to_stream(
+                        &mut tx,
+                        Ok({
                             tari_rpc::NetworkDifficultyResponse {
                                 difficulty: difficulty.0,
                                 estimated_hash_rate: difficulty.1,
                                 height: difficulty.2,
                                 timestamp: difficulty.3,
                                 pow_algo: difficulty.4,
+                                timestamp_anomaly: difficulty.5,
                             }
-                        }))
-                        .await
+                        }),
+                        "difficulty",
+                    )
+                    .await
                     {
-                        Ok(_) => (),
-                        Err(err) => {
-                            warn!(target: LOG_TARGET, "Error sending difficulty via GRPC:  {}", err);
-                            match tx.send(Err(Status::unknown("Error sending data"))).await {
-                                Ok(_) => (),
-                                Err(send_err) => {
-                                    warn!(target: LOG_TARGET, "Error sending error to GRPC client: {}", send_err)
-                                },
-                            }
-                            return;
-                        },
+                        return;
                     }
                 }
-                if result_size < GET_DIFFICULTY_PAGE_SIZE {
+                if result_size < page_size {
                     break;
                 }
-                page = heights
-                    .drain(..cmp::min(heights.len(), GET_DIFFICULTY_PAGE_SIZE))
-                    .collect();
             }
         });
 
@@ -250,13 +337,19 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         &self,
         request: Request<tari_rpc::GetMempoolTransactionsRequest>,
     ) -> Result<Response<Self::GetMempoolTransactionsStream>, Status> {
-        let _request = request.into_inner();
-        debug!(target: LOG_TARGET, "Incoming GRPC request for GetMempoolTransactions",);
+        let permit = self.acquire_stream_permit()?;
+        let request = request.into_inner();
+        debug!(
+            target: LOG_TARGET,
+            "Incoming GRPC request for GetMempoolTransactions: min_fee_per_gram: {}", request.min_fee_per_gram
+        );
+        let min_fee_per_gram = request.min_fee_per_gram;
 
         let mut mempool = self.mempool_service.clone();
         let (mut tx, rx) = mpsc::channel(1000);
 
         task::spawn(async move {
+            let _permit = permit;
             let transactions = match mempool.get_mempool_state().await {
                 Err(err) => {
                     warn!(target: LOG_TARGET, "Error communicating with base node: {}", err,);
@@ -265,30 +358,149 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
                 Ok(data) => data,
             };
             for transaction in transactions.unconfirmed_pool {
-                match tx
-                    .send(Ok(tari_rpc::GetMempoolTransactionsResponse {
+                if min_fee_per_gram > 0 {
+                    let fee = transaction.body.get_total_fee().as_u64();
+                    match fee_per_gram(fee, transaction.calculate_weight()) {
+                        Some(rate) if rate >= min_fee_per_gram => {},
+                        _ => continue,
+                    }
+                }
+                if !send_to_stream(
+                    &mut tx,
+                    Ok(tari_rpc::GetMempoolTransactionsResponse {
                         transaction: Some(transaction.into()),
-                    }))
-                    .await
+                    }),
+                    "mempool transaction",
+                )
+                .await
                 {
-                    Ok(_) => (),
+                    return;
+                }
+            }
+        });
+        debug!(target: LOG_TARGET, "Sending GetMempool response stream to client");
+        Ok(Response::new(rx))
+    }
+
+    async fn get_orphan_blocks(
+        &self,
+        _request: Request<tari_rpc::Empty>,
+    ) -> Result<Response<Self::GetOrphanBlocksStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
+        debug!(target: LOG_TARGET, "Incoming GRPC request for GetOrphanBlocks");
+
+        let mut handler = self.node_service.clone();
+        let headers = handler
+            .get_orphan_headers()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+        let (mut tx, rx) = mpsc::channel(cmp::max(headers.len(), 1));
+
+        task::spawn(async move {
+            let _permit = permit;
+            for header in headers {
+                if !send_to_stream(
+                    &mut tx,
+                    Ok(tari_rpc::OrphanBlockResponse {
+                        block_hash: header.hash(),
+                        height: header.height,
+                        prev_hash: header.prev_hash,
+                    }),
+                    "orphan block",
+                )
+                .await
+                {
+                    return;
+                }
+            }
+        });
+        debug!(target: LOG_TARGET, "Sending GetOrphanBlocks response stream to client");
+        Ok(Response::new(rx))
+    }
+
+    async fn get_difficulty_curve(
+        &self,
+        request: Request<tari_rpc::DifficultyCurveRequest>,
+    ) -> Result<Response<Self::GetDifficultyCurveStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
+        let request = request.into_inner();
+        debug!(
+            target: LOG_TARGET,
+            "Incoming GRPC request for GetDifficultyCurve: start_height: {}, end_height: {}, sorting: {}",
+            request.start_height,
+            request.end_height,
+            request.sorting
+        );
+
+        let mut handler = self.node_service.clone();
+        let tip = handler
+            .get_metadata()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .height_of_longest_chain();
+        let end_height = match request.end_height {
+            0 => tip,
+            end_height => cmp::min(end_height, tip),
+        };
+        if request.start_height > end_height {
+            return Err(Status::invalid_argument(format!(
+                "Start height {} is greater than end height {}",
+                request.start_height, end_height
+            )));
+        }
+
+        let num_heights = cmp::min(
+            end_height - request.start_height + 1,
+            GET_DIFFICULTY_CURVE_MAX_HEIGHTS as u64,
+        );
+        let is_descending = matches!(request.sorting(), Sorting::Desc);
+        let (first_height, last_height) = if is_descending {
+            (end_height + 1 - num_heights, end_height)
+        } else {
+            (request.start_height, request.start_height + num_heights - 1)
+        };
+        let mut pages: Vec<(u64, u64)> = (first_height..=last_height)
+            .step_by(GET_DIFFICULTY_CURVE_PAGE_SIZE)
+            .map(|start| {
+                let end = cmp::min(start + GET_DIFFICULTY_CURVE_PAGE_SIZE as u64 - 1, last_height);
+                (start, end)
+            })
+            .collect();
+        if is_descending {
+            pages.reverse();
+        }
+        let (mut tx, rx) = mpsc::channel(GET_DIFFICULTY_CURVE_PAGE_SIZE);
+
+        task::spawn(async move {
+            let _permit = permit;
+            for (start, end) in pages {
+                let mut difficulties = match handler.get_accumulated_difficulties(start, end).await {
+                    Ok(difficulties) => difficulties,
                     Err(err) => {
-                        warn!(
-                            target: LOG_TARGET,
-                            "Error sending mempool transaction via GRPC:  {}", err
-                        );
-                        match tx.send(Err(Status::unknown("Error sending data"))).await {
-                            Ok(_) => (),
-                            Err(send_err) => {
-                                warn!(target: LOG_TARGET, "Error sending error to GRPC client: {}", send_err)
-                            },
-                        }
+                        warn!(target: LOG_TARGET, "Error communicating with base node: {}", err);
+                        let _ = tx.send(Err(Status::internal(err.to_string()))).await;
                         return;
                     },
+                };
+                if is_descending {
+                    difficulties.reverse();
+                }
+                for (height, monero, sha) in difficulties {
+                    let total = monero.as_u64() as u128 * sha.as_u64() as u128;
+                    let response = tari_rpc::DifficultyCurveResponse {
+                        height,
+                        sha_accumulated_difficulty: sha.as_u64(),
+                        monero_accumulated_difficulty: monero.as_u64(),
+                        total_accumulated_difficulty: total.to_be_bytes().to_vec(),
+                    };
+                    if !send_to_stream(&mut tx, Ok(response), "difficulty curve").await {
+                        return;
+                    }
                 }
             }
         });
-        debug!(target: LOG_TARGET, "Sending GetMempool response stream to client");
+
+        debug!(target: LOG_TARGET, "Sending GetDifficultyCurve response stream to client");
         Ok(Response::new(rx))
     }
 
@@ -296,6 +508,7 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         &self,
         request: Request<tari_rpc::ListHeadersRequest>,
     ) -> Result<Response<Self::ListHeadersStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
         let request = request.into_inner();
         debug!(
             target: LOG_TARGET,
@@ -321,7 +534,8 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         };
 
         let num_headers = cmp::min(num_headers, LIST_HEADERS_MAX_NUM_HEADERS);
-        let (mut tx, rx) = mpsc::channel(LIST_HEADERS_PAGE_SIZE);
+        let page_size = self.config.list_headers_page_size;
+        let (mut tx, rx) = mpsc::channel(page_size);
 
         let headers: Vec<u64> = if request.from_height != 0 {
             match sorting {
@@ -341,12 +555,11 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         };
 
         task::spawn(async move {
+            let _permit = permit;
             trace!(target: LOG_TARGET, "Starting base node request");
             let mut headers = headers;
             trace!(target: LOG_TARGET, "Headers:{:?}", headers);
-            let mut page: Vec<u64> = headers
-                .drain(..cmp::min(headers.len(), LIST_HEADERS_PAGE_SIZE))
-                .collect();
+            let mut page: Vec<u64> = headers.drain(..cmp::min(headers.len(), page_size)).collect();
             while !page.is_empty() {
                 trace!(target: LOG_TARGET, "Page: {:?}", page);
                 let result_headers = match handler.get_headers(page).await {
@@ -361,26 +574,14 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
 
                 for header in result_headers {
                     trace!(target: LOG_TARGET, "Sending block header: {}", header.height);
-                    match tx.send(Ok(header.into())).await {
-                        Ok(_) => (),
-                        Err(err) => {
-                            warn!(target: LOG_TARGET, "Error sending block header via GRPC:  {}", err);
-                            match tx.send(Err(Status::unknown("Error sending data"))).await {
-                                Ok(_) => (),
-                                Err(send_err) => {
-                                    warn!(target: LOG_TARGET, "Error sending error to GRPC client: {}", send_err)
-                                },
-                            }
-                            return;
-                        },
+                    if !send_to_stream(&mut tx, Ok(header.into()), "block header").await {
+                        return;
                     }
                 }
-                if result_size < LIST_HEADERS_PAGE_SIZE {
+                if result_size < page_size {
                     break;
                 }
-                page = headers
-                    .drain(..cmp::min(headers.len(), LIST_HEADERS_PAGE_SIZE))
-                    .collect();
+                page = headers.drain(..cmp::min(headers.len(), page_size)).collect();
             }
         });
 
@@ -400,10 +601,16 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
             .pow_algo as u64)
             .try_into()
             .map_err(|_| Status::invalid_argument("No valid pow algo selected".to_string()))?;
+        let exclude_kernel_sigs = request
+            .exclude_kernel_sigs
+            .into_iter()
+            .map(Signature::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Status::invalid_argument(format!("Invalid excluded kernel signature: {}", e)))?;
         let mut handler = self.node_service.clone();
 
-        let new_template = handler
-            .get_new_block_template(algo, request.max_weight)
+        let (new_template, from_cache) = handler
+            .get_new_block_template_excluding(algo, request.max_weight, exclude_kernel_sigs)
             .await
             .map_err(|e| {
                 warn!(
@@ -422,10 +629,13 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
                 target_difficulty: new_template.target_difficulty.as_u64(),
                 total_fees: new_template.total_fees.into(),
                 algo: Some(tari_rpc::PowAlgo { pow_algo: pow }),
+                num_kernels: new_template.body.kernels().len() as u64,
+                total_weight: new_template.body.calculate_weight(),
             }),
             new_block_template: Some(new_template.into()),
 
             initial_sync_achieved: (*status_watch.borrow()).bootstrapped,
+            from_cache,
         };
 
         debug!(target: LOG_TARGET, "Sending GetNewBlockTemplate response to client");
@@ -443,19 +653,8 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
             .map_err(|s| Status::invalid_argument(format!("Invalid block template: {}", s)))?;
 
         let mut handler = self.node_service.clone();
+        let new_block = build_new_block(&mut handler, block_template).await?;
 
-        let new_block = match handler.get_new_block(block_template).await {
-            Ok(b) => b,
-            Err(CommsInterfaceError::ChainStorageError(ChainStorageError::CannotCalculateNonTipMmr(msg))) => {
-                let status = Status::with_details(
-                    tonic::Code::FailedPrecondition,
-                    msg,
-                    Bytes::from_static(b"CannotCalculateNonTipMmr"),
-                );
-                return Err(status);
-            },
-            Err(e) => return Err(Status::internal(e.to_string())),
-        };
         // construct response
         let block_hash = new_block.hash();
         let mining_hash = new_block.header.merged_mining_hash();
@@ -470,6 +669,41 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         Ok(Response::new(response))
     }
 
+    async fn get_new_block_blob(
+        &self,
+        request: Request<tari_rpc::NewBlockTemplate>,
+    ) -> Result<Response<tari_rpc::GetNewBlockBlobResult>, Status> {
+        let request = request.into_inner();
+        debug!(target: LOG_TARGET, "Incoming GRPC request for get new block blob");
+        let block_template: NewBlockTemplate = request
+            .try_into()
+            .map_err(|s| Status::invalid_argument(format!("Invalid block template: {}", s)))?;
+
+        let mut handler = self.node_service.clone();
+        let new_block = build_new_block(&mut handler, block_template).await?;
+
+        // construct response
+        let block_hash = new_block.hash();
+        let merge_mining_hash = new_block.header.merged_mining_hash();
+        let header = new_block
+            .header
+            .to_binary()
+            .map_err(|e| Status::internal(format!("Could not serialize block header: {}", e)))?;
+        let block_body = new_block
+            .body
+            .to_binary()
+            .map_err(|e| Status::internal(format!("Could not serialize block body: {}", e)))?;
+
+        let response = tari_rpc::GetNewBlockBlobResult {
+            block_hash,
+            header,
+            block_body,
+            merge_mining_hash,
+        };
+        debug!(target: LOG_TARGET, "Sending GetNewBlockBlob response to client");
+        Ok(Response::new(response))
+    }
+
     async fn submit_block(
         &self,
         request: Request<tari_rpc::Block>,
@@ -483,17 +717,57 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
             "Received SubmitBlock #{} request from client", block_height
         );
 
+        let submitted_hash = block.hash();
+        let cached = self
+            .submit_block_cache
+            .lock()
+            .expect("submit block cache lock poisoned")
+            .get(&submitted_hash);
+        if let Some(result) = cached {
+            debug!(
+                target: LOG_TARGET,
+                "Block #{} ({}) was recently submitted. Returning cached result.",
+                block_height,
+                submitted_hash.to_hex()
+            );
+            return result.map(Response::new).map_err(Status::internal);
+        }
+
         let mut handler = self.node_service.clone();
-        let block_hash = handler
-            .submit_block(block, Broadcast::from(true))
-            .await
-            .map_err(|e| Status::internal(e.to_string()))?;
+        let (block_hash, block_add_result) = match handler.submit_block(block, Broadcast::from(true)).await {
+            Ok(r) => r,
+            Err(err) => {
+                // Only validation failures are remembered, other errors may be transient
+                if let CommsInterfaceError::ChainStorageError(ChainStorageError::ValidationError { .. }) = err {
+                    self.submit_block_cache
+                        .lock()
+                        .expect("submit block cache lock poisoned")
+                        .insert(submitted_hash, Err(err.to_string()));
+                }
+                return Err(Status::internal(err.to_string()));
+            },
+        };
+        let (add_result, reorg_depth) = match block_add_result {
+            BlockAddResult::Ok(_) => (tari_rpc::BlockAddResult::BlockAdded, 0),
+            BlockAddResult::ChainReorg { removed, .. } => (tari_rpc::BlockAddResult::BlockReorg, removed.len() as u64),
+            BlockAddResult::OrphanBlock => (tari_rpc::BlockAddResult::BlockOrphan, 0),
+            BlockAddResult::BlockExists => (tari_rpc::BlockAddResult::BlockExists, 0),
+        };
 
         debug!(
             target: LOG_TARGET,
             "Sending SubmitBlock #{} response to client", block_height
         );
-        Ok(Response::new(tari_rpc::SubmitBlockResponse { block_hash }))
+        let response = tari_rpc::SubmitBlockResponse {
+            block_hash,
+            add_result: add_result as i32,
+            reorg_depth,
+        };
+        self.submit_block_cache
+            .lock()
+            .expect("submit block cache lock poisoned")
+            .insert(submitted_hash, Ok(response.clone()));
+        Ok(Response::new(response))
     }
 
     async fn submit_transaction(
@@ -607,33 +881,44 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
 
     async fn get_peers(
         &self,
-        _request: Request<tari_rpc::GetPeersRequest>,
+        request: Request<tari_rpc::GetPeersRequest>,
     ) -> Result<Response<Self::GetPeersStream>, Status> {
-        debug!(target: LOG_TARGET, "Incoming GRPC request for get all peers");
+        let permit = self.acquire_stream_permit()?;
+        let request = request.into_inner();
+        debug!(target: LOG_TARGET, "Incoming GRPC request for get all peers: {:?}", request);
+        let mut skip = usize::try_from(request.offset)
+            .map_err(|_| Status::invalid_argument(format!("Invalid offset: {}", request.offset)))?;
 
-        let peers = self
-            .comms
-            .peer_manager()
-            .all()
-            .await
-            .map_err(|e| Status::unknown(e.to_string()))?;
-        let peers: Vec<tari_rpc::Peer> = peers.into_iter().map(|p| p.into()).collect();
-        let (mut tx, rx) = mpsc::channel(peers.len());
+        let peer_manager = self.comms.peer_manager();
+        let (mut tx, rx) = mpsc::channel(GET_PEERS_PAGE_SIZE);
         task::spawn(async move {
-            for peer in peers {
-                let response = tari_rpc::GetPeersResponse { peer: Some(peer) };
-                match tx.send(Ok(response)).await {
-                    Ok(_) => (),
+            let _permit = permit;
+            let mut after = None;
+            loop {
+                let peers = match peer_manager.fetch_page(after, GET_PEERS_PAGE_SIZE).await {
+                    Ok(peers) => peers,
                     Err(err) => {
-                        warn!(target: LOG_TARGET, "Error sending peer via GRPC:  {}", err);
-                        match tx.send(Err(Status::unknown("Error sending data"))).await {
-                            Ok(_) => (),
-                            Err(send_err) => {
-                                warn!(target: LOG_TARGET, "Error sending error to GRPC client: {}", send_err)
-                            },
-                        }
+                        warn!(target: LOG_TARGET, "Error fetching peers: {:?}", err);
+                        let _ = tx.send(Err(Status::unknown(err.to_string()))).await;
                         return;
                     },
+                };
+                let num_peers = peers.len();
+                after = peers.last().map(Peer::id);
+                for peer in peers {
+                    if skip > 0 {
+                        skip -= 1;
+                        continue;
+                    }
+                    let response = tari_rpc::GetPeersResponse {
+                        peer: Some(peer.into()),
+                    };
+                    if !send_to_stream(&mut tx, Ok(response), "peer").await {
+                        return;
+                    }
+                }
+                if num_peers < GET_PEERS_PAGE_SIZE {
+                    break;
                 }
             }
         });
@@ -642,10 +927,96 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         Ok(Response::new(rx))
     }
 
+    async fn stream_utxo_set(
+        &self,
+        request: Request<tari_rpc::StreamUtxoSetRequest>,
+    ) -> Result<Response<Self::StreamUtxoSetStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
+        let request = request.into_inner();
+        debug!(target: LOG_TARGET, "Incoming GRPC request for StreamUtxoSet: {:?}", request);
+        let feature_flags = u8::try_from(request.feature_flags)
+            .ok()
+            .and_then(OutputFlags::from_bits)
+            .ok_or_else(|| Status::invalid_argument(format!("Invalid feature flags: {}", request.feature_flags)))?;
+        let non_default_script_only = request.non_default_script_only;
+
+        let mut handler = self.node_service.clone();
+        let tip_height = handler
+            .get_metadata()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .height_of_longest_chain();
+        let tip_header = handler
+            .get_headers(vec![tip_height])
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .pop()
+            .ok_or_else(|| Status::internal("Tip header not found"))?;
+        if tip_header.output_mmr_size > STREAM_UTXO_SET_LARGE_SET_WARNING {
+            warn!(
+                target: LOG_TARGET,
+                "StreamUtxoSet will scan {} outputs up to height {}. This may take a while.",
+                tip_header.output_mmr_size,
+                tip_height
+            );
+        }
+
+        let (mut tx, rx) = mpsc::channel(STREAM_UTXO_SET_BUFFER_SIZE);
+        task::spawn(async move {
+            let _permit = permit;
+            let mut start = 0;
+            while start <= tip_height {
+                let end = cmp::min(start + STREAM_UTXO_SET_BLOCK_PAGE_SIZE - 1, tip_height);
+                let blocks = match handler.get_blocks_in_range(start, end).await {
+                    Ok(blocks) => blocks,
+                    Err(err) => {
+                        warn!(target: LOG_TARGET, "Error communicating with local base node: {:?}", err);
+                        let _ = tx.send(Err(Status::internal(err.to_string()))).await;
+                        return;
+                    },
+                };
+                start = end + 1;
+
+                let hashes = blocks
+                    .iter()
+                    .flat_map(|block| block.block().body.outputs().iter().map(|output| output.hash()))
+                    .collect::<Vec<_>>();
+                // Only unspent outputs are returned
+                let outputs = match handler.fetch_matching_utxos(hashes).await {
+                    Ok(outputs) => outputs,
+                    Err(err) => {
+                        warn!(target: LOG_TARGET, "Error communicating with local base node: {:?}", err);
+                        let _ = tx.send(Err(Status::internal(err.to_string()))).await;
+                        return;
+                    },
+                };
+
+                for output in outputs {
+                    if !output.features.flags.contains(feature_flags) {
+                        continue;
+                    }
+                    if non_default_script_only && output.script == TariScript::default() {
+                        continue;
+                    }
+                    let response = tari_rpc::FetchMatchingUtxosResponse {
+                        output: Some(output.into()),
+                    };
+                    if !send_to_stream(&mut tx, Ok(response), "unspent output").await {
+                        return;
+                    }
+                }
+            }
+        });
+
+        debug!(target: LOG_TARGET, "Sending StreamUtxoSet response stream to client");
+        Ok(Response::new(rx))
+    }
+
     async fn get_blocks(
         &self,
         request: Request<tari_rpc::GetBlocksRequest>,
     ) -> Result<Response<Self::GetBlocksStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
         let request = request.into_inner();
         debug!(
             target: LOG_TARGET,
@@ -655,57 +1026,51 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         heights = heights
             .drain(..cmp::min(heights.len(), GET_BLOCKS_MAX_HEIGHTS))
             .collect();
+        heights = dedup(heights);
 
-        let mut handler = self.node_service.clone();
-        let (mut tx, rx) = mpsc::channel(GET_BLOCKS_PAGE_SIZE);
+        let handler = self.node_service.clone();
+        let page_size = self.config.get_blocks_page_size;
+        let (tx, rx) = mpsc::channel(page_size);
         task::spawn(async move {
-            let mut page: Vec<u64> = heights.drain(..cmp::min(heights.len(), GET_BLOCKS_PAGE_SIZE)).collect();
-
-            while !page.is_empty() {
-                let blocks = match handler.get_blocks(page.clone()).await {
-                    Err(err) => {
-                        warn!(
-                            target: LOG_TARGET,
-                            "Error communicating with local base node: {:?}", err,
-                        );
-                        return;
-                    },
-                    Ok(data) => data,
-                };
-                let result_size = blocks.len();
-                for block in blocks {
-                    match tx
-                        .send(
-                            block
-                                .try_into()
-                                .map_err(|err| Status::internal(format!("Could not provide block: {}", err))),
-                        )
-                        .await
-                    {
-                        Ok(_) => (),
-                        Err(err) => {
-                            warn!(target: LOG_TARGET, "Error sending header via GRPC:  {}", err);
-                            match tx.send(Err(Status::unknown("Error sending data"))).await {
-                                Ok(_) => (),
-                                Err(send_err) => {
-                                    warn!(target: LOG_TARGET, "Error sending error to GRPC client: {}", send_err)
-                                },
-                            }
-                            return;
-                        },
-                    }
-                }
-                if result_size < GET_BLOCKS_PAGE_SIZE {
-                    break;
-                }
-                page = heights.drain(..cmp::min(heights.len(), GET_BLOCKS_PAGE_SIZE)).collect();
-            }
+            let _permit = permit;
+            stream_blocks(handler, heights, page_size, tx).await;
         });
 
         debug!(target: LOG_TARGET, "Sending GetBlocks response stream to client");
         Ok(Response::new(rx))
     }
 
+    async fn get_latest_blocks(
+        &self,
+        request: Request<tari_rpc::GetLatestBlocksRequest>,
+    ) -> Result<Response<Self::GetLatestBlocksStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
+        let request = request.into_inner();
+        debug!(
+            target: LOG_TARGET,
+            "Incoming GRPC request for GetLatestBlocks: {}", request.count
+        );
+        let mut handler = self.node_service.clone();
+        let tip = handler
+            .get_metadata()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .height_of_longest_chain();
+        let count = cmp::min(request.count, GET_BLOCKS_MAX_HEIGHTS as u64);
+        // The genesis block is included, so the chain has tip + 1 blocks
+        let heights = (0..=tip).rev().take(count as usize).collect::<Vec<_>>();
+
+        let page_size = self.config.get_blocks_page_size;
+        let (tx, rx) = mpsc::channel(page_size);
+        task::spawn(async move {
+            let _permit = permit;
+            stream_blocks(handler, heights, page_size, tx).await;
+        });
+
+        debug!(target: LOG_TARGET, "Sending GetLatestBlocks response stream to client");
+        Ok(Response::new(rx))
+    }
+
     async fn get_tip_info(
         &self,
         _request: Request<tari_rpc::Empty>,
@@ -730,10 +1095,29 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         Ok(Response::new(response))
     }
 
+    async fn get_tip_hash(
+        &self,
+        _request: Request<tari_rpc::Empty>,
+    ) -> Result<Response<tari_rpc::TipHashResponse>, Status> {
+        trace!(target: LOG_TARGET, "Incoming GRPC request for BN tip hash");
+
+        let mut handler = self.node_service.clone();
+        let meta = handler
+            .get_metadata()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(Response::new(tari_rpc::TipHashResponse {
+            height: meta.height_of_longest_chain(),
+            best_block_hash: meta.best_block().clone(),
+        }))
+    }
+
     async fn search_kernels(
         &self,
         request: Request<tari_rpc::SearchKernelsRequest>,
     ) -> Result<Response<Self::SearchKernelsStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
         debug!(target: LOG_TARGET, "Incoming GRPC request for SearchKernels");
         let request = request.into_inner();
 
@@ -742,8 +1126,10 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
 
         let mut handler = self.node_service.clone();
 
-        let (mut tx, rx) = mpsc::channel(GET_BLOCKS_PAGE_SIZE);
+        let page_size = self.config.get_blocks_page_size;
+        let (mut tx, rx) = mpsc::channel(page_size);
         task::spawn(async move {
+            let _permit = permit;
             let blocks = match handler.get_blocks_with_kernels(kernels).await {
                 Err(err) => {
                     warn!(
@@ -755,25 +1141,16 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
                 Ok(data) => data,
             };
             for block in blocks {
-                match tx
-                    .send(
-                        block
-                            .try_into()
-                            .map_err(|err| Status::internal(format!("Could not provide block:{}", err))),
-                    )
-                    .await
+                if !send_to_stream(
+                    &mut tx,
+                    block
+                        .try_into()
+                        .map_err(|err| Status::internal(format!("Could not provide block:{}", err))),
+                    "block",
+                )
+                .await
                 {
-                    Ok(_) => (),
-                    Err(err) => {
-                        warn!(target: LOG_TARGET, "Error sending header via GRPC:  {}", err);
-                        match tx.send(Err(Status::unknown("Error sending data"))).await {
-                            Ok(_) => (),
-                            Err(send_err) => {
-                                warn!(target: LOG_TARGET, "Error sending error to GRPC client: {}", send_err)
-                            },
-                        }
-                        return;
-                    },
+                    return;
                 }
             }
         });
@@ -787,6 +1164,7 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         &self,
         request: Request<tari_rpc::FetchMatchingUtxosRequest>,
     ) -> Result<Response<Self::FetchMatchingUtxosStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
         debug!(target: LOG_TARGET, "Incoming GRPC request for FetchMatchingUtxos");
         let request = request.into_inner();
 
@@ -795,8 +1173,10 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
 
         let mut handler = self.node_service.clone();
 
-        let (mut tx, rx) = mpsc::channel(GET_BLOCKS_PAGE_SIZE);
+        let page_size = self.config.get_blocks_page_size;
+        let (mut tx, rx) = mpsc::channel(page_size);
         task::spawn(async move {
+            let _permit = permit;
             let outputs = match handler.fetch_matching_utxos(hashes).await {
                 Err(err) => {
                     warn!(
@@ -808,24 +1188,16 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
                 Ok(data) => data,
             };
             for output in outputs {
-                match tx
-                    .send(Ok(tari_rpc::FetchMatchingUtxosResponse {
+                if !send_to_stream(
+                    &mut tx,
+                    Ok(tari_rpc::FetchMatchingUtxosResponse {
                         output: Some(output.into()),
-                    }))
-                    .await
+                    }),
+                    "output",
+                )
+                .await
                 {
-                    Ok(_) => (),
-                    Err(err) => {
-                        warn!(target: LOG_TARGET, "Error sending output via GRPC:  {}", err);
-
-                        match tx.send(Err(Status::unknown("Error sending data"))).await {
-                            Ok(_) => (),
-                            Err(send_err) => {
-                                warn!(target: LOG_TARGET, "Error sending error to GRPC client: {}", send_err)
-                            },
-                        }
-                        return;
-                    },
+                    return;
                 }
             }
         });
@@ -837,6 +1209,34 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         Ok(Response::new(rx))
     }
 
+    async fn is_utxo_unspent(
+        &self,
+        request: Request<tari_rpc::IsUtxoUnspentRequest>,
+    ) -> Result<Response<tari_rpc::IsUtxoUnspentResponse>, Status> {
+        let request = request.into_inner();
+        let commitment = Commitment::from_bytes(&request.commitment)
+            .map_err(|_| Status::invalid_argument("commitment could not be converted".to_string()))?;
+        debug!(
+            target: LOG_TARGET,
+            "Received IsUtxoUnspent request from client ({})",
+            commitment.to_hex()
+        );
+
+        let mut handler = self.node_service.clone();
+        let status = handler.fetch_utxo_status(commitment).await.map_err(|e| {
+            error!(target: LOG_TARGET, "Error fetching UTXO status: {}", e);
+            Status::internal(e.to_string())
+        })?;
+        let status = match status {
+            UtxoStatus::Unspent => tari_rpc::UtxoStatus::Unspent,
+            UtxoStatus::Spent => tari_rpc::UtxoStatus::Spent,
+            UtxoStatus::Unknown => tari_rpc::UtxoStatus::Unknown,
+        };
+
+        debug!(target: LOG_TARGET, "Sending IsUtxoUnspent response to client");
+        Ok(Response::new(tari_rpc::IsUtxoUnspentResponse { status: status.into() }))
+    }
+
     // deprecated
     async fn get_calc_timing(
         &self,
@@ -877,8 +1277,17 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
             },
         };
         let (max, min, avg) = BlockHeader::timing_stats(&headers);
-
-        let response = tari_rpc::BlockTimingResponse { max, min, avg };
+        let intervals = BlockHeader::timing_intervals(&headers);
+        let median = median(intervals.clone()).unwrap_or(0.0);
+        let stddev = std_deviation(intervals).unwrap_or(0.0);
+
+        let response = tari_rpc::BlockTimingResponse {
+            max,
+            min,
+            avg,
+            median,
+            stddev,
+        };
         debug!(target: LOG_TARGET, "Sending GetBlockTiming response to client");
         Ok(Response::new(response))
     }
@@ -913,6 +1322,66 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         Ok(Response::new(consts::APP_VERSION.to_string().into()))
     }
 
+    async fn health_check(
+        &self,
+        _request: Request<tari_rpc::Empty>,
+    ) -> Result<Response<tari_rpc::HealthCheckResponse>, Status> {
+        debug!(target: LOG_TARGET, "Incoming GRPC request for BN health check");
+
+        let status_info = self.state_machine_handle.get_status_info_watch().borrow().clone();
+        let mut handler = self.node_service.clone();
+        let meta = handler
+            .get_metadata()
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let local_height = meta.height_of_longest_chain();
+
+        let response = match status_info.state_info {
+            StateInfo::HeaderSync(info) | StateInfo::BlockSync(info) => tari_rpc::HealthCheckResponse {
+                status: tari_rpc::HealthStatus::Syncing.into(),
+                local_height: info.local_height,
+                tip_height: info.tip_height,
+            },
+            StateInfo::HorizonSync(info) => tari_rpc::HealthCheckResponse {
+                status: tari_rpc::HealthStatus::Syncing.into(),
+                local_height,
+                tip_height: info.horizon_sync_height,
+            },
+            StateInfo::Listening(_) if status_info.bootstrapped => {
+                let headers = handler
+                    .get_headers(vec![local_height])
+                    .await
+                    .map_err(|e| Status::internal(e.to_string()))?;
+                let tip_age = headers
+                    .first()
+                    .map(|header| EpochTime::now().as_u64().saturating_sub(header.timestamp.as_u64()))
+                    .unwrap_or(u64::MAX);
+                let status = if tip_age > HEALTH_CHECK_MAX_TIP_AGE.as_secs() {
+                    warn!(
+                        target: LOG_TARGET,
+                        "Health check: no new block for {}s at height {}", tip_age, local_height
+                    );
+                    tari_rpc::HealthStatus::Unhealthy
+                } else {
+                    tari_rpc::HealthStatus::Ready
+                };
+                tari_rpc::HealthCheckResponse {
+                    status: status.into(),
+                    local_height,
+                    tip_height: 0,
+                }
+            },
+            StateInfo::StartUp | StateInfo::Listening(_) => tari_rpc::HealthCheckResponse {
+                status: tari_rpc::HealthStatus::Starting.into(),
+                local_height,
+                tip_height: 0,
+            },
+        };
+
+        debug!(target: LOG_TARGET, "Sending HealthCheck response to client");
+        Ok(Response::new(response))
+    }
+
     async fn check_for_updates(
         &self,
         _request: Request<tari_rpc::Empty>,
@@ -933,6 +1402,7 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         &self,
         request: Request<tari_rpc::GetBlocksRequest>,
     ) -> Result<Response<Self::GetTokensInCirculationStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
         debug!(target: LOG_TARGET, "Incoming GRPC request for GetTokensInCirculation",);
         let request = request.into_inner();
         let mut heights = request.heights;
@@ -943,6 +1413,7 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
 
         let (mut tx, rx) = mpsc::channel(GET_TOKENS_IN_CIRCULATION_PAGE_SIZE);
         task::spawn(async move {
+            let _permit = permit;
             let mut page: Vec<u64> = heights
                 .drain(..cmp::min(heights.len(), GET_TOKENS_IN_CIRCULATION_PAGE_SIZE))
                 .collect();
@@ -962,18 +1433,8 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
                     .collect();
                 let result_size = values.len();
                 for value in values {
-                    match tx.send(Ok(value)).await {
-                        Ok(_) => (),
-                        Err(err) => {
-                            warn!(target: LOG_TARGET, "Error sending value via GRPC:  {}", err);
-                            match tx.send(Err(Status::unknown("Error sending data"))).await {
-                                Ok(_) => (),
-                                Err(send_err) => {
-                                    warn!(target: LOG_TARGET, "Error sending error to GRPC client: {}", send_err)
-                                },
-                            }
-                            return;
-                        },
+                    if !send_to_stream(&mut tx, Ok(value), "value").await {
+                        return;
                     }
                 }
                 if result_size < GET_TOKENS_IN_CIRCULATION_PAGE_SIZE {
@@ -1057,12 +1518,45 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         }
     }
 
+    async fn get_utxo_merkle_root(
+        &self,
+        request: Request<tari_rpc::UtxoMerkleRootRequest>,
+    ) -> Result<Response<tari_rpc::UtxoMerkleRootResponse>, Status> {
+        let tari_rpc::UtxoMerkleRootRequest { height } = request.into_inner();
+        debug!(
+            target: LOG_TARGET,
+            "Incoming GRPC request for GetUtxoMerkleRoot at height {}", height
+        );
+        let mut node_service = self.node_service.clone();
+        let header = match node_service.get_headers(vec![height]).await {
+            Ok(mut headers) if !headers.is_empty() => headers.remove(0),
+            Ok(_) | Err(CommsInterfaceError::BlockHeaderNotFound(_)) => {
+                return Err(Status::not_found(format!("Header not found at height `{}`", height)));
+            },
+            Err(err) => return Err(Status::internal(err.to_string())),
+        };
+
+        let response = tari_rpc::UtxoMerkleRootResponse {
+            height: header.height,
+            block_hash: header.hash(),
+            output_mr: header.output_mr,
+            kernel_mr: header.kernel_mr,
+            witness_mr: header.witness_mr,
+            output_mmr_size: header.output_mmr_size,
+            kernel_mmr_size: header.kernel_mmr_size,
+        };
+        debug!(target: LOG_TARGET, "Sending GetUtxoMerkleRoot response to client");
+        Ok(Response::new(response))
+    }
+
     async fn identify(&self, _: Request<tari_rpc::Empty>) -> Result<Response<tari_rpc::NodeIdentity>, Status> {
         let identity = self.comms.node_identity_ref();
         Ok(Response::new(tari_rpc::NodeIdentity {
             public_key: identity.public_key().to_vec(),
             public_address: identity.public_address().to_string(),
             node_id: identity.node_id().to_vec(),
+            uptime_seconds: self.start_instant.elapsed().as_secs(),
+            started_at: Some(datetime_to_timestamp(self.started_at)),
         }))
     }
 
@@ -1080,23 +1574,82 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
         let latency = self
             .liveness
             .clone()
-            .get_network_avg_latency()
+            .get_network_latency_stats()
             .await
             .map_err(|err| Status::internal(err.to_string()))?;
 
         let resp = tari_rpc::NetworkStatusResponse {
             status: tari_rpc::ConnectivityStatus::from(status) as i32,
-            avg_latency_ms: latency.unwrap_or_default(),
+            avg_latency_ms: latency.avg_ms,
             num_node_connections: status.num_connected_nodes() as u32,
+            min_latency_ms: latency.min_ms,
+            max_latency_ms: latency.max_ms,
         };
 
         Ok(Response::new(resp))
     }
 
-    async fn list_connected_peers(
+    async fn stream_connectivity_status(
         &self,
         _: Request<tari_rpc::Empty>,
+    ) -> Result<Response<Self::StreamConnectivityStatusStream>, Status> {
+        let permit = self.acquire_stream_permit()?;
+        debug!(target: LOG_TARGET, "Incoming GRPC request for StreamConnectivityStatus");
+        let mut connectivity = self.comms.connectivity();
+        // Subscribe before taking the first snapshot so that no change is missed
+        let mut event_stream = connectivity.get_event_subscription();
+        let (mut tx, rx) = mpsc::channel(100);
+
+        task::spawn(async move {
+            let _permit = permit;
+            loop {
+                let status = match connectivity.get_connectivity_status().await {
+                    Ok(status) => status,
+                    Err(err) => {
+                        warn!(target: LOG_TARGET, "Error getting connectivity status: {}", err);
+                        let _ = tx.send(Err(Status::internal(err.to_string()))).await;
+                        return;
+                    },
+                };
+                let num_node_connections = status.num_connected_nodes() as u32;
+                let update = tari_rpc::ConnectivityStatusUpdate {
+                    status: tari_rpc::ConnectivityStatus::from(status) as i32,
+                    num_node_connections,
+                };
+                if !send_to_stream(&mut tx, Ok(update), "connectivity status").await {
+                    return;
+                }
+
+                match event_stream.recv().await {
+                    Ok(_) => {},
+                    // The next snapshot reflects the latest status, so the missed events do not need to be replayed
+                    Err(broadcast::RecvError::Lagged(n)) => {
+                        debug!(target: LOG_TARGET, "Connectivity status stream lagged by {} event(s)", n);
+                    },
+                    Err(broadcast::RecvError::Closed) => {
+                        debug!(target: LOG_TARGET, "Connectivity event stream closed");
+                        return;
+                    },
+                }
+            }
+        });
+
+        Ok(Response::new(rx))
+    }
+
+    async fn list_connected_peers(
+        &self,
+        request: Request<tari_rpc::ListConnectedPeersRequest>,
     ) -> Result<Response<tari_rpc::ListConnectedPeersResponse>, Status> {
+        let request = request.into_inner();
+        let features = if request.filter_by_features {
+            let features = PeerFeatures::from_bits(request.features)
+                .ok_or_else(|| Status::invalid_argument(format!("Invalid peer features: {}", request.features)))?;
+            Some(features)
+        } else {
+            None
+        };
+
         let mut connectivity = self.comms.connectivity();
         let peer_manager = self.comms.peer_manager();
         let connected_peers = connectivity
@@ -1105,21 +1658,173 @@ impl tari_rpc::base_node_server::BaseNode for BaseNodeGrpcServer {
             .map_err(|err| Status::internal(err.to_string()))?;
 
         let mut peers = Vec::with_capacity(connected_peers.len());
-        for peer in connected_peers {
-            peers.push(
-                peer_manager
-                    .find_by_node_id(peer.peer_node_id())
-                    .await
-                    .map_err(|err| Status::internal(err.to_string()))?,
-            );
+        for conn in connected_peers {
+            let direction = conn.direction();
+            let peer = peer_manager
+                .find_by_node_id(conn.peer_node_id())
+                .await
+                .map_err(|err| Status::internal(err.to_string()))?;
+            peers.push((peer, direction));
         }
 
-        let resp = tari_rpc::ListConnectedPeersResponse {
-            connected_peers: peers.into_iter().map(Into::into).collect(),
+        let resp = tari_rpc::ListConnectedPeersResponse::from_connected_peers(peers, features);
+
+        Ok(Response::new(resp))
+    }
+
+    async fn list_banned_peers(
+        &self,
+        _: Request<tari_rpc::Empty>,
+    ) -> Result<Response<tari_rpc::ListBannedPeersResponse>, Status> {
+        let query = PeerQuery::new().select_where(|p| p.is_banned());
+        let banned_peers = self
+            .comms
+            .peer_manager()
+            .perform_query(query)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+
+        let resp = tari_rpc::ListBannedPeersResponse {
+            banned_peers: banned_peers.into_iter().map(Into::into).collect(),
         };
 
         Ok(Response::new(resp))
     }
+
+    async fn ban_peer(&self, request: Request<tari_rpc::BanPeerRequest>) -> Result<Response<tari_rpc::Empty>, Status> {
+        let request = request.into_inner();
+        let node_id = parse_node_id(&request.node_id)?;
+        if &node_id == self.comms.node_identity_ref().node_id() {
+            return Err(Status::invalid_argument("Cannot ban our own node"));
+        }
+        let duration = match request.duration_seconds {
+            0 => Duration::from_secs(u64::MAX),
+            secs => Duration::from_secs(secs),
+        };
+        let reason = if request.reason.is_empty() {
+            "gRPC manual ban".to_string()
+        } else {
+            request.reason
+        };
+        info!(
+            target: LOG_TARGET,
+            "Banning peer {} for {:?} via gRPC: {}", node_id, duration, reason
+        );
+
+        self.comms
+            .connectivity()
+            .ban_peer_until(node_id, duration, reason)
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?;
+
+        Ok(Response::new(tari_rpc::Empty {}))
+    }
+
+    async fn unban_peer(
+        &self,
+        request: Request<tari_rpc::UnbanPeerRequest>,
+    ) -> Result<Response<tari_rpc::Empty>, Status> {
+        let node_id = parse_node_id(&request.into_inner().node_id)?;
+        info!(target: LOG_TARGET, "Unbanning peer {} via gRPC", node_id);
+
+        match self.comms.peer_manager().unban_peer(&node_id).await {
+            Ok(_) => Ok(Response::new(tari_rpc::Empty {})),
+            Err(err) if err.is_peer_not_found() => Err(Status::not_found(format!("Peer {} not found", node_id))),
+            Err(err) => Err(Status::internal(err.to_string())),
+        }
+    }
+}
+
+async fn build_new_block(
+    handler: &mut LocalNodeCommsInterface,
+    block_template: NewBlockTemplate,
+) -> Result<Block, Status> {
+    match handler.get_new_block(block_template).await {
+        Ok(b) => Ok(b),
+        Err(CommsInterfaceError::ChainStorageError(ChainStorageError::CannotCalculateNonTipMmr(msg))) => {
+            Err(Status::with_details(
+                tonic::Code::FailedPrecondition,
+                msg,
+                Bytes::from_static(b"CannotCalculateNonTipMmr"),
+            ))
+        },
+        Err(e) => Err(Status::internal(e.to_string())),
+    }
+}
+
+/// Fetches the blocks at the given heights from the base node in pages and streams them to the client in the same
+/// order as the heights
+async fn stream_blocks(
+    mut handler: LocalNodeCommsInterface,
+    mut heights: Vec<u64>,
+    page_size: usize,
+    mut tx: mpsc::Sender<Result<tari_rpc::HistoricalBlock, Status>>,
+) {
+    let mut page: Vec<u64> = heights.drain(..cmp::min(heights.len(), page_size)).collect();
+
+    while !page.is_empty() {
+        let blocks = match handler.get_blocks(page.clone()).await {
+            Err(err) => {
+                warn!(
+                    target: LOG_TARGET,
+                    "Error communicating with local base node: {:?}", err,
+                );
+                return;
+            },
+            Ok(data) => data,
+        };
+        let result_size = blocks.len();
+        for block in blocks {
+            if !send_to_stream(
+                &mut tx,
+                block
+                    .try_into()
+                    .map_err(|err| Status::internal(format!("Could not provide block: {}", err))),
+                "block",
+            )
+            .await
+            {
+                return;
+            }
+        }
+        if result_size < page_size {
+            break;
+        }
+        page = heights.drain(..cmp::min(heights.len(), page_size)).collect();
+    }
+}
+
+/// Sends an item to a streaming GRPC client. Returns false if the client has disconnected, in which case the stream
+/// should be stopped. Client disconnects are expected, so these are only logged at debug level.
+async fn send_to_stream<T>(
+    tx: &mut mpsc::Sender<Result<T, Status>>,
+    item: Result<T, Status>,
+    description: &str,
+) -> bool {
+    match tx.send(item).await {
+        Ok(_) => true,
+        Err(_) => {
+            debug!(
+                target: LOG_TARGET,
+                "GRPC client disconnected while sending {}. Closing stream.", description
+            );
+            false
+        },
+    }
+}
+
+fn parse_node_id(bytes: &[u8]) -> Result<NodeId, Status> {
+    let node_id =
+        NodeId::from_bytes(bytes).map_err(|err| Status::invalid_argument(format!("Invalid node id: {}", err)))?;
+    // NodeId::from_bytes ignores any trailing bytes
+    if node_id.as_bytes().len() != bytes.len() {
+        return Err(Status::invalid_argument(format!(
+            "Invalid node id: expected {} bytes but got {}",
+            node_id.as_bytes().len(),
+            bytes.len()
+        )));
+    }
+    Ok(node_id)
 }
 
 enum BlockGroupType {
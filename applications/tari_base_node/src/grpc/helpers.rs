@@ -20,6 +20,8 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::collections::{HashMap, HashSet};
+
 pub fn median(mut list: Vec<u64>) -> Option<f64> {
     if list.is_empty() {
         return None;
@@ -43,8 +45,61 @@ pub fn mean(list: Vec<u64>) -> Option<f64> {
     Some(total as f64 / count as f64)
 }
 
+/// Removes duplicate values from the list, keeping the first occurrence of each value in its original order
+pub fn dedup(list: Vec<u64>) -> Vec<u64> {
+    let mut seen = HashSet::with_capacity(list.len());
+    list.into_iter().filter(|value| seen.insert(*value)).collect()
+}
+
+/// Returns the population standard deviation of the values in the list
+pub fn std_deviation(list: Vec<u64>) -> Option<f64> {
+    let count = list.len();
+    let mean = mean(list.clone())?;
+    let variance = list
+        .into_iter()
+        .map(|value| {
+            let diff = value as f64 - mean;
+            diff * diff
+        })
+        .sum::<f64>() /
+        count as f64;
+    Some(variance.sqrt())
+}
+
+/// Returns the fee paid per gram of weight, rounded down, or None if the weight is zero
+pub fn fee_per_gram(total_fee: u64, weight: u64) -> Option<u64> {
+    if weight == 0 {
+        return None;
+    }
+    Some(total_fee / weight)
+}
+
+/// Sorts the heights from the lowest to the highest and splits them into pages of at most `page_size` heights
+pub fn ascending_pages(mut heights: Vec<u64>, page_size: usize) -> Vec<Vec<u64>> {
+    heights.sort_unstable();
+    heights.chunks(page_size).map(|page| page.to_vec()).collect()
+}
+
+/// Detects blocks whose timestamp is not greater than the timestamp of the previous block mined with the same PoW
+/// algorithm. Blocks must be checked from the lowest to the highest height.
+#[derive(Debug, Default)]
+pub struct TimestampAnomalyDetector {
+    last_timestamps: HashMap<u64, u64>,
+}
+
+impl TimestampAnomalyDetector {
+    /// Returns true if the block is out of order with the previously checked block of the same PoW algorithm
+    pub fn check(&mut self, pow_algo: u64, timestamp: u64) -> bool {
+        self.last_timestamps
+            .insert(pow_algo, timestamp)
+            .map(|last_timestamp| timestamp <= last_timestamp)
+            .unwrap_or(false)
+    }
+}
+
 #[cfg(test)]
 pub mod test {
+    use super::TimestampAnomalyDetector;
 
     #[test]
     fn median() {
@@ -59,4 +114,53 @@ pub mod test {
         let mean_value = super::mean(values);
         assert_eq!(mean_value, Some(5.25f64))
     }
+
+    #[test]
+    fn dedup() {
+        assert_eq!(super::dedup(vec![5u64, 5u64, 5u64]), vec![5u64]);
+        assert_eq!(super::dedup(vec![3u64, 1u64, 3u64, 2u64, 1u64]), vec![3u64, 1u64, 2u64]);
+        assert!(super::dedup(vec![]).is_empty());
+    }
+
+    #[test]
+    fn std_deviation() {
+        let values = vec![2u64, 4u64, 4u64, 4u64, 5u64, 5u64, 7u64, 9u64];
+        let std_deviation_value = super::std_deviation(values);
+        assert_eq!(std_deviation_value, Some(2f64));
+        assert_eq!(super::std_deviation(vec![]), None);
+    }
+
+    #[test]
+    fn fee_per_gram() {
+        assert_eq!(super::fee_per_gram(100, 30), Some(3));
+        assert_eq!(super::fee_per_gram(0, 30), Some(0));
+        assert_eq!(super::fee_per_gram(100, 0), None);
+    }
+
+    #[test]
+    fn ascending_pages() {
+        // Ranges are requested from the tip down
+        let heights = (1u64..=7).rev().collect::<Vec<_>>();
+        assert_eq!(super::ascending_pages(heights, 3), vec![
+            vec![1u64, 2, 3],
+            vec![4u64, 5, 6],
+            vec![7u64]
+        ]);
+        assert!(super::ascending_pages(vec![], 3).is_empty());
+    }
+
+    #[test]
+    fn timestamp_anomalies_across_pages() {
+        let heights = (1u64..=10).rev().collect::<Vec<_>>();
+        let mut detector = TimestampAnomalyDetector::default();
+        // Ordered timestamps are never flagged, including the first block of each PoW algorithm on every page
+        for page in super::ascending_pages(heights, 3) {
+            for height in page {
+                assert!(!detector.check(height % 2, height * 60));
+            }
+        }
+        assert!(detector.check(0, 10 * 60));
+        assert!(detector.check(1, 8 * 60));
+        assert!(!detector.check(1, 11 * 60));
+    }
 }
@@ -0,0 +1,135 @@
+// Copyright 2021, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+use tari_app_grpc::tari_rpc;
+
+/// The outcome of a previous `submit_block` call. Rejections hold the error message returned to the client.
+pub type SubmitBlockResult = Result<tari_rpc::SubmitBlockResponse, String>;
+
+/// A small LRU cache of recently submitted block hashes and the result that was returned for them. This allows
+/// duplicate submissions (e.g. from a mining pool resubmitting the same solution) to be answered without running full
+/// block validation again.
+///
+/// Entries are keyed by the full block hash, so blocks that merely share a hash prefix never collide. Entries expire
+/// after `ttl`, because some rejections (e.g. a timestamp too far in the future) may not hold indefinitely.
+pub struct SubmitBlockCache {
+    entries: VecDeque<(Vec<u8>, Instant, SubmitBlockResult)>,
+    capacity: usize,
+    ttl: Duration,
+}
+
+impl SubmitBlockCache {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity,
+            ttl,
+        }
+    }
+
+    /// Returns the cached result for the given block hash, if one exists and has not expired. A hit marks the entry as
+    /// most recently used.
+    pub fn get(&mut self, block_hash: &[u8]) -> Option<SubmitBlockResult> {
+        let ttl = self.ttl;
+        self.entries.retain(|(_, inserted_at, _)| inserted_at.elapsed() < ttl);
+        let pos = self
+            .entries
+            .iter()
+            .position(|(hash, _, _)| hash.as_slice() == block_hash)?;
+        let entry = self.entries.remove(pos)?;
+        let result = entry.2.clone();
+        self.entries.push_back(entry);
+        Some(result)
+    }
+
+    /// Records the result for the given block hash, evicting the least recently used entry if the cache is full
+    pub fn insert(&mut self, block_hash: Vec<u8>, result: SubmitBlockResult) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries.retain(|(hash, _, _)| *hash != block_hash);
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((block_hash, Instant::now(), result));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn response(block_hash: &[u8]) -> SubmitBlockResult {
+        Ok(tari_rpc::SubmitBlockResponse {
+            block_hash: block_hash.to_vec(),
+            add_result: tari_rpc::BlockAddResult::BlockAdded as i32,
+            reorg_depth: 0,
+        })
+    }
+
+    #[test]
+    fn it_returns_cached_results() {
+        let mut cache = SubmitBlockCache::new(10, Duration::from_secs(60));
+        cache.insert(vec![1; 32], response(&[1; 32]));
+        cache.insert(vec![2; 32], Err("invalid block".to_string()));
+
+        assert_eq!(cache.get(&[1; 32]), Some(response(&[1; 32])));
+        assert_eq!(cache.get(&[2; 32]), Some(Err("invalid block".to_string())));
+        assert!(cache.get(&[3; 32]).is_none());
+    }
+
+    #[test]
+    fn it_does_not_match_on_prefix() {
+        let mut cache = SubmitBlockCache::new(10, Duration::from_secs(60));
+        let mut hash = vec![1; 32];
+        cache.insert(hash.clone(), response(&hash));
+
+        hash[31] = 2;
+        assert!(cache.get(&hash).is_none());
+        assert!(cache.get(&hash[..16]).is_none());
+    }
+
+    #[test]
+    fn it_evicts_the_least_recently_used_entry() {
+        let mut cache = SubmitBlockCache::new(2, Duration::from_secs(60));
+        cache.insert(vec![1; 32], response(&[1; 32]));
+        cache.insert(vec![2; 32], response(&[2; 32]));
+        // Touch the first entry so that the second becomes the least recently used
+        assert!(cache.get(&[1; 32]).is_some());
+        cache.insert(vec![3; 32], response(&[3; 32]));
+
+        assert!(cache.get(&[1; 32]).is_some());
+        assert!(cache.get(&[2; 32]).is_none());
+        assert!(cache.get(&[3; 32]).is_some());
+    }
+
+    #[test]
+    fn it_expires_entries() {
+        let mut cache = SubmitBlockCache::new(10, Duration::from_millis(0));
+        cache.insert(vec![1; 32], response(&[1; 32]));
+        assert!(cache.get(&[1; 32]).is_none());
+    }
+}
@@ -95,7 +95,10 @@ mod recovery;
 mod status_line;
 mod utils;
 
-use crate::command_handler::CommandHandler;
+use crate::{
+    command_handler::CommandHandler,
+    grpc::base_node_grpc_server::{BaseNodeGrpcServer, GrpcServerConfig},
+};
 use futures::{pin_mut, FutureExt};
 use log::*;
 use parser::Parser;
@@ -216,7 +219,22 @@ async fn run_node(node_config: Arc<GlobalConfig>, bootstrap: ConfigBootstrap) ->
 
     if node_config.grpc_enabled {
         // Go, GRPC, go go
-        let grpc = crate::grpc::base_node_grpc_server::BaseNodeGrpcServer::from_base_node_context(&ctx);
+        let default_grpc_config = GrpcServerConfig::default();
+        let grpc_config = GrpcServerConfig {
+            get_difficulty_page_size: node_config
+                .grpc_get_difficulty_page_size
+                .unwrap_or(default_grpc_config.get_difficulty_page_size),
+            list_headers_page_size: node_config
+                .grpc_list_headers_page_size
+                .unwrap_or(default_grpc_config.list_headers_page_size),
+            get_blocks_page_size: node_config
+                .grpc_get_blocks_page_size
+                .unwrap_or(default_grpc_config.get_blocks_page_size),
+            max_concurrent_streams: node_config
+                .grpc_max_concurrent_streams
+                .unwrap_or(default_grpc_config.max_concurrent_streams),
+        };
+        let grpc = BaseNodeGrpcServer::from_base_node_context(&ctx, grpc_config);
         task::spawn(run_grpc(grpc, node_config.grpc_base_node_address, shutdown.to_signal()));
     }
 
@@ -244,7 +262,7 @@ async fn run_node(node_config: Arc<GlobalConfig>, bootstrap: ConfigBootstrap) ->
 
 /// Runs the gRPC server
 async fn run_grpc(
-    grpc: crate::grpc::base_node_grpc_server::BaseNodeGrpcServer,
+    grpc: BaseNodeGrpcServer,
     grpc_address: SocketAddr,
     interrupt_signal: ShutdownSignal,
 ) -> Result<(), anyhow::Error> {
@@ -25,14 +25,16 @@ use crate::automation::{commands::WalletCommand, error::ParseError};
 use chrono::{DateTime, Utc};
 use chrono_english::{parse_date_string, Dialect};
 use core::str::SplitWhitespace;
+use futures::future::Either;
 use std::{
     fmt::{Display, Formatter},
     str::FromStr,
 };
-use tari_app_utilities::utilities::parse_emoji_id_or_public_key;
-use tari_comms::multiaddr::Multiaddr;
+use tari_app_utilities::utilities::{parse_emoji_id_or_public_key, parse_emoji_id_or_public_key_or_node_id};
+use tari_comms::{multiaddr::Multiaddr, peer_manager::NodeId};
 
 use tari_core::transactions::{tari_amount::MicroTari, types::PublicKey};
+use tari_crypto::range_proof::REWIND_USER_MESSAGE_LENGTH;
 
 #[derive(Debug)]
 pub struct ParsedCommand {
@@ -53,10 +55,15 @@ impl Display for ParsedCommand {
             Whois => "whois",
             ExportUtxos => "export-utxos",
             ExportSpentUtxos => "export-spent-utxos",
+            ImportUtxos => "import-utxos",
             CountUtxos => "count-utxos",
             SetBaseNode => "set-base-node",
             SetCustomBaseNode => "set-custom-base-node",
             ClearCustomBaseNode => "clear-custom-base-node",
+            CancelAllPending => "cancel-all-pending",
+            PrunePendingInbound => "prune-pending-inbound",
+            ShowTransaction => "show-transaction",
+            Status => "status",
         };
 
         let args = self
@@ -74,6 +81,7 @@ impl Display for ParsedCommand {
 pub enum ParsedArgument {
     Amount(MicroTari),
     PublicKey(PublicKey),
+    NodeId(NodeId),
     Text(String),
     Float(f64),
     Int(u64),
@@ -82,6 +90,7 @@ pub enum ParsedArgument {
     CSVFileName(String),
     Address(Multiaddr),
     Negotiated(bool),
+    PaymentId(String),
 }
 
 impl Display for ParsedArgument {
@@ -90,6 +99,7 @@ impl Display for ParsedArgument {
         match self {
             Amount(v) => write!(f, "{}", v.to_string()),
             PublicKey(v) => write!(f, "{}", v.to_string()),
+            ParsedArgument::NodeId(v) => write!(f, "{}", v.to_string()),
             Text(v) => write!(f, "{}", v.to_string()),
             Float(v) => write!(f, "{}", v.to_string()),
             Int(v) => write!(f, "{}", v.to_string()),
@@ -98,6 +108,7 @@ impl Display for ParsedArgument {
             CSVFileName(v) => write!(f, "{}", v.to_string()),
             Address(v) => write!(f, "{}", v.to_string()),
             Negotiated(v) => write!(f, "{}", v.to_string()),
+            PaymentId(v) => write!(f, "--payment-id {}", v),
         }
     }
 }
@@ -113,17 +124,22 @@ pub fn parse_command(command: &str) -> Result<ParsedCommand, ParseError> {
     let args = match command {
         GetBalance => Vec::new(),
         SendTari => parse_send_tari(args)?,
-        SendOneSided => parse_send_tari(args)?,
+        SendOneSided => parse_send_one_sided(args)?,
         MakeItRain => parse_make_it_rain(args)?,
         CoinSplit => parse_coin_split(args)?,
-        DiscoverPeer => parse_public_key(args)?,
+        DiscoverPeer => parse_public_key_or_node_id(args)?,
         Whois => parse_whois(args)?,
         ExportUtxos => parse_export_utxos(args)?, // todo: only show X number of utxos
         ExportSpentUtxos => parse_export_spent_utxos(args)?, // todo: only show X number of utxos
+        ImportUtxos => parse_import_utxos(args)?,
         CountUtxos => Vec::new(),
         SetBaseNode => parse_public_key_and_address(args)?,
         SetCustomBaseNode => parse_public_key_and_address(args)?,
         ClearCustomBaseNode => Vec::new(),
+        CancelAllPending => Vec::new(),
+        PrunePendingInbound => parse_prune_pending_inbound(args)?,
+        ShowTransaction => parse_show_transaction(args)?,
+        Status => Vec::new(),
     };
 
     Ok(ParsedCommand { command, args })
@@ -155,6 +171,21 @@ fn parse_public_key(mut args: SplitWhitespace) -> Result<Vec<ParsedArgument>, Pa
     Ok(parsed_args)
 }
 
+fn parse_public_key_or_node_id(mut args: SplitWhitespace) -> Result<Vec<ParsedArgument>, ParseError> {
+    let mut parsed_args = Vec::new();
+
+    // public key/emoji id/node id
+    let dest = args
+        .next()
+        .ok_or_else(|| ParseError::Empty("public key, emoji id or node id".to_string()))?;
+    match parse_emoji_id_or_public_key_or_node_id(dest).ok_or(ParseError::PublicKeyOrNodeId)? {
+        Either::Left(pubkey) => parsed_args.push(ParsedArgument::PublicKey(pubkey)),
+        Either::Right(node_id) => parsed_args.push(ParsedArgument::NodeId(node_id)),
+    }
+
+    Ok(parsed_args)
+}
+
 fn parse_public_key_and_address(mut args: SplitWhitespace) -> Result<Vec<ParsedArgument>, ParseError> {
     let mut parsed_args = Vec::new();
 
@@ -275,6 +306,56 @@ fn parse_send_tari(mut args: SplitWhitespace) -> Result<Vec<ParsedArgument>, Par
     Ok(parsed_args)
 }
 
+/// Parses `<amount> <public key> [--payment-id <id>] <message>`. The payment id, if given, is appended as the last
+/// argument.
+fn parse_send_one_sided(args: SplitWhitespace) -> Result<Vec<ParsedArgument>, ParseError> {
+    let mut tokens = args.collect::<Vec<&str>>();
+    let mut payment_id = None;
+    if tokens.get(2) == Some(&"--payment-id") {
+        let id = tokens
+            .get(3)
+            .ok_or_else(|| ParseError::Empty("payment id".to_string()))?
+            .to_string();
+        if id.len() > REWIND_USER_MESSAGE_LENGTH {
+            return Err(ParseError::Invalid(format!(
+                "The payment id may be at most {} bytes long",
+                REWIND_USER_MESSAGE_LENGTH
+            )));
+        }
+        payment_id = Some(id);
+        tokens.drain(2..4);
+    }
+
+    let mut parsed_args = parse_send_tari(tokens.join(" ").split_whitespace())?;
+    if let Some(id) = payment_id {
+        parsed_args.push(ParsedArgument::PaymentId(id));
+    }
+
+    Ok(parsed_args)
+}
+
+fn parse_prune_pending_inbound(mut args: SplitWhitespace) -> Result<Vec<ParsedArgument>, ParseError> {
+    // The age is required so that slow, but recent, transactions are never pruned by accident
+    let age = args.next().ok_or_else(|| {
+        ParseError::Empty("age in hours\n  Usage:\n    prune-pending-inbound <age in hours>".to_string())
+    })?;
+    let age = age.parse::<u64>().map_err(ParseError::Int)?;
+    if age == 0 {
+        return Err(ParseError::Invalid("The age must be at least 1 hour".to_string()));
+    }
+
+    Ok(vec![ParsedArgument::Int(age)])
+}
+
+fn parse_show_transaction(mut args: SplitWhitespace) -> Result<Vec<ParsedArgument>, ParseError> {
+    let tx_id = args
+        .next()
+        .ok_or_else(|| ParseError::Empty("transaction id\n  Usage:\n    show-transaction <tx_id>".to_string()))?;
+    let tx_id = tx_id.parse::<u64>().map_err(ParseError::Int)?;
+
+    Ok(vec![ParsedArgument::Int(tx_id)])
+}
+
 fn parse_export_utxos(mut args: SplitWhitespace) -> Result<Vec<ParsedArgument>, ParseError> {
     let mut parsed_args = Vec::new();
 
@@ -298,6 +379,14 @@ fn parse_export_utxos(mut args: SplitWhitespace) -> Result<Vec<ParsedArgument>,
     Ok(parsed_args)
 }
 
+fn parse_import_utxos(mut args: SplitWhitespace) -> Result<Vec<ParsedArgument>, ParseError> {
+    let file_name = args
+        .next()
+        .ok_or_else(|| ParseError::Empty("file name\n  Usage:\n    import-utxos <file name>".to_string()))?;
+
+    Ok(vec![ParsedArgument::CSVFileName(file_name.to_string())])
+}
+
 fn parse_export_spent_utxos(mut args: SplitWhitespace) -> Result<Vec<ParsedArgument>, ParseError> {
     let mut parsed_args = Vec::new();
 
@@ -348,6 +437,7 @@ mod test {
     };
     use rand::rngs::OsRng;
     use std::str::FromStr;
+    use tari_comms::peer_manager::NodeId;
     use tari_core::transactions::{tari_amount::MicroTari, types::PublicKey};
     use tari_crypto::keys::PublicKey as PublicKeyTrait;
 
@@ -408,6 +498,26 @@ mod test {
             panic!("Parsed MicroTari amount not the same as provided.");
         }
 
+        let command_str = format!("send-one-sided 999T {} --payment-id invoice-42 msg text", public_key);
+        let parsed = parse_command(&command_str).unwrap();
+
+        if let ParsedArgument::Text(msg) = parsed.args[2].clone() {
+            assert_eq!(msg, "msg text");
+        } else {
+            panic!("Parsed message is not the same as provided.");
+        }
+        if let ParsedArgument::PaymentId(id) = parsed.args[3].clone() {
+            assert_eq!(id, "invoice-42");
+        } else {
+            panic!("Parsed payment id is not the same as provided.");
+        }
+
+        let command_str = format!("send-one-sided 999T {} --payment-id {} msg", public_key, "x".repeat(22));
+        match parse_command(&command_str) {
+            Err(ParseError::Invalid(_)) => {},
+            _ => panic!("Expected an overly long payment id to be rejected"),
+        }
+
         let command_str = format!("discover-peer {}", public_key);
         let parsed = parse_command(&command_str).unwrap();
 
@@ -417,6 +527,20 @@ mod test {
             panic!("Parsed public key is not the same as provided.");
         }
 
+        let node_id = NodeId::from_public_key(&public_key);
+        let command_str = format!("discover-peer {}", node_id);
+        let parsed = parse_command(&command_str).unwrap();
+
+        if let ParsedArgument::NodeId(n) = parsed.args[0].clone() {
+            assert_eq!(n, node_id);
+        } else {
+            panic!("Parsed node id is not the same as provided.");
+        }
+
+        let command_str = "discover-peer not-a-node-id";
+        let parsed = parse_command(command_str);
+        assert!(matches!(parsed, Err(ParseError::PublicKeyOrNodeId)));
+
         let command_str = "export-utxos --csv-file utxo_list.csv".to_string();
         let parsed = parse_command(&command_str).unwrap();
 
@@ -426,6 +550,53 @@ mod test {
             panic!("Parsed csv file name is not the same as provided.");
         }
 
+        let command_str = "prune-pending-inbound";
+        let parsed = parse_command(command_str);
+        assert!(parsed.is_err());
+
+        let command_str = "prune-pending-inbound 0";
+        let parsed = parse_command(command_str);
+        assert!(parsed.is_err());
+
+        let command_str = "prune-pending-inbound 48";
+        let parsed = parse_command(command_str).unwrap();
+
+        if let ParsedArgument::Int(age) = parsed.args[0].clone() {
+            assert_eq!(age, 48);
+        } else {
+            panic!("Parsed age is not the same as provided.");
+        }
+
+        let command_str = "show-transaction";
+        let parsed = parse_command(command_str);
+        assert!(parsed.is_err());
+
+        let command_str = "show-transaction abc";
+        let parsed = parse_command(command_str);
+        assert!(parsed.is_err());
+
+        let command_str = "show-transaction 1234";
+        let parsed = parse_command(command_str).unwrap();
+
+        if let ParsedArgument::Int(tx_id) = parsed.args[0].clone() {
+            assert_eq!(tx_id, 1234);
+        } else {
+            panic!("Parsed transaction id is not the same as provided.");
+        }
+
+        let command_str = "import-utxos";
+        let parsed = parse_command(command_str);
+        assert!(parsed.is_err());
+
+        let command_str = "import-utxos utxo_list.csv".to_string();
+        let parsed = parse_command(&command_str).unwrap();
+
+        if let ParsedArgument::CSVFileName(file) = parsed.args[0].clone() {
+            assert_eq!(file, "utxo_list.csv".to_string());
+        } else {
+            panic!("Parsed csv file name is not the same as provided.");
+        }
+
         let transaction_type = "negotiated";
         let message = "Testing the network!";
         let command_str = format!(
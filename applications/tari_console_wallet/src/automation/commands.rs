@@ -25,13 +25,16 @@ use crate::{
     automation::command_parser::{ParsedArgument, ParsedCommand},
     utils::db::{CUSTOM_BASE_NODE_ADDRESS_KEY, CUSTOM_BASE_NODE_PUBLIC_KEY_KEY},
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use futures::{FutureExt, StreamExt};
 use log::*;
+use serde_json::{json, Map, Value};
 use std::{
-    fs::File,
+    fmt::Display,
+    fs::{self, File},
     io::{LineWriter, Write},
     str::FromStr,
+    sync::Arc,
     time::{Duration, Instant},
 };
 use strum_macros::{Display, EnumIter, EnumString};
@@ -39,6 +42,7 @@ use tari_common::GlobalConfig;
 use tari_comms::{
     connectivity::{ConnectivityEvent, ConnectivityRequester},
     multiaddr::Multiaddr,
+    peer_manager::{NodeId, Peer, PeerManager},
     types::CommsPublicKey,
 };
 use tari_comms_dht::{envelope::NodeDestination, DhtDiscoveryRequester};
@@ -46,14 +50,20 @@ use tari_core::{
     tari_utilities::hex::Hex,
     transactions::{
         tari_amount::{uT, MicroTari, Tari},
-        transaction::UnblindedOutput,
-        types::PublicKey,
+        transaction::{OutputFeatures, OutputFlags, TransactionKernel, UnblindedOutput},
+        types::{ComSignature, Commitment, PrivateKey, PublicKey},
     },
 };
-use tari_crypto::ristretto::pedersen::PedersenCommitmentFactory;
+use tari_crypto::{
+    ristretto::pedersen::PedersenCommitmentFactory,
+    script::{ExecutionStack, TariScript},
+};
 use tari_wallet::{
-    output_manager_service::{handle::OutputManagerHandle, TxId},
-    transaction_service::handle::{TransactionEvent, TransactionServiceHandle},
+    output_manager_service::{handle::OutputManagerHandle, service::Balance, TxId},
+    transaction_service::{
+        handle::{TransactionEvent, TransactionServiceHandle},
+        storage::models::{TransactionDirection, WalletTransaction},
+    },
     util::emoji::EmojiId,
     WalletSqlite,
 };
@@ -77,10 +87,15 @@ pub enum WalletCommand {
     Whois,
     ExportUtxos,
     ExportSpentUtxos,
+    ImportUtxos,
     CountUtxos,
     SetBaseNode,
     SetCustomBaseNode,
     ClearCustomBaseNode,
+    CancelAllPending,
+    PrunePendingInbound,
+    ShowTransaction,
+    Status,
 }
 
 #[derive(Debug, EnumString, PartialEq, Clone)]
@@ -100,9 +115,52 @@ pub struct SentTransaction {
     stage: TransactionStage,
 }
 
+/// How the command runner reports its results on stdout
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputMode {
+    /// Human readable text
+    Text,
+    /// A single JSON object per command, no other output
+    Json,
+}
+
+impl OutputMode {
+    /// Print human readable output. Suppressed in JSON mode so that stdout only contains JSON objects.
+    fn text<T: Display>(self, msg: T) {
+        if self == OutputMode::Text {
+            println!("{}", msg);
+        }
+    }
+
+    /// Print the result of a command as a JSON object, only in JSON mode. The fields of an object result are merged
+    /// into the top level, next to the command name and its arguments.
+    fn json(self, command: &str, args: &[ParsedArgument], result: Result<&Value, &CommandError>) {
+        if self != OutputMode::Json {
+            return;
+        }
+        let mut object = Map::new();
+        object.insert("command".to_string(), Value::from(command));
+        object.insert(
+            "args".to_string(),
+            args.iter().map(|arg| Value::from(arg.to_string())).collect(),
+        );
+        match result {
+            Ok(Value::Object(fields)) => object.extend(fields.clone()),
+            Ok(Value::Null) => {},
+            Ok(value) => {
+                object.insert("result".to_string(), value.clone());
+            },
+            Err(e) => {
+                object.insert("error".to_string(), Value::from(e.to_string()));
+            },
+        }
+        println!("{}", Value::Object(object));
+    }
+}
+
 fn get_transaction_parameters(
     args: Vec<ParsedArgument>,
-) -> Result<(MicroTari, MicroTari, PublicKey, String), CommandError> {
+) -> Result<(MicroTari, MicroTari, PublicKey, String, Option<Vec<u8>>), CommandError> {
     // TODO: Consolidate "fee per gram" in codebase
     let fee_per_gram = 25 * uT;
 
@@ -122,7 +180,13 @@ fn get_transaction_parameters(
         _ => Err(CommandError::Argument),
     }?;
 
-    Ok((fee_per_gram, amount, dest_pubkey, message))
+    let payment_id = match args.get(3).cloned() {
+        Some(PaymentId(id)) => Ok(Some(id.into_bytes())),
+        None => Ok(None),
+        _ => Err(CommandError::Argument),
+    }?;
+
+    Ok((fee_per_gram, amount, dest_pubkey, message, payment_id))
 }
 
 /// Send a normal negotiated transaction to a recipient
@@ -130,21 +194,21 @@ pub async fn send_tari(
     mut wallet_transaction_service: TransactionServiceHandle,
     args: Vec<ParsedArgument>,
 ) -> Result<TxId, CommandError> {
-    let (fee_per_gram, amount, dest_pubkey, message) = get_transaction_parameters(args)?;
+    let (fee_per_gram, amount, dest_pubkey, message, _) = get_transaction_parameters(args)?;
     wallet_transaction_service
         .send_transaction(dest_pubkey, amount, fee_per_gram, message)
         .await
         .map_err(CommandError::TransactionServiceError)
 }
 
-/// Send a one-sided transaction to a recipient
+/// Send a one-sided transaction to a recipient, optionally tagged with a payment id
 pub async fn send_one_sided(
     mut wallet_transaction_service: TransactionServiceHandle,
     args: Vec<ParsedArgument>,
 ) -> Result<TxId, CommandError> {
-    let (fee_per_gram, amount, dest_pubkey, message) = get_transaction_parameters(args)?;
+    let (fee_per_gram, amount, dest_pubkey, message, payment_id) = get_transaction_parameters(args)?;
     wallet_transaction_service
-        .send_one_sided_transaction(dest_pubkey, amount, fee_per_gram, message)
+        .send_one_sided_transaction_with_payment_id(dest_pubkey, amount, fee_per_gram, message, payment_id)
         .await
         .map_err(CommandError::TransactionServiceError)
 }
@@ -175,30 +239,348 @@ pub async fn coin_split(
     Ok(tx_id)
 }
 
-async fn wait_for_comms(connectivity_requester: &ConnectivityRequester) -> Result<bool, CommandError> {
+/// Cancel every pending outbound transaction. Pending inbound transactions are left alone so that incoming payments are
+/// never cancelled by accident.
+pub async fn cancel_all_pending(
+    mut wallet_transaction_service: TransactionServiceHandle,
+    output: OutputMode,
+) -> Result<Value, CommandError> {
+    let mut tx_ids = wallet_transaction_service
+        .get_pending_outbound_transactions()
+        .await?
+        .into_iter()
+        .map(|(tx_id, _)| tx_id)
+        .collect::<Vec<TxId>>();
+    tx_ids.sort_unstable();
+
+    let mut cancelled = Vec::new();
+    let mut failed = Vec::new();
+    for tx_id in tx_ids {
+        match wallet_transaction_service.cancel_transaction(tx_id).await {
+            Ok(_) => {
+                output.text(format!("Cancelled pending outbound transaction {}", tx_id));
+                cancelled.push(tx_id);
+            },
+            Err(e) => {
+                output.text(format!(
+                    "Could not cancel pending outbound transaction {}: {}",
+                    tx_id, e
+                ));
+                warn!(target: LOG_TARGET, "Could not cancel transaction {}: {}", tx_id, e);
+                failed.push(json!({ "tx_id": tx_id, "error": e.to_string() }));
+            },
+        }
+    }
+    output.text(format!(
+        "Cancelled {} pending outbound transaction(s), {} failed",
+        cancelled.len(),
+        failed.len()
+    ));
+
+    Ok(json!({ "cancelled": cancelled, "failed": failed }))
+}
+
+/// Cancel pending inbound transactions that were created more than `age_hours` hours ago, e.g. because the sender
+/// vanished. Any pending incoming outputs reserved for them are released. More recent transactions are left alone.
+pub async fn prune_pending_inbound(
+    mut wallet_transaction_service: TransactionServiceHandle,
+    args: Vec<ParsedArgument>,
+    output: OutputMode,
+) -> Result<Value, CommandError> {
+    let age_hours = match args[0] {
+        ParsedArgument::Int(hours) => Ok(hours),
+        _ => Err(CommandError::Argument),
+    }?;
+    let cutoff = Utc::now().naive_utc() - chrono::Duration::hours(age_hours as i64);
+
+    let mut stale = wallet_transaction_service
+        .get_pending_inbound_transactions()
+        .await?
+        .into_iter()
+        .filter(|(_, tx)| tx.timestamp < cutoff)
+        .map(|(tx_id, tx)| (tx_id, tx.amount))
+        .collect::<Vec<(TxId, MicroTari)>>();
+    stale.sort_unstable_by_key(|(tx_id, _)| *tx_id);
+
+    let mut cancelled = Vec::new();
+    let mut failed = Vec::new();
+    let mut reclaimed = MicroTari::from(0);
+    for (tx_id, amount) in stale {
+        match wallet_transaction_service.cancel_transaction(tx_id).await {
+            Ok(_) => {
+                output.text(format!("Cancelled pending inbound transaction {} ({})", tx_id, amount));
+                reclaimed += amount;
+                cancelled.push(json!({ "tx_id": tx_id, "amount": amount.0 }));
+            },
+            Err(e) => {
+                output.text(format!("Could not cancel pending inbound transaction {}: {}", tx_id, e));
+                warn!(target: LOG_TARGET, "Could not cancel transaction {}: {}", tx_id, e);
+                failed.push(json!({ "tx_id": tx_id, "error": e.to_string() }));
+            },
+        }
+    }
+    output.text(format!(
+        "Cancelled {} pending inbound transaction(s) older than {} hour(s), reclaiming {}; {} failed",
+        cancelled.len(),
+        age_hours,
+        reclaimed,
+        failed.len()
+    ));
+
+    Ok(json!({ "cancelled": cancelled, "reclaimed": reclaimed.0, "failed": failed }))
+}
+
+/// The fields of a pending or completed transaction that `show-transaction` reports
+struct TransactionDetails {
+    tx_id: TxId,
+    status: String,
+    direction: TransactionDirection,
+    amount: MicroTari,
+    fee: Option<MicroTari>,
+    source_public_key: CommsPublicKey,
+    destination_public_key: CommsPublicKey,
+    message: String,
+    timestamp: NaiveDateTime,
+    last_send_timestamp: Option<NaiveDateTime>,
+    cancelled: bool,
+    mined_height: Option<u64>,
+    confirmations: Option<u64>,
+    kernels: Vec<TransactionKernel>,
+}
+
+impl TransactionDetails {
+    /// Pending inbound transactions are addressed to this wallet, so `own_public_key` is used as their destination
+    fn new(transaction: WalletTransaction, own_public_key: CommsPublicKey) -> Self {
+        match transaction {
+            WalletTransaction::PendingInbound(tx) => Self {
+                tx_id: tx.tx_id,
+                status: tx.status.to_string(),
+                direction: TransactionDirection::Inbound,
+                amount: tx.amount,
+                fee: None,
+                source_public_key: tx.source_public_key,
+                destination_public_key: own_public_key,
+                message: tx.message,
+                timestamp: tx.timestamp,
+                last_send_timestamp: tx.last_send_timestamp,
+                cancelled: tx.cancelled,
+                mined_height: None,
+                confirmations: None,
+                kernels: Vec::new(),
+            },
+            WalletTransaction::PendingOutbound(tx) => Self {
+                tx_id: tx.tx_id,
+                status: tx.status.to_string(),
+                direction: TransactionDirection::Outbound,
+                amount: tx.amount,
+                fee: Some(tx.fee),
+                source_public_key: own_public_key,
+                destination_public_key: tx.destination_public_key,
+                message: tx.message,
+                timestamp: tx.timestamp,
+                last_send_timestamp: tx.last_send_timestamp,
+                cancelled: tx.cancelled,
+                mined_height: None,
+                confirmations: None,
+                kernels: Vec::new(),
+            },
+            WalletTransaction::Completed(tx) => Self {
+                tx_id: tx.tx_id,
+                status: tx.status.to_string(),
+                direction: tx.direction,
+                amount: tx.amount,
+                fee: Some(tx.fee),
+                source_public_key: tx.source_public_key,
+                destination_public_key: tx.destination_public_key,
+                message: tx.message,
+                timestamp: tx.timestamp,
+                last_send_timestamp: tx.last_send_timestamp,
+                cancelled: tx.cancelled,
+                mined_height: tx.mined_height,
+                confirmations: tx.confirmations,
+                kernels: tx.transaction.body.kernels().clone(),
+            },
+        }
+    }
+
+    fn print(&self, output: OutputMode) {
+        let optional = |v: Option<String>| v.unwrap_or_else(|| "-".to_string());
+        output.text(format!("Transaction  : {}", self.tx_id));
+        output.text(format!("Status       : {}", self.status));
+        output.text(format!("Direction    : {}", self.direction));
+        output.text(format!("Amount       : {}", self.amount));
+        output.text(format!("Fee          : {}", optional(self.fee.map(|f| f.to_string()))));
+        output.text(format!("Source       : {}", self.source_public_key));
+        output.text(format!("Destination  : {}", self.destination_public_key));
+        output.text(format!(
+            "Emoji ID     : {}",
+            EmojiId::from_pubkey(&self.destination_public_key)
+        ));
+        output.text(format!("Message      : {}", self.message));
+        output.text(format!("Created      : {}", self.timestamp));
+        output.text(format!(
+            "Last sent    : {}",
+            optional(self.last_send_timestamp.map(|t| t.to_string()))
+        ));
+        output.text(format!("Cancelled    : {}", self.cancelled));
+        output.text(format!(
+            "Mined height : {}",
+            optional(self.mined_height.map(|h| h.to_string()))
+        ));
+        output.text(format!(
+            "Confirmations: {}",
+            optional(self.confirmations.map(|c| c.to_string()))
+        ));
+        for kernel in &self.kernels {
+            output.text(format!(
+                "Kernel       : excess {}, fee {}, lock height {}",
+                kernel.excess.to_hex(),
+                kernel.fee,
+                kernel.lock_height
+            ));
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "tx_id": self.tx_id,
+            "status": self.status,
+            "direction": self.direction.to_string(),
+            "amount": self.amount.0,
+            "fee": self.fee.map(|f| f.0),
+            "source_public_key": self.source_public_key.to_hex(),
+            "destination_public_key": self.destination_public_key.to_hex(),
+            "destination_emoji_id": EmojiId::from_pubkey(&self.destination_public_key).to_string(),
+            "message": self.message,
+            "timestamp": self.timestamp.to_string(),
+            "last_send_timestamp": self.last_send_timestamp.map(|t| t.to_string()),
+            "cancelled": self.cancelled,
+            "mined_height": self.mined_height,
+            "confirmations": self.confirmations,
+            "kernels": self.kernels.iter().map(|kernel| json!({
+                "excess": kernel.excess.to_hex(),
+                "fee": kernel.fee.0,
+                "lock_height": kernel.lock_height,
+            })).collect::<Vec<Value>>(),
+        })
+    }
+}
+
+/// Print all the details of a single pending or completed transaction
+pub async fn show_transaction(
+    wallet: &WalletSqlite,
+    args: Vec<ParsedArgument>,
+    output: OutputMode,
+) -> Result<Value, CommandError> {
+    let tx_id = match args[0] {
+        ParsedArgument::Int(tx_id) => Ok(tx_id),
+        _ => Err(CommandError::Argument),
+    }?;
+    let transaction = wallet
+        .transaction_service
+        .clone()
+        .get_any_transaction(tx_id)
+        .await?
+        .ok_or(CommandError::TransactionNotFound(tx_id))?;
+
+    let details = TransactionDetails::new(transaction, wallet.comms.node_identity().public_key().clone());
+    details.print(output);
+
+    Ok(details.to_json())
+}
+
+/// Print the connectivity status, the base node peer and its latency, and the balance as a one-shot health check
+pub async fn status(wallet: &WalletSqlite, output: OutputMode) -> Result<Value, CommandError> {
+    let connectivity_status = wallet
+        .comms
+        .connectivity()
+        .get_connectivity_status()
+        .await
+        .map_err(|e| CommandError::Comms(e.to_string()))?;
+    let mut base_node_service = wallet.base_node_service.clone();
+    let base_node = base_node_service.get_base_node_peer().await?;
+    let latency = base_node_service.get_base_node_latency().await?;
+    let balance = wallet.output_manager_service.clone().get_balance().await?;
+
+    output.text(format!("Connectivity : {}", connectivity_status));
+    match base_node.as_ref() {
+        Some(peer) => output.text(format!("Base node    : {} ({})", peer.public_key, peer.node_id)),
+        None => output.text("Base node    : not set"),
+    }
+    match latency {
+        Some(latency) => output.text(format!("Latency      : {} ms", latency.as_millis())),
+        None => output.text("Latency      : unknown"),
+    }
+    output.text(&balance);
+
+    Ok(json!({
+        "connectivity": connectivity_status.to_string(),
+        "base_node": base_node.map(|peer| json!({
+            "public_key": peer.public_key.to_hex(),
+            "node_id": peer.node_id.to_string(),
+        })),
+        "latency_ms": latency.map(|latency| latency.as_millis() as u64),
+        "balance": balance_json(&balance),
+    }))
+}
+
+fn balance_json(balance: &Balance) -> Value {
+    json!({
+        "available": balance.available_balance.0,
+        "time_locked": balance.time_locked_balance.map(|v| v.0),
+        "pending_incoming": balance.pending_incoming_balance.0,
+        "pending_outgoing": balance.pending_outgoing_balance.0,
+    })
+}
+
+/// Wait for comms to come online. If `base_node` is given, waits until a connection to that specific peer is
+/// established, otherwise the first connected peer will do.
+async fn wait_for_comms(
+    connectivity_requester: &ConnectivityRequester,
+    timeout: Duration,
+    base_node: Option<NodeId>,
+    output: OutputMode,
+) -> Result<bool, CommandError> {
     let mut connectivity = connectivity_requester.get_event_subscription().fuse();
-    print!("Waiting for connectivity... ");
-    let mut timeout = delay_for(Duration::from_secs(30)).fuse();
+    if output == OutputMode::Text {
+        print!("Waiting for connectivity... ");
+    }
+    if let Some(node_id) = base_node.as_ref() {
+        let connection = connectivity_requester
+            .clone()
+            .get_connection(node_id.clone())
+            .await
+            .map_err(|e| CommandError::Comms(e.to_string()))?;
+        if connection.is_some() {
+            output.text("✅");
+            return Ok(true);
+        }
+    }
+    let mut timeout = delay_for(timeout).fuse();
     loop {
         futures::select! {
             result = connectivity.select_next_some() => {
                 if let Ok(msg) = result {
-                    if let ConnectivityEvent::PeerConnected(_) = (*msg).clone() {
-                        println!("✅");
-                        return Ok(true);
+                    if let ConnectivityEvent::PeerConnected(conn) = (*msg).clone() {
+                        if base_node.as_ref().map_or(true, |n| n == conn.peer_node_id()) {
+                            output.text("✅");
+                            return Ok(true);
+                        }
                     }
                 }
             },
             () = timeout => {
-                println!("❌");
+                output.text("❌");
                 return Err(CommandError::Comms("Timed out".to_string()));
             }
         }
     }
 }
+
 async fn set_base_node_peer(
     mut wallet: WalletSqlite,
     args: &[ParsedArgument],
+    output: OutputMode,
 ) -> Result<(CommsPublicKey, Multiaddr), CommandError> {
     let public_key = match args[0].clone() {
         ParsedArgument::PublicKey(s) => Ok(s),
@@ -210,8 +592,8 @@ async fn set_base_node_peer(
         _ => Err(CommandError::Argument),
     }?;
 
-    println!("Setting base node peer...");
-    println!("{}::{}", public_key, net_address);
+    output.text("Setting base node peer...");
+    output.text(format!("{}::{}", public_key, net_address));
     wallet
         .set_base_node_peer(public_key.clone(), net_address.to_string())
         .await?;
@@ -219,37 +601,122 @@ async fn set_base_node_peer(
     Ok((public_key, net_address))
 }
 
+/// The outcome of a peer discovery
+pub struct DiscoverPeerResult {
+    /// How long the discovery took
+    pub elapsed: Duration,
+    /// The discovered peer, or the reason why discovery failed
+    pub peer: Result<Peer, String>,
+}
+
+fn discover_peer_json(result: &DiscoverPeerResult) -> Value {
+    let elapsed_ms = result.elapsed.as_millis() as u64;
+    match &result.peer {
+        Ok(peer) => json!({
+            "discovered": true,
+            "elapsed_ms": elapsed_ms,
+            "peer": {
+                "public_key": peer.public_key.to_hex(),
+                "node_id": peer.node_id.to_string(),
+                "features": peer.features.bits(),
+                "last_seen": peer.last_seen().map(|t| t.to_rfc3339()),
+                "addresses": peer
+                    .addresses
+                    .addresses
+                    .iter()
+                    .map(|a| json!({
+                        "address": a.address.to_string(),
+                        "last_seen": a.last_seen.map(|t| t.to_rfc3339()),
+                        "connection_attempts": a.connection_attempts,
+                    }))
+                    .collect::<Vec<_>>(),
+            },
+        }),
+        Err(err) => json!({ "discovered": false, "elapsed_ms": elapsed_ms, "error": err }),
+    }
+}
+
+/// Prints every known address of a discovered peer, along with its feature flags and when it was last seen
+fn print_discovered_peer(peer: &Peer, output: OutputMode) {
+    output.text(format!("Public key: {}", peer.public_key.to_hex()));
+    output.text(format!("Node ID: {}", peer.node_id));
+    output.text(format!("Features: {}", peer.features));
+    output.text(format!(
+        "Last seen: {}",
+        peer.last_seen()
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_else(|| "Never".to_string())
+    ));
+    output.text(format!("Addresses ({}):", peer.addresses.len()));
+    for address in &peer.addresses.addresses {
+        output.text(format!(
+            "  {} (last seen: {}, connection attempts: {})",
+            address.address,
+            address
+                .last_seen
+                .map(|t| t.to_rfc3339())
+                .unwrap_or_else(|| "Never".to_string()),
+            address.connection_attempts
+        ));
+    }
+}
+
 pub async fn discover_peer(
     mut dht_service: DhtDiscoveryRequester,
+    peer_manager: Arc<PeerManager>,
     args: Vec<ParsedArgument>,
-) -> Result<(), CommandError> {
+    output: OutputMode,
+) -> Result<DiscoverPeerResult, CommandError> {
     use ParsedArgument::*;
-    let dest_public_key = match args[0].clone() {
-        PublicKey(key) => Ok(Box::new(key)),
-        _ => Err(CommandError::Argument),
-    }?;
+    let (dest_public_key, destination) = match args[0].clone() {
+        PublicKey(key) => {
+            let dest_public_key = Box::new(key);
+            (dest_public_key.clone(), NodeDestination::PublicKey(dest_public_key))
+        },
+        // Discovery messages are encrypted for the destination, so its public key is needed even when the message is
+        // routed by node id
+        ParsedArgument::NodeId(node_id) => match peer_manager.find_by_node_id(&node_id).await {
+            Ok(peer) => (Box::new(peer.public_key), NodeDestination::NodeId(Box::new(node_id))),
+            Err(err) => {
+                output.text(format!(
+                    "💀 The public key for node id {} is not known, discover the peer by public key instead: '{:?}'",
+                    node_id, err
+                ));
+                return Ok(DiscoverPeerResult {
+                    elapsed: Duration::from_secs(0),
+                    peer: Err(format!("{:?}", err)),
+                });
+            },
+        },
+        _ => return Err(CommandError::Argument),
+    };
 
     let start = Instant::now();
-    println!("🌎 Peer discovery started.");
-    match dht_service
-        .discover_peer(dest_public_key.clone(), NodeDestination::PublicKey(dest_public_key))
-        .await
-    {
+    output.text("🌎 Peer discovery started.");
+    match dht_service.discover_peer(dest_public_key, destination).await {
         Ok(peer) => {
-            println!("⚡️ Discovery succeeded in {}ms.", start.elapsed().as_millis());
-            println!("{}", peer);
+            let elapsed = start.elapsed();
+            output.text(format!("⚡️ Discovery succeeded in {}ms.", elapsed.as_millis()));
+            print_discovered_peer(&peer, output);
+            Ok(DiscoverPeerResult {
+                elapsed,
+                peer: Ok(peer),
+            })
         },
         Err(err) => {
-            println!("💀 Discovery failed: '{:?}'", err);
+            output.text(format!("💀 Discovery failed: '{:?}'", err));
+            Ok(DiscoverPeerResult {
+                elapsed: start.elapsed(),
+                peer: Err(format!("{:?}", err)),
+            })
         },
     }
-
-    Ok(())
 }
 
 pub async fn make_it_rain(
     wallet_transaction_service: TransactionServiceHandle,
     args: Vec<ParsedArgument>,
+    output: OutputMode,
 ) -> Result<(), CommandError> {
     use ParsedArgument::*;
 
@@ -298,10 +765,10 @@ pub async fn make_it_rain(
         // Wait until specified test start time
         let now = Utc::now();
         let delay_ms = if start_time > now {
-            println!(
+            output.text(format!(
                 "`make-it-rain` scheduled to start at {}: msg \"{}\"",
                 start_time, message
-            );
+            ));
             (start_time - now).num_milliseconds() as u64
         } else {
             0
@@ -323,10 +790,10 @@ pub async fn make_it_rain(
             submit_time: Duration,
         }
         let transaction_type = if negotiated { "negotiated" } else { "one-sided" };
-        println!(
+        output.text(format!(
             "\n`make-it-rain` starting {} {} transactions \"{}\"\n",
             num_txs, transaction_type, message
-        );
+        ));
         let (sender, mut receiver) = mpsc::channel(num_txs);
         {
             let sender = sender;
@@ -365,9 +832,11 @@ pub async fn make_it_rain(
                         send_one_sided(tx_service, send_args).await
                     };
                     let submit_time = Instant::now();
-                    tokio::task::spawn(async move {
-                        print!("{} ", i + 1);
-                    });
+                    if output == OutputMode::Text {
+                        tokio::task::spawn(async move {
+                            print!("{} ", i + 1);
+                        });
+                    }
                     if let Err(e) = sender_clone
                         .send(TransactionSendStats {
                             i: i + 1,
@@ -415,13 +884,13 @@ pub async fn make_it_rain(
             target: LOG_TARGET,
             "make-it-rain concluded {} {} transactions", num_txs, transaction_type
         );
-        println!(
+        output.text(format!(
             "\n`make-it-rain` concluded {} {} transactions (\"{}\") at {}",
             num_txs,
             transaction_type,
             message,
             Utc::now()
-        );
+        ));
     });
 
     Ok(())
@@ -431,15 +900,16 @@ pub async fn monitor_transactions(
     transaction_service: TransactionServiceHandle,
     tx_ids: Vec<TxId>,
     wait_stage: TransactionStage,
+    output: OutputMode,
 ) -> Vec<SentTransaction> {
     let mut event_stream = transaction_service.get_event_stream_fused();
     let mut results = Vec::new();
     debug!(target: LOG_TARGET, "monitor transactions wait_stage: {:?}", wait_stage);
-    println!(
+    output.text(format!(
         "Monitoring {} sent transactions to {:?} stage...",
         tx_ids.len(),
         wait_stage
-    );
+    ));
 
     loop {
         match event_stream.next().await {
@@ -550,6 +1020,7 @@ pub async fn command_runner(
     commands: Vec<ParsedCommand>,
     wallet: WalletSqlite,
     config: GlobalConfig,
+    output: OutputMode,
 ) -> Result<(), CommandError> {
     let wait_stage = TransactionStage::from_str(&config.wallet_command_send_wait_stage)
         .map_err(|e| CommandError::Config(e.to_string()))?;
@@ -562,128 +1033,164 @@ pub async fn command_runner(
 
     let mut tx_ids = Vec::new();
 
-    println!("==============");
-    println!("Command Runner");
-    println!("==============");
+    output.text("==============");
+    output.text("Command Runner");
+    output.text("==============");
     use WalletCommand::*;
     for (idx, parsed) in commands.into_iter().enumerate() {
-        println!("\n{}. {}\n", idx + 1, parsed);
+        output.text(format!("\n{}. {}\n", idx + 1, parsed));
 
-        match parsed.command {
-            GetBalance => match output_service.clone().get_balance().await {
-                Ok(balance) => {
-                    println!("{}", balance);
+        let result: Result<Value, CommandError> = async {
+            match parsed.command {
+                GetBalance => match output_service.clone().get_balance().await {
+                    Ok(balance) => {
+                        output.text(&balance);
+                        Ok(balance_json(&balance))
+                    },
+                    Err(e) => {
+                        if output == OutputMode::Text {
+                            eprintln!("GetBalance error! {}", e);
+                        }
+                        Ok(json!({ "error": e.to_string() }))
+                    },
                 },
-                Err(e) => eprintln!("GetBalance error! {}", e),
-            },
-            DiscoverPeer => {
-                if !online {
-                    online = wait_for_comms(&connectivity_requester).await?;
-                }
-                discover_peer(dht_service.clone(), parsed.args).await?
-            },
-            SendTari => {
-                let tx_id = send_tari(transaction_service.clone(), parsed.args).await?;
-                debug!(target: LOG_TARGET, "send-tari tx_id {}", tx_id);
-                tx_ids.push(tx_id);
-            },
-            SendOneSided => {
-                let tx_id = send_one_sided(transaction_service.clone(), parsed.args).await?;
-                debug!(target: LOG_TARGET, "send-one-sided tx_id {}", tx_id);
-                tx_ids.push(tx_id);
-            },
-            MakeItRain => {
-                make_it_rain(transaction_service.clone(), parsed.args).await?;
-            },
-            CoinSplit => {
-                let tx_id = coin_split(&parsed.args, &mut output_service, &mut transaction_service.clone()).await?;
-                tx_ids.push(tx_id);
-                println!("Coin split succeeded");
-            },
-            Whois => {
-                let public_key = match parsed.args[0].clone() {
-                    ParsedArgument::PublicKey(key) => Ok(Box::new(key)),
-                    _ => Err(CommandError::Argument),
-                }?;
-                let emoji_id = EmojiId::from_pubkey(&public_key);
-
-                println!("Public Key: {}", public_key.to_hex());
-                println!("Emoji ID  : {}", emoji_id);
-            },
-            ExportUtxos => {
-                let utxos = output_service.get_unspent_outputs().await?;
-                let count = utxos.len();
-                let sum: MicroTari = utxos.iter().map(|utxo| utxo.value).sum();
-                if parsed.args.is_empty() {
-                    for (i, utxo) in utxos.iter().enumerate() {
-                        println!("{}. Value: {} {}", i + 1, utxo.value, utxo.features);
+                DiscoverPeer => {
+                    if !online {
+                        let base_node = if config.wallet_command_wait_for_base_node {
+                            wallet.clone().get_base_node_peer().await?.map(|peer| peer.node_id)
+                        } else {
+                            None
+                        };
+                        online = wait_for_comms(
+                            &connectivity_requester,
+                            Duration::from_secs(config.wallet_command_comms_wait_timeout),
+                            base_node,
+                            output,
+                        )
+                        .await?;
                     }
-                } else if let ParsedArgument::CSVFileName(file) = parsed.args[1].clone() {
-                    write_utxos_to_csv_file(utxos, file)?;
-                }
-                println!("Total number of UTXOs: {}", count);
-                println!("Total value of UTXOs: {}", sum);
-            },
-            ExportSpentUtxos => {
-                let utxos = output_service.get_spent_outputs().await?;
-                let count = utxos.len();
-                let sum: MicroTari = utxos.iter().map(|utxo| utxo.value).sum();
-                if parsed.args.is_empty() {
-                    for (i, utxo) in utxos.iter().enumerate() {
-                        println!("{}. Value: {} {}", i + 1, utxo.value, utxo.features);
+                    discover_peer(
+                        dht_service.clone(),
+                        wallet.comms.peer_manager(),
+                        parsed.args.clone(),
+                        output,
+                    )
+                    .await
+                    .map(|result| discover_peer_json(&result))
+                },
+                SendTari => {
+                    let tx_id = send_tari(transaction_service.clone(), parsed.args.clone()).await?;
+                    debug!(target: LOG_TARGET, "send-tari tx_id {}", tx_id);
+                    tx_ids.push(tx_id);
+                    Ok(json!({ "tx_id": tx_id }))
+                },
+                SendOneSided => {
+                    let tx_id = send_one_sided(transaction_service.clone(), parsed.args.clone()).await?;
+                    debug!(target: LOG_TARGET, "send-one-sided tx_id {}", tx_id);
+                    tx_ids.push(tx_id);
+                    Ok(json!({ "tx_id": tx_id }))
+                },
+                MakeItRain => {
+                    make_it_rain(transaction_service.clone(), parsed.args.clone(), output).await?;
+                    Ok(Value::Null)
+                },
+                CoinSplit => {
+                    let tx_id = coin_split(&parsed.args, &mut output_service, &mut transaction_service.clone()).await?;
+                    tx_ids.push(tx_id);
+                    output.text("Coin split succeeded");
+                    Ok(json!({ "tx_id": tx_id }))
+                },
+                Whois => {
+                    let public_key = match parsed.args[0].clone() {
+                        ParsedArgument::PublicKey(key) => Ok(Box::new(key)),
+                        _ => Err(CommandError::Argument),
+                    }?;
+                    let emoji_id = EmojiId::from_pubkey(&public_key);
+
+                    output.text(format!("Public Key: {}", public_key.to_hex()));
+                    output.text(format!("Emoji ID  : {}", emoji_id));
+                    Ok(json!({ "public_key": public_key.to_hex(), "emoji_id": emoji_id.to_string() }))
+                },
+                ExportUtxos => {
+                    let utxos = output_service.get_unspent_outputs().await?;
+                    export_utxos(utxos, &parsed.args, output)
+                },
+                ExportSpentUtxos => {
+                    let utxos = output_service.get_spent_outputs().await?;
+                    export_utxos(utxos, &parsed.args, output)
+                },
+                ImportUtxos => import_utxos(wallet.clone(), &parsed.args, output).await,
+                CountUtxos => {
+                    let utxos = output_service.get_unspent_outputs().await?;
+                    let count = utxos.len();
+                    let values: Vec<MicroTari> = utxos.iter().map(|utxo| utxo.value).collect();
+                    let sum: MicroTari = values.iter().sum();
+                    output.text(format!("Total number of UTXOs: {}", count));
+                    output.text(format!("Total value of UTXOs : {}", sum));
+                    let min = values.iter().min();
+                    if let Some(min) = min {
+                        output.text(format!("Minimum value UTXO   : {}", min));
                     }
-                } else if let ParsedArgument::CSVFileName(file) = parsed.args[1].clone() {
-                    write_utxos_to_csv_file(utxos, file)?;
-                }
-                println!("Total number of UTXOs: {}", count);
-                println!("Total value of UTXOs: {}", sum);
-            },
-            CountUtxos => {
-                let utxos = output_service.get_unspent_outputs().await?;
-                let count = utxos.len();
-                let values: Vec<MicroTari> = utxos.iter().map(|utxo| utxo.value).collect();
-                let sum: MicroTari = values.iter().sum();
-                println!("Total number of UTXOs: {}", count);
-                println!("Total value of UTXOs : {}", sum);
-                if let Some(min) = values.iter().min() {
-                    println!("Minimum value UTXO   : {}", min);
-                }
-                if count > 0 {
-                    let average = f64::from(sum) / count as f64;
-                    let average = Tari::from(average / 1_000_000f64);
-                    println!("Average value UTXO   : {}", average);
-                }
-                if let Some(max) = values.iter().max() {
-                    println!("Maximum value UTXO   : {}", max);
-                }
-            },
-            SetBaseNode => {
-                set_base_node_peer(wallet.clone(), &parsed.args).await?;
-            },
-            SetCustomBaseNode => {
-                let (public_key, net_address) = set_base_node_peer(wallet.clone(), &parsed.args).await?;
-                wallet
-                    .db
-                    .set_client_key_value(CUSTOM_BASE_NODE_PUBLIC_KEY_KEY.to_string(), public_key.to_string())
-                    .await?;
-                wallet
-                    .db
-                    .set_client_key_value(CUSTOM_BASE_NODE_ADDRESS_KEY.to_string(), net_address.to_string())
-                    .await?;
-                println!("Custom base node peer saved in wallet database.");
-            },
-            ClearCustomBaseNode => {
-                wallet
-                    .db
-                    .clear_client_value(CUSTOM_BASE_NODE_PUBLIC_KEY_KEY.to_string())
-                    .await?;
-                wallet
-                    .db
-                    .clear_client_value(CUSTOM_BASE_NODE_ADDRESS_KEY.to_string())
-                    .await?;
-                println!("Custom base node peer cleared from wallet database.");
-            },
+                    let average = if count > 0 {
+                        let average = f64::from(sum) / count as f64;
+                        output.text(format!("Average value UTXO   : {}", Tari::from(average / 1_000_000f64)));
+                        Some(average)
+                    } else {
+                        None
+                    };
+                    let max = values.iter().max();
+                    if let Some(max) = max {
+                        output.text(format!("Maximum value UTXO   : {}", max));
+                    }
+                    Ok(json!({
+                        "count": count,
+                        "total_value": sum.0,
+                        "min_value": min.map(|v| v.0),
+                        "average_value": average,
+                        "max_value": max.map(|v| v.0),
+                    }))
+                },
+                SetBaseNode => {
+                    let (public_key, net_address) = set_base_node_peer(wallet.clone(), &parsed.args, output).await?;
+                    Ok(json!({ "public_key": public_key.to_hex(), "address": net_address.to_string() }))
+                },
+                SetCustomBaseNode => {
+                    let (public_key, net_address) = set_base_node_peer(wallet.clone(), &parsed.args, output).await?;
+                    wallet
+                        .db
+                        .set_client_key_value(CUSTOM_BASE_NODE_PUBLIC_KEY_KEY.to_string(), public_key.to_string())
+                        .await?;
+                    wallet
+                        .db
+                        .set_client_key_value(CUSTOM_BASE_NODE_ADDRESS_KEY.to_string(), net_address.to_string())
+                        .await?;
+                    output.text("Custom base node peer saved in wallet database.");
+                    Ok(json!({ "public_key": public_key.to_hex(), "address": net_address.to_string() }))
+                },
+                ClearCustomBaseNode => {
+                    wallet
+                        .db
+                        .clear_client_value(CUSTOM_BASE_NODE_PUBLIC_KEY_KEY.to_string())
+                        .await?;
+                    wallet
+                        .db
+                        .clear_client_value(CUSTOM_BASE_NODE_ADDRESS_KEY.to_string())
+                        .await?;
+                    output.text("Custom base node peer cleared from wallet database.");
+                    Ok(Value::Null)
+                },
+                CancelAllPending => cancel_all_pending(transaction_service.clone(), output).await,
+                PrunePendingInbound => {
+                    prune_pending_inbound(transaction_service.clone(), parsed.args.clone(), output).await
+                },
+                ShowTransaction => show_transaction(&wallet, parsed.args.clone(), output).await,
+                Status => status(&wallet, output).await,
+            }
         }
+        .await;
+
+        output.json(&parsed.command.to_string(), &parsed.args, result.as_ref());
+        result?;
     }
 
     // listen to event stream
@@ -693,9 +1200,10 @@ pub async fn command_runner(
             target: LOG_TARGET,
             "wallet monitor_transactions timeout duration {:?}", duration
         );
-        match timeout(
+        let monitored_tx_ids = tx_ids.clone();
+        let timed_out = match timeout(
             duration,
-            monitor_transactions(transaction_service.clone(), tx_ids, wait_stage.clone()),
+            monitor_transactions(transaction_service.clone(), tx_ids, wait_stage.clone(), output),
         )
         .await
         {
@@ -704,16 +1212,27 @@ pub async fn command_runner(
                     target: LOG_TARGET,
                     "monitor_transactions done to stage {:?} with tx_ids: {:?}", wait_stage, txs
                 );
-                println!("Done! All transactions monitored to {:?} stage.", wait_stage);
+                output.text(format!("Done! All transactions monitored to {:?} stage.", wait_stage));
+                false
             },
             Err(_e) => {
-                println!(
+                output.text(format!(
                     "The configured timeout ({:#?}) was reached before all transactions reached the {:?} stage. See \
                      the logs for more info.",
                     duration, wait_stage
-                );
+                ));
+                true
             },
-        }
+        };
+        output.json(
+            "monitor-transactions",
+            &[],
+            Ok(&json!({
+                "tx_ids": monitored_tx_ids,
+                "stage": format!("{:?}", wait_stage),
+                "timed_out": timed_out,
+            })),
+        );
     } else {
         trace!(
             target: LOG_TARGET,
@@ -724,19 +1243,42 @@ pub async fn command_runner(
     Ok(())
 }
 
+/// Print (or write to the CSV file given in `args`) the given UTXOs along with a count and total value
+fn export_utxos(
+    utxos: Vec<UnblindedOutput>,
+    args: &[ParsedArgument],
+    output: OutputMode,
+) -> Result<Value, CommandError> {
+    let count = utxos.len();
+    let sum: MicroTari = utxos.iter().map(|utxo| utxo.value).sum();
+    let mut exported = Vec::new();
+    if args.is_empty() {
+        for (i, utxo) in utxos.iter().enumerate() {
+            output.text(format!("{}. Value: {} {}", i + 1, utxo.value, utxo.features));
+            exported.push(json!({ "value": utxo.value.0, "features": utxo.features.to_string() }));
+        }
+    } else if let ParsedArgument::CSVFileName(file) = args[1].clone() {
+        write_utxos_to_csv_file(utxos, file)?;
+    }
+    output.text(format!("Total number of UTXOs: {}", count));
+    output.text(format!("Total value of UTXOs: {}", sum));
+    Ok(json!({ "count": count, "total_value": sum.0, "utxos": exported }))
+}
+
 fn write_utxos_to_csv_file(utxos: Vec<UnblindedOutput>, file_path: String) -> Result<(), CommandError> {
     let factory = PedersenCommitmentFactory::default();
     let file = File::create(file_path).map_err(|e| CommandError::CSVFile(e.to_string()))?;
     let mut csv_file = LineWriter::new(file);
+    // New columns go at the end so that parsers relying on the column order keep working
     writeln!(
         csv_file,
-        r##""index","value","spending_key","commitment","flags","maturity","script","input_data","script_private_key","sender_offset_public_key","public_nonce","signature_u","signature_v""##
+        r##""index","value","spending_key","commitment","flags","maturity","script","input_data","script_private_key","sender_offset_public_key","public_nonce","signature_u","signature_v","sender_offset_emoji_id""##
     )
     .map_err(|e| CommandError::CSVFile(e.to_string()))?;
     for (i, utxo) in utxos.iter().enumerate() {
         writeln!(
             csv_file,
-            r##""{}","{}","{}","{}","{:?}","{}","{}","{}","{}","{}","{}","{}","{}""##,
+            r##""{}","{}","{}","{}","{:?}","{}","{}","{}","{}","{}","{}","{}","{}","{}""##,
             i + 1,
             utxo.value.0,
             utxo.spending_key.to_hex(),
@@ -750,8 +1292,185 @@ fn write_utxos_to_csv_file(utxos: Vec<UnblindedOutput>, file_path: String) -> Re
             utxo.metadata_signature.public_nonce().to_hex(),
             utxo.metadata_signature.u().to_hex(),
             utxo.metadata_signature.v().to_hex(),
+            EmojiId::from_pubkey(&utxo.sender_offset_public_key),
         )
         .map_err(|e| CommandError::CSVFile(e.to_string()))?;
     }
     Ok(())
 }
+
+/// Import the UTXOs in a CSV file written by `export-utxos --csv-file`. Rows that cannot be parsed, or whose
+/// commitment does not match the reconstructed output, are skipped and reported.
+async fn import_utxos(
+    mut wallet: WalletSqlite,
+    args: &[ParsedArgument],
+    output: OutputMode,
+) -> Result<Value, CommandError> {
+    let file_path = match args[0].clone() {
+        ParsedArgument::CSVFileName(file) => Ok(file),
+        _ => Err(CommandError::Argument),
+    }?;
+    let contents = fs::read_to_string(file_path).map_err(|e| CommandError::CSVFile(e.to_string()))?;
+
+    let factory = PedersenCommitmentFactory::default();
+    let mut tx_ids = Vec::new();
+    let mut skipped = Vec::new();
+    // Skip the header row
+    for (i, row) in contents.lines().enumerate().skip(1) {
+        if row.trim().is_empty() {
+            continue;
+        }
+        let row_number = i + 1;
+        let utxo = match read_utxo_from_csv_row(row, &factory) {
+            Ok(utxo) => utxo,
+            Err(reason) => {
+                output.text(format!("Skipped row {}: {}", row_number, reason));
+                skipped.push(json!({ "row": row_number, "reason": reason }));
+                continue;
+            },
+        };
+        match wallet
+            .import_unblinded_utxo(utxo, &CommsPublicKey::default(), "Imported from CSV".to_string())
+            .await
+        {
+            Ok(tx_id) => tx_ids.push(tx_id),
+            Err(e) => {
+                output.text(format!("Skipped row {}: {}", row_number, e));
+                skipped.push(json!({ "row": row_number, "reason": e.to_string() }));
+            },
+        }
+    }
+
+    output.text(format!("Imported {} UTXO(s), skipped {}", tx_ids.len(), skipped.len()));
+    Ok(json!({ "imported": tx_ids.len(), "tx_ids": tx_ids, "skipped": skipped }))
+}
+
+/// Reconstruct an `UnblindedOutput` from a row written by `write_utxos_to_csv_file`, checking that its commitment
+/// matches the exported one
+fn read_utxo_from_csv_row(row: &str, factory: &PedersenCommitmentFactory) -> Result<UnblindedOutput, String> {
+    let fields = row.trim().trim_matches('"').split("\",\"").collect::<Vec<_>>();
+    if fields.len() < 13 {
+        return Err(format!("expected at least 13 columns, found {}", fields.len()));
+    }
+    let value = fields[1]
+        .parse::<u64>()
+        .map(MicroTari)
+        .map_err(|e| format!("invalid value: {}", e))?;
+    let spending_key = PrivateKey::from_hex(fields[2]).map_err(|e| format!("invalid spending_key: {}", e))?;
+    let features = OutputFeatures {
+        flags: parse_output_flags(fields[4])?,
+        maturity: fields[5]
+            .parse::<u64>()
+            .map_err(|e| format!("invalid maturity: {}", e))?,
+    };
+    let script = TariScript::from_hex(fields[6]).map_err(|e| format!("invalid script: {}", e))?;
+    let input_data = ExecutionStack::from_hex(fields[7]).map_err(|e| format!("invalid input_data: {}", e))?;
+    let script_private_key =
+        PrivateKey::from_hex(fields[8]).map_err(|e| format!("invalid script_private_key: {}", e))?;
+    let sender_offset_public_key =
+        PublicKey::from_hex(fields[9]).map_err(|e| format!("invalid sender_offset_public_key: {}", e))?;
+    let metadata_signature = ComSignature::new(
+        Commitment::from_hex(fields[10]).map_err(|e| format!("invalid public_nonce: {}", e))?,
+        PrivateKey::from_hex(fields[11]).map_err(|e| format!("invalid signature_u: {}", e))?,
+        PrivateKey::from_hex(fields[12]).map_err(|e| format!("invalid signature_v: {}", e))?,
+    );
+
+    let utxo = UnblindedOutput::new(
+        value,
+        spending_key,
+        Some(features),
+        script,
+        input_data,
+        script_private_key,
+        sender_offset_public_key,
+        metadata_signature,
+    );
+    let commitment = utxo
+        .as_transaction_input(factory)
+        .map_err(|e| e.to_string())?
+        .commitment
+        .to_hex();
+    if commitment != fields[3] {
+        return Err("commitment does not match the reconstructed output".to_string());
+    }
+    Ok(utxo)
+}
+
+/// Parse output flags in the `Debug` format used by the CSV export, e.g. `(empty)` or `COINBASE_OUTPUT`
+fn parse_output_flags(flags: &str) -> Result<OutputFlags, String> {
+    flags
+        .split(" | ")
+        .try_fold(OutputFlags::empty(), |flags, flag| match flag {
+            "(empty)" => Ok(flags),
+            "COINBASE_OUTPUT" => Ok(flags | OutputFlags::COINBASE_OUTPUT),
+            _ => Err(format!("unknown output flag '{}'", flag)),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::automation::commands::{parse_output_flags, read_utxo_from_csv_row, write_utxos_to_csv_file};
+    use std::fs;
+    use tari_core::transactions::{
+        helpers::create_test_input,
+        tari_amount::MicroTari,
+        transaction::OutputFlags,
+        types::CryptoFactories,
+    };
+
+    #[test]
+    fn test_read_utxo_from_csv_row() {
+        let factories = CryptoFactories::default();
+        let (_, utxo1) = create_test_input(MicroTari(1_000), 0, &factories.commitment);
+        let (_, utxo2) = create_test_input(MicroTari(2_000), 5, &factories.commitment);
+
+        let file_path = std::env::temp_dir().join(format!("import_utxos_test_{}.csv", std::process::id()));
+        write_utxos_to_csv_file(
+            vec![utxo1.clone(), utxo2.clone()],
+            file_path.to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let contents = fs::read_to_string(&file_path).unwrap();
+        fs::remove_file(&file_path).unwrap();
+
+        let rows = contents.lines().skip(1).collect::<Vec<_>>();
+        assert_eq!(rows.len(), 2);
+        for (row, expected) in rows.iter().zip(&[utxo1, utxo2]) {
+            let utxo = read_utxo_from_csv_row(row, &factories.commitment).unwrap();
+            assert_eq!(utxo.value, expected.value);
+            assert_eq!(utxo.spending_key, expected.spending_key);
+            assert_eq!(utxo.features, expected.features);
+            assert_eq!(utxo.script, expected.script);
+            assert_eq!(utxo.sender_offset_public_key, expected.sender_offset_public_key);
+            assert_eq!(utxo.metadata_signature, expected.metadata_signature);
+        }
+
+        // A row whose value was changed no longer matches its commitment
+        let mut fields = rows[0].split("\",\"").collect::<Vec<_>>();
+        fields[1] = "1001";
+        let tampered = fields.join("\",\"");
+        let result = read_utxo_from_csv_row(&tampered, &factories.commitment);
+        assert_eq!(
+            result.unwrap_err(),
+            "commitment does not match the reconstructed output".to_string()
+        );
+
+        let result = read_utxo_from_csv_row(r#""1","1000""#, &factories.commitment);
+        assert!(result.unwrap_err().contains("expected at least 13 columns"));
+
+        let mut fields = rows[0].split("\",\"").collect::<Vec<_>>();
+        fields[2] = "not-a-key";
+        let result = read_utxo_from_csv_row(&fields.join("\",\""), &factories.commitment);
+        assert!(result.unwrap_err().starts_with("invalid spending_key"));
+    }
+
+    #[test]
+    fn test_parse_output_flags() {
+        assert_eq!(parse_output_flags("(empty)").unwrap(), OutputFlags::empty());
+        assert_eq!(
+            parse_output_flags("COINBASE_OUTPUT").unwrap(),
+            OutputFlags::COINBASE_OUTPUT
+        );
+        assert!(parse_output_flags("NOT_A_FLAG").is_err());
+    }
+}
@@ -27,8 +27,9 @@ use log::*;
 use tari_app_utilities::utilities::ExitCodes;
 use tari_core::transactions::{tari_amount::MicroTariError, transaction::TransactionError};
 use tari_wallet::{
+    base_node_service::error::BaseNodeServiceError,
     error::{WalletError, WalletStorageError},
-    output_manager_service::error::OutputManagerError,
+    output_manager_service::{error::OutputManagerError, TxId},
     transaction_service::error::TransactionServiceError,
 };
 use thiserror::Error;
@@ -47,6 +48,8 @@ pub enum CommandError {
     TransactionServiceError(#[from] TransactionServiceError),
     #[error("Output manager error: `{0}`")]
     OutputManagerError(#[from] OutputManagerError),
+    #[error("Base node service error: `{0}`")]
+    BaseNodeServiceError(#[from] BaseNodeServiceError),
     #[error("Tokio join error `{0}`")]
     Join(#[from] JoinError),
     #[error("Config error `{0}`")]
@@ -59,6 +62,8 @@ pub enum CommandError {
     WalletError(#[from] WalletError),
     #[error("Wallet storage error `{0}`")]
     WalletStorageError(#[from] WalletStorageError),
+    #[error("Transaction {0} not found")]
+    TransactionNotFound(TxId),
 }
 
 impl From<CommandError> for ExitCodes {
@@ -76,6 +81,8 @@ pub enum ParseError {
     MicroTariAmount(#[from] MicroTariError),
     #[error("Failed to parse public key or emoji id.")]
     PublicKey,
+    #[error("Failed to parse public key, emoji id or node id.")]
+    PublicKeyOrNodeId,
     #[error("Failed to parse a missing {0}")]
     Empty(String),
     #[error("Failed to parse float.")]
@@ -351,16 +351,20 @@ impl wallet_server::Wallet for WalletGrpcServer {
             .await
             .map_err(|err| Status::internal(err.to_string()))?;
         let mut base_node_service = self.wallet.base_node_service.clone();
+        // The wallet only measures the latency to its base node, so min, max and average are the same
+        let latency_ms = base_node_service
+            .get_base_node_latency()
+            .await
+            .map_err(|err| Status::internal(err.to_string()))?
+            .map(|d| u32::try_from(d.as_millis()).unwrap_or(u32::MAX))
+            .unwrap_or_default();
 
         let resp = tari_rpc::NetworkStatusResponse {
             status: tari_rpc::ConnectivityStatus::from(status) as i32,
-            avg_latency_ms: base_node_service
-                .get_base_node_latency()
-                .await
-                .map_err(|err| Status::internal(err.to_string()))?
-                .map(|d| u32::try_from(d.as_millis()).unwrap_or(u32::MAX))
-                .unwrap_or_default(),
+            avg_latency_ms: latency_ms,
             num_node_connections: status.num_connected_nodes() as u32,
+            min_latency_ms: latency_ms,
+            max_latency_ms: latency_ms,
         };
 
         Ok(Response::new(resp))
@@ -378,18 +382,16 @@ impl wallet_server::Wallet for WalletGrpcServer {
             .map_err(|err| Status::internal(err.to_string()))?;
 
         let mut peers = Vec::with_capacity(connected_peers.len());
-        for peer in connected_peers {
-            peers.push(
-                peer_manager
-                    .find_by_node_id(peer.peer_node_id())
-                    .await
-                    .map_err(|err| Status::internal(err.to_string()))?,
-            );
+        for conn in connected_peers {
+            let direction = conn.direction();
+            let peer = peer_manager
+                .find_by_node_id(conn.peer_node_id())
+                .await
+                .map_err(|err| Status::internal(err.to_string()))?;
+            peers.push((peer, direction));
         }
 
-        let resp = tari_rpc::ListConnectedPeersResponse {
-            connected_peers: peers.into_iter().map(Into::into).collect(),
-        };
+        let resp = tari_rpc::ListConnectedPeersResponse::from_connected_peers(peers, None);
 
         Ok(Response::new(resp))
     }
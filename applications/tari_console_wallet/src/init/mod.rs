@@ -343,7 +343,8 @@ pub async fn init_wallet(
         peer_seeds: Default::default(),
         dns_seeds: Default::default(),
         dns_seeds_use_dnssec: true,
-    };
+    }
+    .with_network_test_address_policy();
 
     let base_node_service_config = BaseNodeServiceConfig::new(
         config.wallet_base_node_service_refresh_interval,
@@ -38,6 +38,7 @@ use crate::{
     },
     wallet_modes::PeerConfig,
 };
+use log::*;
 use tari_common::{configuration::Network, GlobalConfig};
 use tari_comms::peer_manager::Peer;
 use tari_wallet::WalletSqlite;
@@ -49,6 +50,10 @@ use tui::{
 };
 
 pub const LOG_TARGET: &str = "wallet::ui::app";
+/// Contacts are exported to and imported from this file, next to the console wallet database
+pub const CONTACTS_FILE_NAME: &str = "contacts.csv";
+/// The index of the transactions tab in the tabs container
+const TRANSACTIONS_TAB_INDEX: usize = 0;
 
 pub struct App<B: Backend> {
     pub title: String,
@@ -72,6 +77,7 @@ impl<B: Backend> App<B> {
         node_config: GlobalConfig,
         notifier: Notifier,
     ) -> Self {
+        let contacts_file = node_config.console_wallet_db_file.with_file_name(CONTACTS_FILE_NAME);
         let app_state = AppState::new(
             wallet.comms.node_identity().as_ref(),
             network,
@@ -83,7 +89,7 @@ impl<B: Backend> App<B> {
 
         let tabs = TabsContainer::<B>::new(title.clone())
             .add("Transactions".into(), Box::new(TransactionsTab::new()))
-            .add("Send".into(), Box::new(SendTab::new()))
+            .add("Send".into(), Box::new(SendTab::new(contacts_file)))
             .add("Receive".into(), Box::new(ReceiveTab::new()))
             .add("Network".into(), Box::new(NetworkTab::new(base_node_selected)));
 
@@ -114,6 +120,7 @@ impl<B: Backend> App<B> {
         match c {
             '\t' => {
                 self.tabs.next();
+                self.on_tab_shown();
             },
             _ => self.tabs.on_key(&mut self.app_state, c),
         }
@@ -133,10 +140,21 @@ impl<B: Backend> App<B> {
 
     pub fn on_right(&mut self) {
         self.tabs.next();
+        self.on_tab_shown();
     }
 
     pub fn on_left(&mut self) {
         self.tabs.previous();
+        self.on_tab_shown();
+    }
+
+    /// Called whenever a tab is shown, including the initially selected tab at startup
+    pub fn on_tab_shown(&mut self) {
+        if self.tabs.selected_index() == TRANSACTIONS_TAB_INDEX {
+            if let Err(e) = Handle::current().block_on(self.app_state.mark_transactions_viewed()) {
+                warn!(target: LOG_TARGET, "Failed to update the last viewed transactions marker: {}", e);
+            }
+        }
     }
 
     pub fn on_esc(&mut self) {
@@ -149,6 +167,12 @@ impl<B: Backend> App<B> {
 
     pub fn on_tick(&mut self) {
         Handle::current().block_on(self.app_state.update_cache());
+        let new_txs = if self.tabs.selected_index() == TRANSACTIONS_TAB_INDEX {
+            0
+        } else {
+            self.app_state.count_new_transactions()
+        };
+        self.tabs.set_badge(TRANSACTIONS_TAB_INDEX, new_txs);
         self.tabs.on_tick(&mut self.app_state);
     }
 
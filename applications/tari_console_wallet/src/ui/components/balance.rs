@@ -45,14 +45,16 @@ impl<B: Backend> Component<B> for Balance {
         f.render_widget(block, area);
 
         let balance = app_state.get_balance();
+        let balance_by_output_type = app_state.get_balance_by_output_type();
 
         let available_balance = Spans::from(vec![
             Span::styled("Available:", Style::default().fg(Color::Magenta)),
             Span::raw(" "),
             Span::raw(format!("{}", balance.available_balance)),
             Span::raw(format!(
-                " (Time Locked: {})",
-                balance.time_locked_balance.unwrap_or_else(|| MicroTari::from(0u64))
+                " (Time Locked: {}, Coinbase: {})",
+                balance.time_locked_balance.unwrap_or_else(|| MicroTari::from(0u64)),
+                balance_by_output_type.coinbase
             )),
         ]);
         let incoming_balance = Spans::from(vec![
@@ -35,6 +35,7 @@ pub struct NetworkTab {
     confirmation_dialog: bool,
     base_node_list_state: WindowedListState,
     detailed_base_node: Option<Peer>,
+    latency_report: Option<String>,
 }
 
 impl NetworkTab {
@@ -53,6 +54,7 @@ impl NetworkTab {
             confirmation_dialog: false,
             base_node_list_state: WindowedListState::new(),
             detailed_base_node: Some(base_node_selected),
+            latency_report: None,
         }
     }
 
@@ -76,7 +78,9 @@ impl NetworkTab {
             Span::styled("Up/Down Arrow Keys", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(" to select a new Base Node, "),
             Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" to set."),
+            Span::raw(" to set. Press "),
+            Span::styled("F", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to switch to the fastest Base Node."),
         ]))
         .block(Block::default());
         f.render_widget(instructions, areas[0]);
@@ -186,17 +190,24 @@ impl NetworkTab {
         let mut column0_items = Vec::with_capacity(peers.len());
         let mut column1_items = Vec::with_capacity(peers.len());
         let mut column2_items = Vec::with_capacity(peers.len());
+        let mut column3_items = Vec::with_capacity(peers.len());
         for p in peers.iter() {
-            column0_items.push(ListItem::new(Span::raw(p.node_id.to_string())));
-            column1_items.push(ListItem::new(Span::raw(p.public_key.to_string())));
-            column2_items.push(ListItem::new(Span::raw(p.user_agent.clone())));
+            column0_items.push(ListItem::new(Span::raw(p.peer.node_id.to_string())));
+            column1_items.push(ListItem::new(Span::raw(p.peer.public_key.to_string())));
+            let latency = p
+                .latency
+                .map(|l| format!("{} ms", l.as_millis()))
+                .unwrap_or_else(|| "-".to_string());
+            column2_items.push(ListItem::new(Span::raw(latency)));
+            column3_items.push(ListItem::new(Span::raw(p.user_agent().to_string())));
         }
         let column_list = MultiColumnList::new()
             .heading_style(Style::default().fg(Color::Magenta))
             .max_width(MAX_WIDTH)
             .add_column(Some("NodeID"), Some(27), column0_items)
             .add_column(Some("Public Key"), Some(65), column1_items)
-            .add_column(Some("User Agent"), Some(MAX_WIDTH.saturating_sub(93)), column2_items);
+            .add_column(Some("Latency"), Some(10), column2_items)
+            .add_column(Some("User Agent"), Some(MAX_WIDTH.saturating_sub(103)), column3_items);
         column_list.render(f, list_areas[0], &mut ListState::default());
     }
 
@@ -378,6 +389,19 @@ impl<B: Backend> Component<B> for NetworkTab {
             draw_dialog(f, area, "Error!".to_string(), msg, Color::Red, 120, 9);
         }
 
+        if let Some(report) = self.latency_report.clone() {
+            let height = 5 + app_state.get_base_node_list().len() as u16;
+            draw_dialog(
+                f,
+                area,
+                "Fastest Base Node".to_string(),
+                report,
+                Color::Green,
+                120,
+                height,
+            );
+        }
+
         if self.confirmation_dialog {
             draw_dialog(
                 f,
@@ -396,6 +420,10 @@ impl<B: Backend> Component<B> for NetworkTab {
             self.error_message = None;
             return;
         }
+        if self.latency_report.is_some() && '\n' == c {
+            self.latency_report = None;
+            return;
+        }
 
         if self.on_key_confirm_dialog(c, app_state) == KeyHandled::Handled {
             return;
@@ -426,6 +454,29 @@ impl<B: Backend> Component<B> for NetworkTab {
                     self.detailed_base_node = app_state.get_base_node_list().get(0).map(|(_, peer)| peer.clone());
                 }
             },
+            'f' => match Handle::current().block_on(app_state.select_fastest_base_node()) {
+                Ok((peer, latencies)) => {
+                    let mut report = latencies
+                        .iter()
+                        .map(|(p, latency)| {
+                            format!(
+                                "{}: {}",
+                                p.public_key,
+                                latency
+                                    .map(|l| format!("{} ms", l.as_millis()))
+                                    .unwrap_or_else(|| "unreachable".to_string())
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    report.push(format!("\nSelected {}", peer.public_key));
+                    self.latency_report = Some(report.join("\n"));
+                    self.detailed_base_node = Some(peer);
+                },
+                Err(e) => {
+                    warn!(target: LOG_TARGET, "Could not select the fastest base node: {}", e);
+                    self.error_message = Some(format!("Error selecting the fastest Base Node:\n{}", e));
+                },
+            },
             's' => {
                 // set the currently selected base node as a custom base node
                 let base_node = app_state.get_selected_base_node();
@@ -34,7 +34,14 @@ impl ReceiveTab {
             .margin(1)
             .split(help_body_area[0]);
 
-        let qr_code = Paragraph::new(app_state.get_identity().qr_code.as_str()).block(Block::default());
+        let qr_code = Paragraph::new(
+            app_state
+                .get_identity()
+                .qr_code
+                .as_deref()
+                .unwrap_or("QR code unavailable"),
+        )
+        .block(Block::default());
 
         f.render_widget(qr_code, chunks[0]);
 
@@ -45,6 +52,7 @@ impl ReceiveTab {
                     Constraint::Length(3),
                     Constraint::Length(3),
                     Constraint::Length(3),
+                    Constraint::Length(3),
                     Constraint::Min(1),
                 ]
                 .as_ref(),
@@ -87,6 +95,18 @@ impl ReceiveTab {
             .split(info_chunks[3]);
         let emoji_id = Paragraph::new(app_state.get_identity().emoji_id.as_str());
         f.render_widget(emoji_id, label_layout[0]);
+
+        // Deep Link
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(Span::styled("Deep Link", Style::default().fg(Color::White)));
+        f.render_widget(block, info_chunks[4]);
+        let label_layout = Layout::default()
+            .constraints([Constraint::Length(1)].as_ref())
+            .margin(1)
+            .split(info_chunks[4]);
+        let deep_link = Paragraph::new(app_state.get_deep_link());
+        f.render_widget(deep_link, label_layout[0]);
     }
 }
 
@@ -7,6 +7,7 @@ use crate::{
     },
     utils::formatting::display_compressed_string,
 };
+use std::{fs, path::PathBuf};
 use tari_core::transactions::tari_amount::MicroTari;
 use tari_wallet::types::DEFAULT_FEE_PER_GRAM;
 use tokio::{runtime::Handle, sync::watch};
@@ -37,10 +38,11 @@ pub struct SendTab {
     contacts_list_state: WindowedListState,
     send_result_watch: Option<watch::Receiver<UiTransactionSendStatus>>,
     confirmation_dialog: Option<ConfirmationDialogType>,
+    contacts_file: PathBuf,
 }
 
 impl SendTab {
-    pub fn new() -> Self {
+    pub fn new(contacts_file: PathBuf) -> Self {
         Self {
             balance: Balance::new(),
             send_input_mode: SendInputMode::None,
@@ -58,13 +60,20 @@ impl SendTab {
             contacts_list_state: WindowedListState::new(),
             send_result_watch: None,
             confirmation_dialog: None,
+            contacts_file,
         }
     }
 
-    fn draw_send_form<B>(&self, f: &mut Frame<B>, area: Rect, _app_state: &AppState)
+    fn draw_send_form<B>(&self, f: &mut Frame<B>, area: Rect, app_state: &AppState)
     where B: Backend {
+        let send_rate = app_state.get_session_send_rate();
+        let title = if send_rate > 0.0 {
+            format!("Send Transaction ({:.1} Txs/min this session)", send_rate)
+        } else {
+            "Send Transaction".to_string()
+        };
         let block = Block::default().borders(Borders::ALL).title(Span::styled(
-            "Send Transaction",
+            title,
             Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
         ));
         f.render_widget(block, area);
@@ -118,7 +127,7 @@ impl SendTab {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("(T)o (Public Key or Emoji ID) :"),
+                    .title("(T)o (Public Key, Emoji ID or tari:// link) :"),
             );
         f.render_widget(to_input, vert_chunks[1]);
 
@@ -202,6 +211,10 @@ impl SendTab {
             Span::raw(" to (d)elete a contact, "),
             Span::styled("N", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(" to create a (n)ew contact, "),
+            Span::styled("X", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to e(x)port and "),
+            Span::styled("I", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to (i)mport contacts, "),
             Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
             Span::raw(" to select."),
         ]))
@@ -515,6 +528,73 @@ impl SendTab {
                     self.edit_contact_mode = ContactInputMode::Alias;
                     return KeyHandled::Handled;
                 },
+                'x' => {
+                    let lines = app_state
+                        .export_contacts()
+                        .iter()
+                        .map(|c| format!("{},{}\n", c.alias, c.public_key))
+                        .collect::<String>();
+                    match fs::write(&self.contacts_file, lines) {
+                        Ok(_) => {
+                            self.success_message = Some(format!(
+                                "Contacts exported to {}\nPress Enter to continue.",
+                                self.contacts_file.display()
+                            ))
+                        },
+                        Err(e) => {
+                            self.error_message =
+                                Some(format!("Could not export contacts: {}\nPress Enter to continue.", e))
+                        },
+                    }
+                    return KeyHandled::Handled;
+                },
+                'i' => {
+                    let contents = match fs::read_to_string(&self.contacts_file) {
+                        Ok(contents) => contents,
+                        Err(e) => {
+                            self.error_message = Some(format!(
+                                "Could not read {}: {}\nPress Enter to continue.",
+                                self.contacts_file.display(),
+                                e
+                            ));
+                            return KeyHandled::Handled;
+                        },
+                    };
+                    // Each line is `alias,public key or emoji id`, the alias may itself contain commas
+                    let contacts = contents
+                        .lines()
+                        .filter(|l| !l.trim().is_empty())
+                        .map(|l| match l.rsplitn(2, ',').collect::<Vec<_>>().as_slice() {
+                            [public_key, alias] => (alias.trim().to_string(), public_key.trim().to_string()),
+                            _ => ("".to_string(), l.trim().to_string()),
+                        })
+                        .collect();
+                    match Handle::current().block_on(app_state.import_contacts(contacts)) {
+                        Ok(summary) if summary.failed.is_empty() => {
+                            self.success_message = Some(format!(
+                                "Imported {} contacts\nPress Enter to continue.",
+                                summary.imported
+                            ))
+                        },
+                        Ok(summary) => {
+                            self.error_message = Some(format!(
+                                "Imported {} contacts, could not import: {}\nPress Enter to continue.",
+                                summary.imported,
+                                summary
+                                    .failed
+                                    .iter()
+                                    .map(|(alias, _)| alias.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ))
+                        },
+                        Err(e) => {
+                            self.error_message =
+                                Some(format!("Could not import contacts: {}\nPress Enter to continue.", e))
+                        },
+                    }
+                    return KeyHandled::Handled;
+                },
                 _ => (),
             }
         }
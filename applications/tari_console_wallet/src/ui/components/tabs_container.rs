@@ -34,6 +34,7 @@ pub struct TabsContainer<B: Backend> {
     title: String,
     tabs: Vec<Box<dyn Component<B>>>,
     titles: Vec<String>,
+    badges: Vec<usize>,
     index: usize,
 }
 
@@ -43,6 +44,7 @@ impl<B: Backend> TabsContainer<B> {
             title,
             tabs: vec![],
             titles: vec![],
+            badges: vec![],
             index: 0,
         }
     }
@@ -50,9 +52,21 @@ impl<B: Backend> TabsContainer<B> {
     pub fn add(mut self, title: String, tab: Box<dyn Component<B>>) -> Self {
         self.tabs.push(tab);
         self.titles.push(title);
+        self.badges.push(0);
         self
     }
 
+    pub fn selected_index(&self) -> usize {
+        self.index
+    }
+
+    /// Set a count to display next to the title of the tab at the given index. A count of 0 hides the badge.
+    pub fn set_badge(&mut self, index: usize, count: usize) {
+        if let Some(badge) = self.badges.get_mut(index) {
+            *badge = count;
+        }
+    }
+
     pub fn next(&mut self) {
         self.index = (self.index + 1) % self.titles.len();
     }
@@ -69,7 +83,17 @@ impl<B: Backend> TabsContainer<B> {
         let titles = self
             .titles
             .iter()
-            .map(|t| Spans::from(Span::styled(t, Style::default().fg(Color::White))))
+            .zip(self.badges.iter())
+            .map(|(t, badge)| {
+                if *badge > 0 {
+                    Spans::from(vec![
+                        Span::styled(t, Style::default().fg(Color::White)),
+                        Span::styled(format!(" ({})", badge), Style::default().fg(Color::Yellow)),
+                    ])
+                } else {
+                    Spans::from(Span::styled(t, Style::default().fg(Color::White)))
+                }
+            })
             .collect();
         let tabs = Tabs::new(titles)
             .block(Block::default().borders(Borders::ALL).title(Span::styled(
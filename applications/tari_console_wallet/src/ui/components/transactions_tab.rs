@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::ui::{
     components::{balance::Balance, Component},
-    state::AppState,
+    state::{AppState, TransactionFilter},
     widgets::{draw_dialog, MultiColumnList, WindowedListState},
     MAX_WIDTH,
 };
@@ -30,6 +30,7 @@ pub struct TransactionsTab {
     completed_list_state: WindowedListState,
     detailed_transaction: Option<CompletedTransaction>,
     error_message: Option<String>,
+    balance_report: Option<String>,
     confirmation_dialog: bool,
 }
 
@@ -42,6 +43,7 @@ impl TransactionsTab {
             completed_list_state: WindowedListState::new(),
             detailed_transaction: None,
             error_message: None,
+            balance_report: None,
             confirmation_dialog: false,
         }
     }
@@ -149,9 +151,15 @@ impl TransactionsTab {
         } else {
             Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
         };
-        let block = Block::default()
-            .borders(Borders::ALL)
-            .title(Span::styled("Completed (T)ransactions", style));
+        let num_unconfirmed = app_state
+            .get_completed_txs_by_status(TransactionStatus::MinedUnconfirmed)
+            .len();
+        let title = if num_unconfirmed > 0 {
+            format!("Completed (T)ransactions - {} unconfirmed", num_unconfirmed)
+        } else {
+            "Completed (T)ransactions".to_string()
+        };
+        let block = Block::default().borders(Borders::ALL).title(Span::styled(title, style));
         f.render_widget(block, area);
 
         let completed_txs = app_state.get_completed_txs();
@@ -442,8 +450,18 @@ impl<B: Backend> Component<B> for TransactionsTab {
         span_vec.push(Span::raw(" selects a transaction, "));
         span_vec.push(Span::styled("C", Style::default().add_modifier(Modifier::BOLD)));
         span_vec.push(Span::raw(" cancels a selected Pending Tx, "));
+        span_vec.push(Span::styled("R", Style::default().add_modifier(Modifier::BOLD)));
+        span_vec.push(Span::raw(" rebroadcasts a selected Completed Tx, "));
+        span_vec.push(Span::styled("F", Style::default().add_modifier(Modifier::BOLD)));
+        span_vec.push(Span::raw(" resends a selected Pending Tx at the fast fee, "));
         span_vec.push(Span::styled("A", Style::default().add_modifier(Modifier::BOLD)));
         span_vec.push(Span::raw(" shows abandoned coinbase Txs, "));
+        span_vec.push(Span::styled("M", Style::default().add_modifier(Modifier::BOLD)));
+        span_vec.push(Span::raw(" hides all coinbase Txs, "));
+        span_vec.push(Span::styled("X", Style::default().add_modifier(Modifier::BOLD)));
+        span_vec.push(Span::raw(" hides cancelled Txs, "));
+        span_vec.push(Span::styled("V", Style::default().add_modifier(Modifier::BOLD)));
+        span_vec.push(Span::raw(" verifies the balance, "));
         span_vec.push(Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)));
         span_vec.push(Span::raw(" exits the list."));
 
@@ -457,6 +475,10 @@ impl<B: Backend> Component<B> for TransactionsTab {
             draw_dialog(f, area, "Error!".to_string(), msg, Color::Red, 120, 9);
         }
 
+        if let Some(msg) = self.balance_report.clone() {
+            draw_dialog(f, area, "Balance Check".to_string(), msg, Color::Green, 120, 9);
+        }
+
         if self.confirmation_dialog {
             draw_dialog(
                 f,
@@ -471,6 +493,11 @@ impl<B: Backend> Component<B> for TransactionsTab {
     }
 
     fn on_key(&mut self, app_state: &mut AppState, c: char) {
+        if self.balance_report.is_some() && '\n' == c {
+            self.balance_report = None;
+            return;
+        }
+
         if self.error_message.is_some() && '\n' == c {
             self.error_message = None;
             return;
@@ -531,7 +558,56 @@ impl<B: Backend> Component<B> for TransactionsTab {
                     self.confirmation_dialog = true;
                 }
             },
-            'a' => app_state.toggle_abandoned_coinbase_filter(),
+            'r' => {
+                if self.selected_tx_list == SelectedTransactionList::CompletedTxs {
+                    if let Some(i) = self.completed_list_state.selected() {
+                        if let Some(completed_tx) = app_state.get_completed_tx(i).cloned() {
+                            if let Err(e) =
+                                Handle::current().block_on(app_state.rebroadcast_transaction(completed_tx.tx_id))
+                            {
+                                self.error_message = Some(format!(
+                                    "Could not rebroadcast transaction.\n{}\nPress Enter to continue.",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                }
+            },
+            'f' => {
+                if self.selected_tx_list == SelectedTransactionList::PendingTxs {
+                    if let Some(i) = self.pending_list_state.selected() {
+                        if let Some(pending_tx) = app_state.get_pending_tx(i).cloned() {
+                            let fee_per_gram = app_state.get_fee_tiers().fast.as_u64();
+                            if let Err(e) =
+                                Handle::current().block_on(app_state.cancel_and_resend(pending_tx.tx_id, fee_per_gram))
+                            {
+                                self.error_message = Some(format!(
+                                    "Could not resend transaction.\n{}\nPress Enter to continue.",
+                                    e
+                                ));
+                            }
+                        }
+                    }
+                }
+            },
+            'v' => match Handle::current().block_on(app_state.reconcile_balance()) {
+                Ok(report) if report.is_balanced() => {
+                    self.balance_report = Some(format!("{}\nPress Enter to continue.", report));
+                },
+                Ok(report) => {
+                    self.balance_report = Some(format!(
+                        "Your balance does not match your transaction history.\n{}\nPress Enter to continue.",
+                        report
+                    ));
+                },
+                Err(e) => {
+                    self.error_message = Some(format!("Could not check balance.\n{}\nPress Enter to continue.", e));
+                },
+            },
+            'a' => app_state.toggle_completed_tx_filter(TransactionFilter::ABANDONED_COINBASES),
+            'm' => app_state.toggle_completed_tx_filter(TransactionFilter::ALL_COINBASES),
+            'x' => app_state.toggle_completed_tx_filter(TransactionFilter::CANCELLED),
             '\n' => match self.selected_tx_list {
                 SelectedTransactionList::None => {},
                 SelectedTransactionList::PendingTxs => {
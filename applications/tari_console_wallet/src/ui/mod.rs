@@ -65,6 +65,7 @@ pub fn run(app: App<CrosstermBackend<Stdout>>) -> Result<(), ExitCodes> {
             Result::<_, UiError>::Ok(())
         })
         .map_err(|e| ExitCodes::WalletError(e.to_string()))?;
+    app.on_tab_shown();
     crossterm_loop(app)
 }
 /// This is the main loop of the application UI using Crossterm based events
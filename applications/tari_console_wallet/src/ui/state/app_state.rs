@@ -30,44 +30,76 @@ use crate::{
         UiContact,
         UiError,
     },
-    utils::db::{CUSTOM_BASE_NODE_ADDRESS_KEY, CUSTOM_BASE_NODE_PUBLIC_KEY_KEY},
+    utils::db::{CUSTOM_BASE_NODE_ADDRESS_KEY, CUSTOM_BASE_NODE_PUBLIC_KEY_KEY, LAST_VIEWED_TRANSACTIONS_KEY},
     wallet_modes::PeerConfig,
 };
 use bitflags::bitflags;
-use futures::{stream::Fuse, StreamExt};
+use chrono::{NaiveDateTime, Utc};
+use futures::{future, stream::Fuse, StreamExt};
 use log::*;
 use qrcode::{render::unicode, QrCode};
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tari_common::{configuration::Network, GlobalConfig};
 use tari_comms::{
-    connectivity::ConnectivityEventRx,
+    connectivity::{ConnectivityEventRx, ConnectivityRequester},
     multiaddr::Multiaddr,
-    peer_manager::{NodeId, Peer, PeerFeatures, PeerFlags},
+    peer_manager::{NodeId, Peer, PeerFeatures, PeerFlags, PeerManager},
     types::CommsPublicKey,
+    validate_peer_addresses,
     NodeIdentity,
 };
-use tari_core::transactions::{
-    tari_amount::{uT, MicroTari},
-    types::PublicKey,
+use tari_core::{
+    base_node::rpc::BaseNodeWalletRpcClient,
+    mempool::FeePerGramStats,
+    transactions::{
+        tari_amount::{uT, MicroTari},
+        types::PublicKey,
+    },
 };
 use tari_crypto::{ristretto::RistrettoPublicKey, tari_utilities::hex::Hex};
 use tari_shutdown::ShutdownSignal;
 use tari_wallet::{
     base_node_service::{handle::BaseNodeEventReceiver, service::BaseNodeState},
     contacts_service::storage::database::Contact,
-    output_manager_service::{handle::OutputManagerEventReceiver, service::Balance, TxId, TxoValidationType},
+    output_manager_service::{
+        handle::{OutputManagerEventReceiver, OutputManagerHandle},
+        service::{Balance, BalanceByOutputType},
+        TxId,
+        TxoValidationType,
+    },
     transaction_service::{
-        handle::TransactionEventReceiver,
-        storage::models::{CompletedTransaction, TransactionStatus},
+        handle::{TransactionEventReceiver, TransactionServiceHandle},
+        storage::models::{
+            CompletedTransaction,
+            InboundTransaction,
+            OutboundTransaction,
+            TransactionDirection,
+            TransactionStatus,
+            WalletTransaction,
+        },
     },
-    types::ValidationRetryStrategy,
+    types::{ValidationRetryStrategy, DEFAULT_FEE_PER_GRAM},
     util::emoji::EmojiId,
     WalletSqlite,
 };
-use tokio::sync::{watch, RwLock};
+use tokio::{
+    sync::{watch, RwLock},
+    time,
+};
 
 const LOG_TARGET: &str = "wallet::console_wallet::app_state";
 
+/// Fee per gram tiers used when the base node has not provided any mempool fee stats
+const FALLBACK_MEDIUM_FEE_PER_GRAM: MicroTari = MicroTari(50);
+const FALLBACK_FAST_FEE_PER_GRAM: MicroTari = MicroTari(100);
+/// The maximum time allowed for dialing a candidate base node and measuring its latency
+const BASE_NODE_LATENCY_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[derive(Clone)]
 pub struct AppState {
     inner: Arc<RwLock<AppStateInner>>,
@@ -85,7 +117,20 @@ impl AppState {
         base_node_config: PeerConfig,
         node_config: GlobalConfig,
     ) -> Self {
-        let inner = AppStateInner::new(node_identity, network, wallet, base_node_selected, base_node_config);
+        // Validation against a newly selected base node backs off instead of retrying in a tight loop, in case the base
+        // node is unreachable
+        let validation_retry_strategy = ValidationRetryStrategy::LimitedWithBackoff(
+            node_config.wallet_validation_retry_limit,
+            Duration::from_secs(node_config.wallet_validation_retry_backoff),
+        );
+        let inner = AppStateInner::new(
+            node_identity,
+            network,
+            wallet,
+            base_node_selected,
+            base_node_config,
+            validation_retry_strategy,
+        );
         let cached_data = inner.data.clone();
 
         Self {
@@ -193,11 +238,8 @@ impl AppState {
         message: String,
         result_tx: watch::Sender<UiTransactionSendStatus>,
     ) -> Result<(), UiError> {
-        let inner = self.inner.write().await;
-        let public_key = match CommsPublicKey::from_hex(public_key.as_str()) {
-            Ok(pk) => pk,
-            Err(_) => EmojiId::str_to_pubkey(public_key.as_str()).map_err(|_| UiError::PublicKeyParseError)?,
-        };
+        let mut inner = self.inner.write().await;
+        let public_key = parse_destination(public_key.as_str(), self.node_config.network)?;
 
         let fee_per_gram = fee_per_gram * uT;
         let tx_service_handle = inner.wallet.transaction_service.clone();
@@ -209,6 +251,9 @@ impl AppState {
             tx_service_handle,
             result_tx,
         ));
+        inner.record_session_send();
+        drop(inner);
+        self.update_cache().await;
 
         Ok(())
     }
@@ -221,11 +266,8 @@ impl AppState {
         message: String,
         result_tx: watch::Sender<UiTransactionSendStatus>,
     ) -> Result<(), UiError> {
-        let inner = self.inner.write().await;
-        let public_key = match CommsPublicKey::from_hex(public_key.as_str()) {
-            Ok(pk) => pk,
-            Err(_) => EmojiId::str_to_pubkey(public_key.as_str()).map_err(|_| UiError::PublicKeyParseError)?,
-        };
+        let mut inner = self.inner.write().await;
+        let public_key = parse_destination(public_key.as_str(), self.node_config.network)?;
 
         let fee_per_gram = fee_per_gram * uT;
         let tx_service_handle = inner.wallet.transaction_service.clone();
@@ -237,10 +279,25 @@ impl AppState {
             tx_service_handle,
             result_tx,
         ));
+        inner.record_session_send();
+        drop(inner);
+        self.update_cache().await;
 
         Ok(())
     }
 
+    /// The average number of transactions sent per minute during this session. The elapsed time is taken to be at least
+    /// one minute so that the rate does not spike after the first few sends.
+    pub fn get_session_send_rate(&self) -> f64 {
+        match self.cached_data.session_first_send {
+            None => 0.0,
+            Some(first_send) => {
+                let elapsed_minutes = first_send.elapsed().as_secs_f64().max(60.0) / 60.0;
+                self.cached_data.session_sent_txs as f64 / elapsed_minutes
+            },
+        }
+    }
+
     pub async fn cancel_transaction(&mut self, tx_id: TxId) -> Result<(), UiError> {
         let inner = self.inner.write().await;
         let mut tx_service_handle = inner.wallet.transaction_service.clone();
@@ -248,10 +305,186 @@ impl AppState {
         Ok(())
     }
 
+    /// Cancel a pending outbound transaction and send the same amount to the same recipient using the given fee per
+    /// gram. Cancelling releases the inputs reserved by the output manager before the transaction service responds, so
+    /// they are available to the new transaction. Returns the TxId of the new transaction.
+    pub async fn cancel_and_resend(&mut self, tx_id: TxId, new_fee_per_gram: u64) -> Result<TxId, UiError> {
+        let mut inner = self.inner.write().await;
+        let mut tx_service_handle = inner.wallet.transaction_service.clone();
+        let tx = match tx_service_handle.get_any_transaction(tx_id).await? {
+            Some(WalletTransaction::PendingOutbound(tx)) => tx,
+            Some(WalletTransaction::PendingInbound(_)) => {
+                return Err(UiError::ResendError(tx_id, "transaction is inbound".to_string()));
+            },
+            Some(WalletTransaction::Completed(tx)) => {
+                let reason = if tx.status == TransactionStatus::MinedUnconfirmed ||
+                    tx.status == TransactionStatus::MinedConfirmed
+                {
+                    "transaction is already mined"
+                } else {
+                    "transaction is already completed"
+                };
+                return Err(UiError::ResendError(tx_id, reason.to_string()));
+            },
+            None => return Err(UiError::ResendError(tx_id, "transaction not found".to_string())),
+        };
+        if tx.cancelled {
+            return Err(UiError::ResendError(tx_id, "transaction is cancelled".to_string()));
+        }
+
+        tx_service_handle.cancel_transaction(tx_id).await?;
+        let new_tx_id = tx_service_handle
+            .send_transaction(tx.destination_public_key, tx.amount, new_fee_per_gram * uT, tx.message)
+            .await?;
+        info!(
+            target: LOG_TARGET,
+            "Cancelled transaction {} and resent it as transaction {} at {} µT/g", tx_id, new_tx_id, new_fee_per_gram
+        );
+
+        inner.record_session_send();
+        inner.refresh_full_transaction_state().await?;
+        drop(inner);
+        self.update_cache().await;
+        Ok(new_tx_id)
+    }
+
+    /// Resubmit a stuck transaction to the mempool of the selected base node. Mined and cancelled transactions are
+    /// rejected.
+    pub async fn rebroadcast_transaction(&mut self, tx_id: TxId) -> Result<(), UiError> {
+        let mut inner = self.inner.write().await;
+        let mut tx_service_handle = inner.wallet.transaction_service.clone();
+        let tx = tx_service_handle
+            .get_any_transaction(tx_id)
+            .await?
+            .map(CompletedTransaction::from)
+            .ok_or_else(|| UiError::RebroadcastError(tx_id, "transaction not found".to_string()))?;
+        if tx.cancelled {
+            return Err(UiError::RebroadcastError(tx_id, "transaction is cancelled".to_string()));
+        }
+        if tx.status == TransactionStatus::MinedUnconfirmed || tx.status == TransactionStatus::MinedConfirmed {
+            return Err(UiError::RebroadcastError(
+                tx_id,
+                "transaction is already mined".to_string(),
+            ));
+        }
+        tx_service_handle.rebroadcast_transaction(tx_id).await?;
+        inner.refresh_single_transaction_state(tx_id).await?;
+        drop(inner);
+        self.update_cache().await;
+        Ok(())
+    }
+
+    /// Fetch the wallet's recovery seed words from the output manager. The UI only has access to a wallet once it has
+    /// been opened (and decrypted, if a passphrase is set), so the words can only be read from an unlocked wallet. The
+    /// words are deliberately not stored in the cached app state; callers should zeroize them once displayed.
+    pub async fn get_seed_words(&self) -> Result<Vec<String>, UiError> {
+        let inner = self.inner.read().await;
+        let mut output_manager_service = inner.wallet.output_manager_service.clone();
+        let seed_words = output_manager_service.get_seed_words().await?;
+        Ok(seed_words)
+    }
+
+    /// Submit Unspent, Spent and Invalid TXO validation requests to the output manager, e.g. when the user suspects
+    /// their balance is stale. The requests use the configured validation retry strategy. This returns once the
+    /// requests are submitted; the validation results arrive through the output manager event stream.
+    pub async fn revalidate_outputs(&self) -> Result<(), UiError> {
+        let inner = self.inner.read().await;
+        let mut output_manager_service = inner.wallet.output_manager_service.clone();
+        let retry_strategy = inner.validation_retry_strategy;
+        drop(inner);
+        output_manager_service
+            .validate_txos(TxoValidationType::Unspent, retry_strategy)
+            .await?;
+        output_manager_service
+            .validate_txos(TxoValidationType::Spent, retry_strategy)
+            .await?;
+        output_manager_service
+            .validate_txos(TxoValidationType::Invalid, retry_strategy)
+            .await?;
+        Ok(())
+    }
+
+    /// Compare the balance reported by the output manager with the net value of the transaction history. If they
+    /// differ, a TXO revalidation is triggered.
+    pub async fn reconcile_balance(&self) -> Result<BalanceReconciliation, UiError> {
+        let inner = self.inner.read().await;
+        let mut output_manager_service = inner.wallet.output_manager_service.clone();
+        let mut transaction_service = inner.wallet.transaction_service.clone();
+        drop(inner);
+        let balance = output_manager_service.get_balance().await?;
+        let completed = transaction_service.get_completed_transactions().await?;
+        let pending_inbound = transaction_service.get_pending_inbound_transactions().await?;
+        let pending_outbound = transaction_service.get_pending_outbound_transactions().await?;
+        let history_total =
+            transaction_history_total(completed.values(), pending_inbound.values(), pending_outbound.values());
+
+        let mut report = BalanceReconciliation::new(&balance, history_total);
+        if !report.is_balanced() {
+            warn!(target: LOG_TARGET, "Balance discrepancy detected: {}", report);
+            self.revalidate_outputs().await?;
+            report.revalidation_triggered = true;
+        }
+        Ok(report)
+    }
+
     pub fn get_identity(&self) -> &MyIdentity {
         &self.cached_data.my_identity
     }
 
+    /// Returns the `tari://` deep link for this wallet, which can be shared where a QR code isn't practical
+    pub fn get_deep_link(&self) -> &str {
+        self.cached_data.my_identity.deep_link.as_str()
+    }
+
+    pub fn export_contacts(&self) -> Vec<UiContact> {
+        self.cached_data.contacts.clone()
+    }
+
+    /// Upsert a batch of `(alias, public key or emoji id)` contacts. Entries that cannot be parsed or stored are
+    /// skipped and reported in the returned summary. The contacts cache is only refreshed once, after all entries
+    /// are processed.
+    pub async fn import_contacts(&mut self, contacts: Vec<(String, String)>) -> Result<ContactImportSummary, UiError> {
+        let mut inner = self.inner.write().await;
+        let mut summary = ContactImportSummary::default();
+
+        for (alias, public_key_or_emoji_id) in contacts {
+            if alias.trim().is_empty() {
+                summary
+                    .failed
+                    .push((public_key_or_emoji_id, "Contact alias is empty".to_string()));
+                continue;
+            }
+
+            let public_key = match CommsPublicKey::from_hex(public_key_or_emoji_id.as_str()) {
+                Ok(pk) => pk,
+                Err(_) => match EmojiId::str_to_pubkey(public_key_or_emoji_id.as_str()) {
+                    Ok(pk) => pk,
+                    Err(_) => {
+                        summary.failed.push((alias, UiError::PublicKeyParseError.to_string()));
+                        continue;
+                    },
+                },
+            };
+
+            let contact = Contact {
+                alias: alias.clone(),
+                public_key,
+            };
+            match inner.wallet.contacts_service.upsert_contact(contact).await {
+                Ok(_) => summary.imported += 1,
+                Err(e) => {
+                    warn!(target: LOG_TARGET, "Could not import contact `{}`: {}", alias, e);
+                    summary.failed.push((alias, e.to_string()));
+                },
+            }
+        }
+
+        inner.refresh_contacts_state().await?;
+        drop(inner);
+        self.update_cache().await;
+        Ok(summary)
+    }
+
     pub fn get_contacts(&self) -> &Vec<UiContact> {
         &self.cached_data.contacts
     }
@@ -293,18 +526,19 @@ impl AppState {
     }
 
     pub fn get_completed_txs(&self) -> Vec<&CompletedTransaction> {
-        if self
-            .completed_tx_filter
-            .contains(TransactionFilter::ABANDONED_COINBASES)
-        {
-            self.cached_data
-                .completed_txs
-                .iter()
-                .filter(|tx| !(tx.cancelled && tx.status == TransactionStatus::Coinbase))
-                .collect()
-        } else {
-            self.cached_data.completed_txs.iter().collect()
-        }
+        self.cached_data
+            .completed_txs
+            .iter()
+            .filter(|tx| !self.completed_tx_filter.excludes(tx))
+            .collect()
+    }
+
+    /// Returns the completed transactions with the given status, subject to the same filters as `get_completed_txs`
+    pub fn get_completed_txs_by_status(&self, status: TransactionStatus) -> Vec<&CompletedTransaction> {
+        self.get_completed_txs()
+            .into_iter()
+            .filter(|tx| tx.status == status)
+            .collect()
     }
 
     pub fn get_confirmations(&self, tx_id: &TxId) -> Option<&u64> {
@@ -320,7 +554,7 @@ impl AppState {
         }
     }
 
-    pub fn get_connected_peers(&self) -> &Vec<Peer> {
+    pub fn get_connected_peers(&self) -> &Vec<ConnectedPeerInfo> {
         &self.cached_data.connected_peers
     }
 
@@ -328,10 +562,27 @@ impl AppState {
         &self.cached_data.balance
     }
 
+    /// The available balance broken down by output type, e.g. to show how much of it is coinbase
+    pub fn get_balance_by_output_type(&self) -> &BalanceByOutputType {
+        &self.cached_data.balance_by_output_type
+    }
+
     pub fn get_base_node_state(&self) -> &BaseNodeState {
         &self.cached_data.base_node_state
     }
 
+    /// Returns the Slow/Medium/Fast fee per gram tiers for the send screen. The tiers are derived from the base node's
+    /// mempool fee stats as of the last base node poll, and fall back to fixed values if the base node is offline,
+    /// does not provide mempool stats or has an empty mempool.
+    pub fn get_fee_tiers(&self) -> FeeTiers {
+        self.cached_data
+            .base_node_state
+            .fee_per_gram_stats
+            .filter(|stats| stats.max_fee_per_gram > MicroTari(0))
+            .map(FeeTiers::from)
+            .unwrap_or_default()
+    }
+
     pub fn get_selected_base_node(&self) -> &Peer {
         &self.cached_data.base_node_selected
     }
@@ -354,9 +605,71 @@ impl AppState {
         Ok(())
     }
 
+    /// Measure the latency to every base node in the base node list and switch to the reachable one with the lowest
+    /// latency. The base nodes are measured concurrently, so this takes at most `BASE_NODE_LATENCY_TIMEOUT`. Returns
+    /// the selected peer and the latency measured for each candidate, where `None` means that the candidate could
+    /// not be reached.
+    pub async fn select_fastest_base_node(&mut self) -> Result<(Peer, Vec<(Peer, Option<Duration>)>), UiError> {
+        let (connectivity, peer_manager) = {
+            let inner = self.inner.read().await;
+            (inner.wallet.comms.connectivity(), inner.wallet.comms.peer_manager())
+        };
+
+        let peer_manager = &peer_manager;
+        let measurements = self.cached_data.base_node_list.iter().map(|(_, peer)| {
+            let connectivity = connectivity.clone();
+            async move {
+                let latency = match time::timeout(
+                    BASE_NODE_LATENCY_TIMEOUT,
+                    measure_base_node_latency(connectivity, peer_manager, peer),
+                )
+                .await
+                {
+                    Ok(Ok(latency)) => Some(latency),
+                    Ok(Err(e)) => {
+                        debug!(target: LOG_TARGET, "Could not measure latency to base node {}: {}", peer.node_id, e);
+                        None
+                    },
+                    Err(_) => {
+                        debug!(target: LOG_TARGET, "Timed out measuring latency to base node {}", peer.node_id);
+                        None
+                    },
+                };
+                info!(
+                    target: LOG_TARGET,
+                    "Base node {} latency: {}",
+                    peer.node_id,
+                    latency
+                        .map(|l| format!("{} ms", l.as_millis()))
+                        .unwrap_or_else(|| "unreachable".to_string())
+                );
+                (peer.clone(), latency)
+            }
+        });
+        let latencies = future::join_all(measurements).await;
+
+        let fastest = latencies
+            .iter()
+            .filter_map(|(peer, latency)| latency.map(|l| (peer, l)))
+            .min_by_key(|(_, latency)| *latency)
+            .map(|(peer, _)| peer.clone())
+            .ok_or(UiError::NoReachableBaseNode)?;
+        info!(target: LOG_TARGET, "Selected fastest base node {}", fastest.node_id);
+        self.set_base_node_peer(fastest.clone()).await?;
+        self.update_cache().await;
+
+        Ok((fastest, latencies))
+    }
+
     pub async fn set_custom_base_node(&mut self, public_key: String, address: String) -> Result<Peer, UiError> {
         let pub_key = PublicKey::from_hex(public_key.as_str())?;
         let addr = address.parse::<Multiaddr>().map_err(|_| UiError::AddressParseError)?;
+        // A mainnet wallet cannot be served by a base node on a loopback or link-local address. Test addresses are
+        // allowed on the testnets, as they are in the connection manager.
+        if self.node_config.network == Network::MainNet {
+            validate_peer_addresses(&[addr.clone()], false)
+                .map_err(|e| UiError::InvalidNetworkAddress(self.node_config.network, e.to_string()))?;
+        }
         let node_id = NodeId::from_key(&pub_key);
         let peer = Peer::new(
             pub_key,
@@ -386,15 +699,64 @@ impl AppState {
         (&self.node_config.transaction_num_confirmations_required).to_owned()
     }
 
-    pub fn toggle_abandoned_coinbase_filter(&mut self) {
-        self.completed_tx_filter.toggle(TransactionFilter::ABANDONED_COINBASES);
+    /// Record that the user has viewed the transaction list. The marker is persisted in the wallet database.
+    pub async fn mark_transactions_viewed(&mut self) -> Result<(), UiError> {
+        let mut inner = self.inner.write().await;
+        inner.set_last_viewed_timestamp(Utc::now().naive_utc()).await?;
+        drop(inner);
+        self.update_cache().await;
+        Ok(())
+    }
+
+    /// Returns the number of completed transactions that arrived since the transaction list was last viewed
+    pub fn count_new_transactions(&self) -> usize {
+        match self.cached_data.last_viewed_timestamp {
+            Some(last_viewed) => self
+                .get_completed_txs()
+                .iter()
+                .filter(|tx| tx.timestamp > last_viewed)
+                .count(),
+            None => self.get_completed_txs().len(),
+        }
+    }
+
+    pub fn toggle_completed_tx_filter(&mut self, filter: TransactionFilter) {
+        self.completed_tx_filter.toggle(filter);
     }
 }
 
+/// Dial the base node and measure the round trip time of a tip info request over the base node wallet RPC, which is
+/// the same latency measure that the base node service reports for the selected base node.
+async fn measure_base_node_latency(
+    mut connectivity: ConnectivityRequester,
+    peer_manager: &PeerManager,
+    peer: &Peer,
+) -> Result<Duration, String> {
+    if !peer_manager.exists(&peer.public_key).await {
+        peer_manager.add_peer(peer.clone()).await.map_err(|e| e.to_string())?;
+    }
+    let mut conn = connectivity
+        .dial_peer(peer.node_id.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+    let mut client = conn
+        .connect_rpc::<BaseNodeWalletRpcClient>()
+        .await
+        .map_err(|e| e.to_string())?;
+    client.get_tip_info().await.map_err(|e| e.to_string())?;
+    client
+        .get_last_request_latency()
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "No latency was recorded".to_string())
+}
+
 pub struct AppStateInner {
     updated: bool,
     data: AppStateData,
     wallet: WalletSqlite,
+    /// The retry strategy for the validation that is triggered when the base node is changed
+    validation_retry_strategy: ValidationRetryStrategy,
 }
 
 impl AppStateInner {
@@ -404,6 +766,7 @@ impl AppStateInner {
         wallet: WalletSqlite,
         base_node_selected: Peer,
         base_node_config: PeerConfig,
+        validation_retry_strategy: ValidationRetryStrategy,
     ) -> Self {
         let data = AppStateData::new(node_identity, network, base_node_selected, base_node_config);
 
@@ -411,6 +774,7 @@ impl AppStateInner {
             updated: false,
             data,
             wallet,
+            validation_retry_strategy,
         }
     }
 
@@ -425,6 +789,14 @@ impl AppStateInner {
         }
     }
 
+    pub fn record_session_send(&mut self) {
+        self.data.session_sent_txs += 1;
+        if self.data.session_first_send.is_none() {
+            self.data.session_first_send = Some(Instant::now());
+        }
+        self.updated = true;
+    }
+
     pub async fn refresh_full_transaction_state(&mut self) -> Result<(), UiError> {
         let mut pending_transactions: Vec<CompletedTransaction> = Vec::new();
         pending_transactions.extend(
@@ -479,11 +851,36 @@ impl AppStateInner {
         });
 
         self.data.completed_txs = completed_transactions;
+        self.refresh_last_viewed_timestamp().await?;
         self.refresh_balance().await?;
         self.updated = true;
         Ok(())
     }
 
+    async fn refresh_last_viewed_timestamp(&mut self) -> Result<(), UiError> {
+        self.data.last_viewed_timestamp = self
+            .wallet
+            .db
+            .get_client_key_value(LAST_VIEWED_TRANSACTIONS_KEY.to_string())
+            .await?
+            .and_then(|value| value.parse::<i64>().ok())
+            .map(|secs| NaiveDateTime::from_timestamp(secs, 0));
+        Ok(())
+    }
+
+    pub async fn set_last_viewed_timestamp(&mut self, timestamp: NaiveDateTime) -> Result<(), UiError> {
+        self.wallet
+            .db
+            .set_client_key_value(
+                LAST_VIEWED_TRANSACTIONS_KEY.to_string(),
+                timestamp.timestamp().to_string(),
+            )
+            .await?;
+        self.data.last_viewed_timestamp = Some(timestamp);
+        self.updated = true;
+        Ok(())
+    }
+
     pub async fn refresh_single_confirmation_state(&mut self, tx_id: TxId, confirmations: u64) -> Result<(), UiError> {
         let stat = self.data.confirmations.entry(tx_id).or_insert(confirmations);
         *stat = confirmations;
@@ -583,27 +980,49 @@ impl AppStateInner {
         let connections = self.wallet.comms.connectivity().get_active_connections().await?;
 
         let peer_manager = self.wallet.comms.peer_manager();
-        let mut peers = Vec::with_capacity(connections.len());
-        for c in connections.iter() {
-            if let Ok(p) = peer_manager.find_by_node_id(c.peer_node_id()).await {
-                peers.push(p);
+        let base_node_state = &self.data.base_node_state;
+        let mut connected_peers = Vec::with_capacity(connections.len());
+        for c in connections {
+            // Connections can drop while we iterate, skip them rather than failing the whole refresh
+            if !c.is_connected() {
+                continue;
+            }
+            if let Ok(peer) = peer_manager.find_by_node_id(c.peer_node_id()).await {
+                // The base node service already measures the latency of the selected base node. Other peers fall back
+                // to the time it took to set up the connection, which is only known for outbound connections.
+                let latency = base_node_state
+                    .base_node_peer
+                    .as_ref()
+                    .filter(|p| p.node_id == peer.node_id)
+                    .and(base_node_state.latency)
+                    .or_else(|| c.setup_duration());
+                connected_peers.push(ConnectedPeerInfo { peer, latency });
             }
         }
 
-        self.data.connected_peers = peers;
+        self.data.connected_peers = connected_peers;
         self.updated = true;
         Ok(())
     }
 
     pub async fn refresh_balance(&mut self) -> Result<(), UiError> {
         let balance = self.wallet.output_manager_service.get_balance().await?;
+        let balance_by_output_type = self.wallet.output_manager_service.get_balance_by_output_type().await?;
         self.data.balance = balance;
+        self.data.balance_by_output_type = balance_by_output_type;
         self.updated = true;
 
         Ok(())
     }
 
     pub async fn refresh_base_node_state(&mut self, state: BaseNodeState) -> Result<(), UiError> {
+        if let (Some(base_node_peer), Some(latency)) = (state.base_node_peer.as_ref(), state.latency) {
+            self.data
+                .connected_peers
+                .iter_mut()
+                .filter(|p| p.peer.node_id == base_node_peer.node_id)
+                .for_each(|p| p.latency = Some(latency));
+        }
         self.data.base_node_state = state;
         self.updated = true;
 
@@ -638,26 +1057,13 @@ impl AppStateInner {
     }
 
     pub async fn set_base_node_peer(&mut self, peer: Peer) -> Result<(), UiError> {
+        // Validate the peer before mutating any state so that a malformed peer cannot leave the wallet half-updated
+        let address = base_node_address(&peer)?;
         self.wallet
-            .set_base_node_peer(
-                peer.public_key.clone(),
-                peer.clone()
-                    .addresses
-                    .first()
-                    .ok_or(UiError::NoAddressError)?
-                    .to_string(),
-            )
+            .set_base_node_peer(peer.public_key.clone(), address.clone())
             .await?;
 
-        if let Err(e) = self
-            .wallet
-            .transaction_service
-            .validate_transactions(ValidationRetryStrategy::UntilSuccess)
-            .await
-        {
-            error!(target: LOG_TARGET, "Problem validating transactions: {}", e);
-        }
-        self.validate_outputs().await;
+        self.trigger_validations().await;
 
         self.data.base_node_previous = self.data.base_node_selected.clone();
         self.data.base_node_selected = peer.clone();
@@ -667,33 +1073,20 @@ impl AppStateInner {
             target: LOG_TARGET,
             "Setting new base node peer for wallet: {}::{}",
             peer.public_key,
-            peer.addresses.first().ok_or(UiError::NoAddressError)?.to_string(),
+            address,
         );
 
         Ok(())
     }
 
     pub async fn set_custom_base_node_peer(&mut self, peer: Peer) -> Result<(), UiError> {
+        // Validate the peer before mutating any state so that a malformed peer cannot leave the wallet half-updated
+        let address = base_node_address(&peer)?;
         self.wallet
-            .set_base_node_peer(
-                peer.public_key.clone(),
-                peer.clone()
-                    .addresses
-                    .first()
-                    .ok_or(UiError::NoAddressError)?
-                    .to_string(),
-            )
+            .set_base_node_peer(peer.public_key.clone(), address.clone())
             .await?;
 
-        if let Err(e) = self
-            .wallet
-            .transaction_service
-            .validate_transactions(ValidationRetryStrategy::UntilSuccess)
-            .await
-        {
-            error!(target: LOG_TARGET, "Problem validating transactions: {}", e);
-        }
-        self.validate_outputs().await;
+        self.trigger_validations().await;
 
         self.data.base_node_previous = self.data.base_node_selected.clone();
         self.data.base_node_selected = peer.clone();
@@ -710,17 +1103,14 @@ impl AppStateInner {
             .await?;
         self.wallet
             .db
-            .set_client_key_value(
-                CUSTOM_BASE_NODE_ADDRESS_KEY.to_string(),
-                peer.addresses.first().ok_or(UiError::NoAddressError)?.to_string(),
-            )
+            .set_client_key_value(CUSTOM_BASE_NODE_ADDRESS_KEY.to_string(), address.clone())
             .await?;
 
         info!(
             target: LOG_TARGET,
             "Setting custom base node peer for wallet: {}::{}",
             peer.public_key,
-            peer.addresses.first().ok_or(UiError::NoAddressError)?.to_string(),
+            address,
         );
 
         Ok(())
@@ -735,15 +1125,7 @@ impl AppStateInner {
             )
             .await?;
 
-        if let Err(e) = self
-            .wallet
-            .transaction_service
-            .validate_transactions(ValidationRetryStrategy::UntilSuccess)
-            .await
-        {
-            error!(target: LOG_TARGET, "Problem validating transactions: {}", e);
-        }
-        self.validate_outputs().await;
+        self.trigger_validations().await;
 
         self.data.base_node_peer_custom = None;
         self.data.base_node_selected = previous;
@@ -762,33 +1144,57 @@ impl AppStateInner {
         Ok(())
     }
 
+    /// Validates transactions and outputs against the current base node
+    async fn trigger_validations(&mut self) {
+        request_validations(
+            &mut self.wallet.transaction_service,
+            &mut self.wallet.output_manager_service,
+            self.validation_retry_strategy,
+        )
+        .await;
+    }
+
     pub async fn validate_outputs(&mut self) {
-        if let Err(e) = self
-            .wallet
-            .output_manager_service
-            .validate_txos(TxoValidationType::Unspent, ValidationRetryStrategy::UntilSuccess)
-            .await
-        {
-            error!(target: LOG_TARGET, "Problem validating UTXOs: {}", e);
-        }
+        request_output_validations(&mut self.wallet.output_manager_service, self.validation_retry_strategy).await;
+    }
+}
 
-        if let Err(e) = self
-            .wallet
-            .output_manager_service
-            .validate_txos(TxoValidationType::Spent, ValidationRetryStrategy::UntilSuccess)
-            .await
-        {
-            error!(target: LOG_TARGET, "Problem validating STXOs: {}", e);
-        }
+/// Requests transaction validation followed by Unspent, Spent and Invalid TXO validation. Failures are logged.
+async fn request_validations(
+    transaction_service: &mut TransactionServiceHandle,
+    output_manager_service: &mut OutputManagerHandle,
+    retry_strategy: ValidationRetryStrategy,
+) {
+    if let Err(e) = transaction_service.validate_transactions(retry_strategy).await {
+        error!(target: LOG_TARGET, "Problem validating transactions: {}", e);
+    }
+    request_output_validations(output_manager_service, retry_strategy).await;
+}
 
-        if let Err(e) = self
-            .wallet
-            .output_manager_service
-            .validate_txos(TxoValidationType::Invalid, ValidationRetryStrategy::UntilSuccess)
-            .await
-        {
-            error!(target: LOG_TARGET, "Problem validating Invalid TXOs: {}", e);
-        }
+/// Requests Unspent, Spent and Invalid TXO validation. Failures are logged.
+async fn request_output_validations(
+    output_manager_service: &mut OutputManagerHandle,
+    retry_strategy: ValidationRetryStrategy,
+) {
+    if let Err(e) = output_manager_service
+        .validate_txos(TxoValidationType::Unspent, retry_strategy)
+        .await
+    {
+        error!(target: LOG_TARGET, "Problem validating UTXOs: {}", e);
+    }
+
+    if let Err(e) = output_manager_service
+        .validate_txos(TxoValidationType::Spent, retry_strategy)
+        .await
+    {
+        error!(target: LOG_TARGET, "Problem validating STXOs: {}", e);
+    }
+
+    if let Err(e) = output_manager_service
+        .validate_txos(TxoValidationType::Invalid, retry_strategy)
+        .await
+    {
+        error!(target: LOG_TARGET, "Problem validating Invalid TXOs: {}", e);
     }
 }
 
@@ -799,13 +1205,17 @@ struct AppStateData {
     confirmations: HashMap<TxId, u64>,
     my_identity: MyIdentity,
     contacts: Vec<UiContact>,
-    connected_peers: Vec<Peer>,
+    connected_peers: Vec<ConnectedPeerInfo>,
     balance: Balance,
+    balance_by_output_type: BalanceByOutputType,
     base_node_state: BaseNodeState,
     base_node_selected: Peer,
     base_node_previous: Peer,
     base_node_list: Vec<(String, Peer)>,
     base_node_peer_custom: Option<Peer>,
+    session_sent_txs: u64,
+    session_first_send: Option<Instant>,
+    last_viewed_timestamp: Option<NaiveDateTime>,
 }
 
 impl AppStateData {
@@ -817,21 +1227,28 @@ impl AppStateData {
     ) -> Self {
         let eid = EmojiId::from_pubkey(node_identity.public_key()).to_string();
         let qr_link = format!("tari://{}/pubkey/{}", network, &node_identity.public_key().to_hex());
-        let code = QrCode::new(qr_link).unwrap();
-        let image = code
-            .render::<unicode::Dense1x2>()
-            .dark_color(unicode::Dense1x2::Dark)
-            .light_color(unicode::Dense1x2::Light)
-            .build()
-            .lines()
-            .skip(1)
-            .fold("".to_string(), |acc, l| format!("{}{}\n", acc, l));
+        let image = match QrCode::new(&qr_link) {
+            Ok(code) => Some(
+                code.render::<unicode::Dense1x2>()
+                    .dark_color(unicode::Dense1x2::Dark)
+                    .light_color(unicode::Dense1x2::Light)
+                    .build()
+                    .lines()
+                    .skip(1)
+                    .fold("".to_string(), |acc, l| format!("{}{}\n", acc, l)),
+            ),
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Could not generate QR code for '{}': {}", qr_link, e);
+                None
+            },
+        };
 
         let identity = MyIdentity {
             public_key: node_identity.public_key().to_string(),
             public_address: node_identity.public_address().to_string(),
             emoji_id: eid,
             qr_code: image,
+            deep_link: qr_link,
         };
         let base_node_previous = base_node_selected.clone();
 
@@ -864,11 +1281,15 @@ impl AppStateData {
             contacts: Vec::new(),
             connected_peers: Vec::new(),
             balance: Balance::zero(),
+            balance_by_output_type: BalanceByOutputType::default(),
             base_node_state: BaseNodeState::default(),
             base_node_selected,
             base_node_previous,
             base_node_list,
             base_node_peer_custom: base_node_config.base_node_custom,
+            session_sent_txs: 0,
+            session_first_send: None,
+            last_viewed_timestamp: None,
         }
     }
 }
@@ -878,7 +1299,133 @@ pub struct MyIdentity {
     pub public_key: String,
     pub public_address: String,
     pub emoji_id: String,
-    pub qr_code: String,
+    /// The rendered QR code of the wallet's tari link, or None if it could not be generated
+    pub qr_code: Option<String>,
+    /// The `tari://{network}/pubkey/{hex}` link that the QR code encodes
+    pub deep_link: String,
+}
+
+/// A connected peer along with live metadata about its connection
+#[derive(Clone, Debug)]
+pub struct ConnectedPeerInfo {
+    pub peer: Peer,
+    /// The round trip time measured by the base node service for the selected base node, otherwise the time it took to
+    /// set up the connection, if known
+    pub latency: Option<Duration>,
+}
+
+impl ConnectedPeerInfo {
+    /// The user agent the peer reported when the connection was established
+    pub fn user_agent(&self) -> &str {
+        &self.peer.user_agent
+    }
+}
+
+/// The outcome of a bulk contact import
+#[derive(Clone, Debug, Default)]
+pub struct ContactImportSummary {
+    /// The number of contacts that were added or updated
+    pub imported: usize,
+    /// The alias (or public key, if the alias was missing) and failure reason of each contact that was not imported
+    pub failed: Vec<(String, String)>,
+}
+
+/// The result of comparing the balance reported by the output manager with the net value of the transaction history
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BalanceReconciliation {
+    /// The available and pending incoming balance reported by the output manager
+    pub wallet_balance: MicroTari,
+    /// The value received less the value sent and fees paid in all valid transactions, None if the history spends
+    /// more than it received
+    pub transaction_history_total: Option<MicroTari>,
+    /// True if a TXO revalidation was requested because the values differ
+    pub revalidation_triggered: bool,
+}
+
+impl BalanceReconciliation {
+    pub fn new(balance: &Balance, transaction_history_total: Option<MicroTari>) -> Self {
+        Self {
+            wallet_balance: balance.available_balance + balance.pending_incoming_balance,
+            transaction_history_total,
+            revalidation_triggered: false,
+        }
+    }
+
+    pub fn is_balanced(&self) -> bool {
+        self.transaction_history_total == Some(self.wallet_balance)
+    }
+}
+
+impl fmt::Display for BalanceReconciliation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Wallet balance: {}, Transaction history: ", self.wallet_balance)?;
+        match self.transaction_history_total {
+            Some(total) => write!(f, "{}", total)?,
+            None => write!(f, "negative")?,
+        }
+        if self.revalidation_triggered {
+            write!(f, " (revalidation requested)")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the value the wallet should hold according to its transaction history: the amounts received less the
+/// amounts sent and the fees paid. Pending transactions are included because the output manager already counts their
+/// outputs as pending incoming or encumbered. Transactions sent to ourselves only cost the fee. Returns None if more
+/// was spent than received.
+fn transaction_history_total<'a>(
+    completed: impl IntoIterator<Item = &'a CompletedTransaction>,
+    pending_inbound: impl IntoIterator<Item = &'a InboundTransaction>,
+    pending_outbound: impl IntoIterator<Item = &'a OutboundTransaction>,
+) -> Option<MicroTari> {
+    let mut received = MicroTari::from(0);
+    let mut spent = MicroTari::from(0);
+    for tx in completed.into_iter().filter(|tx| tx.valid) {
+        if tx.source_public_key == tx.destination_public_key {
+            spent += tx.fee;
+            continue;
+        }
+        match tx.direction {
+            TransactionDirection::Outbound => spent += tx.amount + tx.fee,
+            _ => received += tx.amount,
+        }
+    }
+    for tx in pending_inbound {
+        received += tx.amount;
+    }
+    for tx in pending_outbound {
+        spent += tx.amount + tx.fee;
+    }
+    received.checked_sub(spent)
+}
+
+/// Fee per gram choices offered on the send screen
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeTiers {
+    pub slow: MicroTari,
+    pub medium: MicroTari,
+    pub fast: MicroTari,
+}
+
+impl Default for FeeTiers {
+    fn default() -> Self {
+        Self {
+            slow: DEFAULT_FEE_PER_GRAM,
+            medium: FALLBACK_MEDIUM_FEE_PER_GRAM,
+            fast: FALLBACK_FAST_FEE_PER_GRAM,
+        }
+    }
+}
+
+impl From<FeePerGramStats> for FeeTiers {
+    fn from(stats: FeePerGramStats) -> Self {
+        // Never go below the wallet default, and keep the tiers in ascending order
+        let slow = stats.min_fee_per_gram.max(DEFAULT_FEE_PER_GRAM);
+        let medium = stats.avg_fee_per_gram.max(slow);
+        let fast = stats.max_fee_per_gram.max(medium);
+        Self { slow, medium, fast }
+    }
 }
 
 #[derive(Clone)]
@@ -895,5 +1442,250 @@ bitflags! {
     pub struct TransactionFilter: u8 {
         const NONE = 0b0000_0000;
         const ABANDONED_COINBASES = 0b0000_0001;
+        const ALL_COINBASES = 0b0000_0010;
+        const CANCELLED = 0b0000_0100;
+    }
+}
+
+impl TransactionFilter {
+    /// Returns true if the given transaction should be hidden by any of the set filters
+    pub fn excludes(&self, tx: &CompletedTransaction) -> bool {
+        let is_coinbase = tx.status == TransactionStatus::Coinbase || tx.coinbase_block_height.is_some();
+        (self.contains(TransactionFilter::ABANDONED_COINBASES) &&
+            tx.cancelled &&
+            tx.status == TransactionStatus::Coinbase) ||
+            (self.contains(TransactionFilter::ALL_COINBASES) && is_coinbase) ||
+            (self.contains(TransactionFilter::CANCELLED) && tx.cancelled)
+    }
+}
+
+/// Returns the address used to connect to a base node peer, which is its first address
+fn base_node_address(peer: &Peer) -> Result<String, UiError> {
+    peer.addresses
+        .first()
+        .map(|address| address.to_string())
+        .ok_or(UiError::NoAddressError)
+}
+
+/// Parses a `tari://{network}/pubkey/{hex}` deep link into its network and public key
+pub fn parse_tari_uri(uri: &str) -> Result<(Network, PublicKey), UiError> {
+    let invalid = || UiError::InvalidTariUri(uri.to_string());
+    let path = uri.strip_prefix("tari://").ok_or_else(invalid)?;
+    let parts = path.split('/').collect::<Vec<_>>();
+    match parts.as_slice() {
+        [network, "pubkey", public_key] => {
+            let network = network.parse::<Network>().map_err(|_| invalid())?;
+            let public_key = PublicKey::from_hex(public_key).map_err(|_| UiError::PublicKeyParseError)?;
+            Ok((network, public_key))
+        },
+        _ => Err(invalid()),
+    }
+}
+
+/// Resolves a send destination given as a `tari://` deep link, a hex public key or an emoji id. Deep links for a
+/// network other than `network` are rejected.
+fn parse_destination(destination: &str, network: Network) -> Result<CommsPublicKey, UiError> {
+    if destination.starts_with("tari://") {
+        let (link_network, public_key) = parse_tari_uri(destination)?;
+        if link_network != network {
+            return Err(UiError::NetworkMismatch(link_network, network));
+        }
+        return Ok(public_key);
+    }
+    match CommsPublicKey::from_hex(destination) {
+        Ok(pk) => Ok(pk),
+        Err(_) => EmojiId::str_to_pubkey(destination).map_err(|_| UiError::PublicKeyParseError),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::rngs::OsRng;
+    use tari_core::transactions::{transaction::Transaction, types::BlindingFactor};
+    use tari_crypto::keys::PublicKey as PublicKeyTrait;
+    use tari_service_framework::reply_channel;
+    use tari_wallet::{
+        output_manager_service::handle::{OutputManagerRequest, OutputManagerResponse},
+        transaction_service::handle::{TransactionServiceRequest, TransactionServiceResponse},
+    };
+    use tokio::sync::broadcast;
+
+    fn completed_transaction(
+        source_public_key: &PublicKey,
+        destination_public_key: &PublicKey,
+        amount: MicroTari,
+        fee: MicroTari,
+        direction: TransactionDirection,
+    ) -> CompletedTransaction {
+        CompletedTransaction::new(
+            0,
+            source_public_key.clone(),
+            destination_public_key.clone(),
+            amount,
+            fee,
+            Transaction::new(
+                vec![],
+                vec![],
+                vec![],
+                BlindingFactor::default(),
+                BlindingFactor::default(),
+            ),
+            TransactionStatus::MinedConfirmed,
+            String::new(),
+            Utc::now().naive_utc(),
+            direction,
+            None,
+        )
+    }
+
+    #[test]
+    fn it_reconciles_the_balance_with_the_transaction_history() {
+        let (_secret_key, our_key) = PublicKey::random_keypair(&mut OsRng);
+        let (_secret_key, their_key) = PublicKey::random_keypair(&mut OsRng);
+        let mut invalid = completed_transaction(&their_key, &our_key, 500 * uT, 0 * uT, TransactionDirection::Inbound);
+        invalid.valid = false;
+        let history = vec![
+            completed_transaction(&their_key, &our_key, 100 * uT, 0 * uT, TransactionDirection::Inbound),
+            completed_transaction(&our_key, &their_key, 30 * uT, 2 * uT, TransactionDirection::Outbound),
+            completed_transaction(&our_key, &our_key, 40 * uT, 1 * uT, TransactionDirection::Inbound),
+            invalid,
+        ];
+        let total = transaction_history_total(&history, &[], &[]);
+        assert_eq!(total, Some(67 * uT));
+
+        let mut balance = Balance {
+            available_balance: 60 * uT,
+            time_locked_balance: None,
+            pending_incoming_balance: 7 * uT,
+            pending_outgoing_balance: 0 * uT,
+        };
+        assert!(BalanceReconciliation::new(&balance, total).is_balanced());
+
+        // An output the history knows nothing about, e.g. one that was reorged out but is still counted as unspent
+        balance.available_balance = 160 * uT;
+        let report = BalanceReconciliation::new(&balance, total);
+        assert!(!report.is_balanced());
+        assert_eq!(
+            report.to_string(),
+            format!("Wallet balance: {}, Transaction history: {}", 167 * uT, 67 * uT)
+        );
+
+        let overspent = transaction_history_total(&history[1..2], &[], &[]);
+        assert_eq!(overspent, None);
+        assert!(!BalanceReconciliation::new(&Balance::zero(), overspent).is_balanced());
+    }
+
+    #[tokio_macros::test]
+    async fn it_requests_each_validation_exactly_once() {
+        let (transaction_sender, mut transaction_requests) = reply_channel::unbounded();
+        let (output_manager_sender, mut output_manager_requests) = reply_channel::unbounded();
+        let mut transaction_service = TransactionServiceHandle::new(transaction_sender, broadcast::channel(1).0);
+        let mut output_manager_service = OutputManagerHandle::new(output_manager_sender, broadcast::channel(1).0);
+
+        let transaction_task = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            while let Some(request_context) = transaction_requests.next().await {
+                let (request, reply_tx) = request_context.split();
+                requests.push(request);
+                let _ = reply_tx.send(Ok(TransactionServiceResponse::ValidationStarted(1)));
+            }
+            requests
+        });
+        let output_manager_task = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            while let Some(request_context) = output_manager_requests.next().await {
+                let (request, reply_tx) = request_context.split();
+                requests.push(request);
+                let _ = reply_tx.send(Ok(OutputManagerResponse::UtxoValidationStarted(1)));
+            }
+            requests
+        });
+
+        request_validations(
+            &mut transaction_service,
+            &mut output_manager_service,
+            ValidationRetryStrategy::LimitedWithBackoff(3, Duration::from_secs(1)),
+        )
+        .await;
+        drop(transaction_service);
+        drop(output_manager_service);
+
+        let requests = transaction_task.await.unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(matches!(
+            requests[0],
+            TransactionServiceRequest::ValidateTransactions(ValidationRetryStrategy::LimitedWithBackoff(3, _))
+        ));
+
+        let validation_types = output_manager_task
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|request| match request {
+                OutputManagerRequest::ValidateUtxos(
+                    validation_type,
+                    ValidationRetryStrategy::LimitedWithBackoff(3, _),
+                ) => validation_type,
+                request => panic!("Unexpected output manager request: {}", request),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(validation_types, vec![
+            TxoValidationType::Unspent,
+            TxoValidationType::Spent,
+            TxoValidationType::Invalid
+        ]);
+    }
+
+    #[test]
+    fn it_rejects_base_node_peers_without_an_address() {
+        let (_secret_key, public_key) = PublicKey::random_keypair(&mut OsRng);
+        let node_id = NodeId::from_key(&public_key);
+        let mut peer = Peer::new(
+            public_key,
+            node_id,
+            Vec::<Multiaddr>::new().into(),
+            PeerFlags::empty(),
+            PeerFeatures::COMMUNICATION_NODE,
+            Default::default(),
+            Default::default(),
+        );
+        assert!(matches!(base_node_address(&peer), Err(UiError::NoAddressError)));
+
+        let address = "/ip4/127.0.0.1/tcp/18189".parse::<Multiaddr>().unwrap();
+        peer.addresses.add_net_address(&address);
+        assert_eq!(base_node_address(&peer).unwrap(), address.to_string());
+    }
+
+    #[test]
+    fn it_parses_tari_uris() {
+        let (_secret_key, public_key) = PublicKey::random_keypair(&mut OsRng);
+        let public_key_hex = public_key.to_hex();
+
+        let (network, parsed) = parse_tari_uri(&format!("tari://weatherwax/pubkey/{}", public_key_hex)).unwrap();
+        assert_eq!(network, Network::Weatherwax);
+        assert_eq!(parsed, public_key);
+
+        assert!(parse_tari_uri(&public_key_hex).is_err());
+        assert!(parse_tari_uri("tari://weatherwax/pubkey").is_err());
+        assert!(parse_tari_uri(&format!("tari://nonet/pubkey/{}", public_key_hex)).is_err());
+        assert!(parse_tari_uri(&format!("tari://weatherwax/emoji/{}", public_key_hex)).is_err());
+        assert!(parse_tari_uri("tari://weatherwax/pubkey/not_hex").is_err());
+    }
+
+    #[test]
+    fn it_rejects_deep_links_for_other_networks() {
+        let (_secret_key, public_key) = PublicKey::random_keypair(&mut OsRng);
+        let link = format!("tari://weatherwax/pubkey/{}", public_key.to_hex());
+
+        assert_eq!(parse_destination(&link, Network::Weatherwax).unwrap(), public_key);
+        assert!(matches!(
+            parse_destination(&link, Network::MainNet),
+            Err(UiError::NetworkMismatch(Network::Weatherwax, Network::MainNet))
+        ));
+        assert_eq!(
+            parse_destination(&public_key.to_hex(), Network::MainNet).unwrap(),
+            public_key
+        );
     }
 }
@@ -136,6 +136,13 @@ impl WalletEventMonitor {
                                     BaseNodeEvent::BaseNodePeerSet(peer) => {
                                         self.trigger_base_node_peer_refresh(*peer).await;
                                     }
+                                    BaseNodeEvent::BaseNodePeerUnreachable(peer) => {
+                                        warn!(
+                                            target: LOG_TARGET,
+                                            "Base node {} is unreachable, consider switching to another base node",
+                                            peer.node_id
+                                        );
+                                    }
                                 }
                             },
                             Err(_) => debug!(target: LOG_TARGET, "Lagging read on base node event broadcast channel"),
@@ -1,9 +1,10 @@
+use tari_common::configuration::Network;
 use tari_comms::connectivity::ConnectivityError;
 use tari_crypto::tari_utilities::hex::HexError;
 use tari_wallet::{
     contacts_service::error::ContactsServiceError,
     error::{WalletError, WalletStorageError},
-    output_manager_service::error::OutputManagerError,
+    output_manager_service::{error::OutputManagerError, TxId},
     transaction_service::error::TransactionServiceError,
 };
 use thiserror::Error;
@@ -30,4 +31,16 @@ pub enum UiError {
     AddressParseError,
     #[error("Peer did not include an address")]
     NoAddressError,
+    #[error("Address is not valid on {0}: {1}")]
+    InvalidNetworkAddress(Network, String),
+    #[error("Invalid tari link: {0}")]
+    InvalidTariUri(String),
+    #[error("The link is for {0} but this wallet is on {1}")]
+    NetworkMismatch(Network, Network),
+    #[error("None of the known base nodes could be reached")]
+    NoReachableBaseNode,
+    #[error("Transaction {0} cannot be rebroadcast: {1}")]
+    RebroadcastError(TxId, String),
+    #[error("Transaction {0} cannot be cancelled and resent: {1}")]
+    ResendError(TxId, String),
 }
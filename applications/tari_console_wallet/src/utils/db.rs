@@ -32,6 +32,7 @@ use tari_wallet::WalletSqlite;
 pub const LOG_TARGET: &str = "wallet::utils::db";
 pub const CUSTOM_BASE_NODE_PUBLIC_KEY_KEY: &str = "console_wallet_custom_base_node_public_key";
 pub const CUSTOM_BASE_NODE_ADDRESS_KEY: &str = "console_wallet_custom_base_node_address";
+pub const LAST_VIEWED_TRANSACTIONS_KEY: &str = "console_wallet_last_viewed_transactions_timestamp";
 
 /// This helper function will attempt to read a stored base node public key and address from the wallet database.
 /// If both are found they are used to construct and return a Peer.
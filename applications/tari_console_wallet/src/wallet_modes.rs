@@ -20,7 +20,10 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 use crate::{
-    automation::{command_parser::parse_command, commands::command_runner},
+    automation::{
+        command_parser::parse_command,
+        commands::{command_runner, OutputMode},
+    },
     grpc::WalletGrpcServer,
     notifier::Notifier,
     recovery::wallet_recovery,
@@ -130,11 +133,19 @@ impl PeerConfig {
 
 pub fn command_mode(config: WalletModeConfig, wallet: WalletSqlite, command: String) -> Result<(), ExitCodes> {
     let WalletModeConfig {
-        global_config, handle, ..
+        global_config,
+        handle,
+        bootstrap,
+        ..
     } = config.clone();
     let commands = vec![parse_command(&command)?];
     info!(target: LOG_TARGET, "Starting wallet command mode");
-    handle.block_on(command_runner(commands, wallet.clone(), global_config))?;
+    handle.block_on(command_runner(
+        commands,
+        wallet.clone(),
+        global_config,
+        command_output_mode(&bootstrap),
+    ))?;
 
     info!(target: LOG_TARGET, "Completed wallet command mode");
 
@@ -143,10 +154,16 @@ pub fn command_mode(config: WalletModeConfig, wallet: WalletSqlite, command: Str
 
 pub fn script_mode(config: WalletModeConfig, wallet: WalletSqlite, path: PathBuf) -> Result<(), ExitCodes> {
     let WalletModeConfig {
-        global_config, handle, ..
+        global_config,
+        handle,
+        bootstrap,
+        ..
     } = config.clone();
+    let output = command_output_mode(&bootstrap);
     info!(target: LOG_TARGET, "Starting wallet script mode");
-    println!("Starting wallet script mode");
+    if output == OutputMode::Text {
+        println!("Starting wallet script mode");
+    }
     let script = fs::read_to_string(path).map_err(|e| ExitCodes::InputError(e.to_string()))?;
 
     if script.is_empty() {
@@ -155,7 +172,9 @@ pub fn script_mode(config: WalletModeConfig, wallet: WalletSqlite, path: PathBuf
 
     let mut commands = Vec::new();
 
-    println!("Parsing commands...");
+    if output == OutputMode::Text {
+        println!("Parsing commands...");
+    }
     for command in script.lines() {
         // skip empty lines and 'comments' starting with #
         if !command.is_empty() && !command.starts_with('#') {
@@ -163,16 +182,26 @@ pub fn script_mode(config: WalletModeConfig, wallet: WalletSqlite, path: PathBuf
             commands.push(parse_command(command)?);
         }
     }
-    println!("{} commands parsed successfully.", commands.len());
-
-    println!("Starting the command runner!");
-    handle.block_on(command_runner(commands, wallet.clone(), global_config))?;
+    if output == OutputMode::Text {
+        println!("{} commands parsed successfully.", commands.len());
+        println!("Starting the command runner!");
+    }
+    handle.block_on(command_runner(commands, wallet.clone(), global_config, output))?;
 
     info!(target: LOG_TARGET, "Completed wallet script mode");
 
     wallet_or_exit(config, wallet)
 }
 
+/// The command runner prints JSON if requested on the command line, otherwise human readable text
+fn command_output_mode(bootstrap: &ConfigBootstrap) -> OutputMode {
+    if bootstrap.command_mode_json {
+        OutputMode::Json
+    } else {
+        OutputMode::Text
+    }
+}
+
 /// Prompts the user to continue to the wallet, or exit.
 fn wallet_or_exit(config: WalletModeConfig, wallet: WalletSqlite) -> Result<(), ExitCodes> {
     if config.bootstrap.command_mode_auto_exit {
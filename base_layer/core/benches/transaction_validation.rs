@@ -0,0 +1,81 @@
+// Copyright 2021. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+#[cfg(not(feature = "benches"))]
+mod benches {
+    pub fn main() {
+        println!("Enable the `benches` feature to run benches");
+    }
+}
+
+#[cfg(feature = "benches")]
+mod benches {
+    use criterion::{criterion_group, Criterion};
+    use std::time::Duration;
+    use tari_core::{
+        transactions::{
+            tari_amount::{uT, T},
+            transaction::Transaction,
+            types::CryptoFactories,
+        },
+        tx,
+        validation::{transaction_validators::TxInternalConsistencyValidator, MempoolTransactionValidation},
+    };
+
+    // A malformed transaction is rejected by the kernel pre-check, so it should validate orders of magnitude faster
+    // than a well-formed one, which has to run the bulletproof verification of each of its outputs.
+    fn internal_consistency(c: &mut Criterion) {
+        let validator = TxInternalConsistencyValidator::new(CryptoFactories::default());
+        let (tx, _, _) = tx!(1*T, fee: 100*uT, inputs: 2, outputs: 4);
+        let no_kernels = Transaction::new(
+            tx.body.inputs().clone(),
+            tx.body.outputs().clone(),
+            Vec::new(),
+            tx.offset.clone(),
+            tx.script_offset.clone(),
+        );
+
+        c.bench_function("Validate well-formed transaction", move |b| {
+            b.iter(|| validator.validate(&tx).unwrap());
+        });
+
+        let validator = TxInternalConsistencyValidator::new(CryptoFactories::default());
+        c.bench_function("Reject transaction without kernels", move |b| {
+            b.iter(|| validator.validate(&no_kernels).unwrap_err());
+        });
+    }
+
+    criterion_group!(
+        name = transaction_validation;
+        config = Criterion::default().warm_up_time(Duration::from_millis(500)).sample_size(10);
+        targets = internal_consistency
+    );
+
+    pub fn main() {
+        transaction_validation();
+        criterion::Criterion::default().configure_from_args().final_summary();
+    }
+}
+
+fn main() {
+    benches::main();
+}
@@ -0,0 +1,72 @@
+// Copyright 2021. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::cmp::Ordering;
+use tari_common_types::chain_metadata::ChainMetadata;
+
+/// The state of the local chain relative to another chain, as determined by [ChainComparison::compare]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainComparison {
+    /// The local chain has more accumulated difficulty
+    Ahead,
+    /// The local chain has less accumulated difficulty
+    Behind,
+    /// Both chains have the same tip
+    Equal,
+    /// Both chains have the same accumulated difficulty, but different tips
+    Forked,
+}
+
+impl ChainComparison {
+    /// Compare the local chain to another chain. The chain with the most accumulated difficulty is the best chain, so a
+    /// fork is only reported when neither chain has more accumulated difficulty than the other.
+    pub fn compare(local: &ChainMetadata, other: &ChainMetadata) -> Self {
+        match local.accumulated_difficulty().cmp(&other.accumulated_difficulty()) {
+            Ordering::Greater => ChainComparison::Ahead,
+            Ordering::Less => ChainComparison::Behind,
+            Ordering::Equal if local.best_block() == other.best_block() => ChainComparison::Equal,
+            Ordering::Equal => ChainComparison::Forked,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_compares_by_accumulated_difficulty() {
+        let local = ChainMetadata::new(10, vec![1], 0, 0, 500_000);
+        let other = ChainMetadata::new(12, vec![2], 0, 0, 499_999);
+        assert_eq!(ChainComparison::compare(&local, &other), ChainComparison::Ahead);
+        assert_eq!(ChainComparison::compare(&other, &local), ChainComparison::Behind);
+    }
+
+    #[test]
+    fn it_detects_equal_and_forked_chains() {
+        let local = ChainMetadata::new(10, vec![1], 0, 0, 500_000);
+        assert_eq!(ChainComparison::compare(&local, &local.clone()), ChainComparison::Equal);
+
+        let other = ChainMetadata::new(10, vec![2], 0, 0, 500_000);
+        assert_eq!(ChainComparison::compare(&local, &other), ChainComparison::Forked);
+    }
+}
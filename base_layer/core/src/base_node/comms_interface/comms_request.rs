@@ -28,6 +28,8 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Error, Formatter};
+use strum_macros::IntoStaticStr;
+use tari_common_types::types::BlockHash;
 use tari_crypto::tari_utilities::hex::Hex;
 
 /// A container for the parameters required for a FetchMmrState request.
@@ -39,7 +41,7 @@ pub struct MmrStateRequest {
 }
 
 /// API Request enum
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, IntoStaticStr)]
 pub enum NodeCommsRequest {
     GetChainMetadata,
     FetchHeaders(Vec<u64>),
@@ -48,6 +50,7 @@ pub enum NodeCommsRequest {
     FetchMatchingUtxos(Vec<HashOutput>),
     FetchMatchingTxos(Vec<HashOutput>),
     FetchMatchingBlocks(Vec<u64>),
+    FetchBlocksInRange { start: u64, end: u64 },
     FetchBlocksWithHashes(Vec<HashOutput>),
     FetchBlocksWithKernels(Vec<Signature>),
     FetchBlocksWithUtxos(Vec<Commitment>),
@@ -56,12 +59,29 @@ pub enum NodeCommsRequest {
     GetNewBlockTemplate(GetNewBlockTemplateRequest),
     GetNewBlock(NewBlockTemplate),
     FetchKernelByExcessSig(Signature),
+    FetchDeletedBitmap { height: Option<u64> },
+    GetAccumulatedDifficulty(u64),
+    GetAccumulatedDifficulties { start: u64, end: u64 },
+    GetMempoolStats,
+    FetchUtxoStatus(Commitment),
+    FetchBlockBodies(Vec<u64>),
+    FetchOrphanHeaders,
+    BlockExists(BlockHash),
+}
+
+impl NodeCommsRequest {
+    /// The name of the request variant, without any of its parameters.
+    pub fn name(&self) -> &'static str {
+        self.into()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetNewBlockTemplateRequest {
     pub algo: PowAlgorithm,
     pub max_weight: u64,
+    /// Mempool transactions containing any of these kernel excess signatures are not included in the template
+    pub exclude_kernel_sigs: Vec<Signature>,
 }
 
 impl Display for NodeCommsRequest {
@@ -75,12 +95,19 @@ impl Display for NodeCommsRequest {
             FetchMatchingUtxos(v) => write!(f, "FetchMatchingUtxos (n={})", v.len()),
             FetchMatchingTxos(v) => write!(f, "FetchMatchingTxos (n={})", v.len()),
             FetchMatchingBlocks(v) => write!(f, "FetchMatchingBlocks (n={})", v.len()),
+            FetchBlocksInRange { start, end } => write!(f, "FetchBlocksInRange ({}-{})", start, end),
             FetchBlocksWithHashes(v) => write!(f, "FetchBlocksWithHashes (n={})", v.len()),
             FetchBlocksWithKernels(v) => write!(f, "FetchBlocksWithKernels (n={})", v.len()),
             FetchBlocksWithUtxos(v) => write!(f, "FetchBlocksWithUtxos (n={})", v.len()),
             GetHeaderByHash(v) => write!(f, "GetHeaderByHash({})", v.to_hex()),
             GetBlockByHash(v) => write!(f, "GetBlockByHash({})", v.to_hex()),
-            GetNewBlockTemplate(v) => write!(f, "GetNewBlockTemplate ({}) with weight {}", v.algo, v.max_weight),
+            GetNewBlockTemplate(v) => write!(
+                f,
+                "GetNewBlockTemplate ({}) with weight {} excluding {} kernel(s)",
+                v.algo,
+                v.max_weight,
+                v.exclude_kernel_sigs.len()
+            ),
             GetNewBlock(b) => write!(f, "GetNewBlock (Block Height={})", b.header.height),
             FetchKernelByExcessSig(s) => write!(
                 f,
@@ -88,6 +115,17 @@ impl Display for NodeCommsRequest {
                 s.get_public_nonce().to_hex(),
                 s.get_signature().to_hex()
             ),
+            FetchDeletedBitmap { height: Some(height) } => write!(f, "FetchDeletedBitmap (height={})", height),
+            FetchDeletedBitmap { height: None } => write!(f, "FetchDeletedBitmap (tip)"),
+            GetAccumulatedDifficulty(height) => write!(f, "GetAccumulatedDifficulty (height={})", height),
+            GetAccumulatedDifficulties { start, end } => {
+                write!(f, "GetAccumulatedDifficulties ({}-{})", start, end)
+            },
+            GetMempoolStats => write!(f, "GetMempoolStats"),
+            FetchUtxoStatus(c) => write!(f, "FetchUtxoStatus (commitment={})", c.to_hex()),
+            FetchBlockBodies(v) => write!(f, "FetchBlockBodies (n={})", v.len()),
+            FetchOrphanHeaders => write!(f, "FetchOrphanHeaders"),
+            BlockExists(v) => write!(f, "BlockExists({})", v.to_hex()),
         }
     }
 }
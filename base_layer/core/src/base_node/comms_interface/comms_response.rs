@@ -22,9 +22,11 @@
 
 use crate::{
     blocks::{block_header::BlockHeader, Block, NewBlockTemplate},
-    chain_storage::HistoricalBlock,
+    chain_storage::{HistoricalBlock, UtxoStatus},
     proof_of_work::Difficulty,
     transactions::{
+        aggregated_body::AggregateBody,
+        tari_amount::MicroTari,
         transaction::{TransactionKernel, TransactionOutput},
         types::HashOutput,
     },
@@ -43,7 +45,11 @@ pub enum NodeCommsResponse {
     TransactionOutputs(Vec<TransactionOutput>),
     HistoricalBlocks(Vec<HistoricalBlock>),
     HistoricalBlock(Box<Option<HistoricalBlock>>),
-    NewBlockTemplate(NewBlockTemplate),
+    /// A new block template. `from_cache` is true if the template was reused from a recent, identical request.
+    NewBlockTemplate {
+        block_template: NewBlockTemplate,
+        from_cache: bool,
+    },
     NewBlock {
         success: bool,
         error: Option<String>,
@@ -52,6 +58,23 @@ pub enum NodeCommsResponse {
     TargetDifficulty(Difficulty),
     FetchHeadersAfterResponse(Vec<BlockHeader>),
     MmrNodes(Vec<HashOutput>, Vec<u8>),
+    DeletedBitmap(Vec<u8>),
+    AccumulatedDifficulty {
+        monero: Difficulty,
+        sha3: Difficulty,
+    },
+    /// The accumulated (height, monero, sha3) difficulties of a range of blocks, ordered by height
+    AccumulatedDifficulties(Vec<(u64, Difficulty, Difficulty)>),
+    MempoolStats {
+        unconfirmed_txs: u64,
+        total_weight: u64,
+        total_fees: MicroTari,
+    },
+    UtxoStatus(UtxoStatus),
+    /// The transaction body of each requested block, along with the height of the block
+    BlockBodies(Vec<(u64, AggregateBody)>),
+    OrphanHeaders(Vec<BlockHeader>),
+    BlockExists(bool),
 }
 
 impl Display for NodeCommsResponse {
@@ -65,7 +88,7 @@ impl Display for NodeCommsResponse {
             HistoricalBlock(_) => write!(f, "HistoricalBlock"),
             TransactionOutputs(_) => write!(f, "TransactionOutputs"),
             HistoricalBlocks(_) => write!(f, "HistoricalBlocks"),
-            NewBlockTemplate(_) => write!(f, "NewBlockTemplate"),
+            NewBlockTemplate { .. } => write!(f, "NewBlockTemplate"),
             NewBlock {
                 success,
                 error,
@@ -79,6 +102,24 @@ impl Display for NodeCommsResponse {
             TargetDifficulty(_) => write!(f, "TargetDifficulty"),
             FetchHeadersAfterResponse(_) => write!(f, "FetchHeadersAfterResponse"),
             MmrNodes(_, _) => write!(f, "MmrNodes"),
+            DeletedBitmap(_) => write!(f, "DeletedBitmap"),
+            AccumulatedDifficulty { monero, sha3 } => {
+                write!(f, "AccumulatedDifficulty(monero={}, sha3={})", monero, sha3)
+            },
+            AccumulatedDifficulties(difficulties) => write!(f, "AccumulatedDifficulties(n={})", difficulties.len()),
+            MempoolStats {
+                unconfirmed_txs,
+                total_weight,
+                total_fees,
+            } => write!(
+                f,
+                "MempoolStats(unconfirmed_txs={}, total_weight={}, total_fees={})",
+                unconfirmed_txs, total_weight, total_fees
+            ),
+            UtxoStatus(status) => write!(f, "UtxoStatus({})", status),
+            BlockBodies(bodies) => write!(f, "BlockBodies(n={})", bodies.len()),
+            OrphanHeaders(headers) => write!(f, "OrphanHeaders(n={})", headers.len()),
+            BlockExists(exists) => write!(f, "BlockExists({})", exists),
         }
     }
 }
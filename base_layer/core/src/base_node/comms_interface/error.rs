@@ -26,6 +26,7 @@ use crate::{
     consensus::ConsensusManagerError,
     mempool::MempoolError,
 };
+use tari_comms::connectivity::ConnectivityError;
 use tari_comms_dht::outbound::DhtOutboundError;
 use tari_service_framework::reply_channel::TransportChannelError;
 use thiserror::Error;
@@ -62,4 +63,8 @@ pub enum CommsInterfaceError {
     ApiError(String),
     #[error("Header not found at {0}")]
     BlockHeaderNotFound(u64),
+    #[error("Request too large: {0}")]
+    RequestTooLarge(String),
+    #[error("Connectivity error: {0}")]
+    ConnectivityError(#[from] ConnectivityError),
 }
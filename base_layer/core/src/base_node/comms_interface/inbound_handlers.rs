@@ -26,6 +26,7 @@ use crate::{
             local_interface::BlockEventSender,
             NodeCommsRequest,
             NodeCommsResponse,
+            RequestCounters,
         },
         OutboundNodeCommsInterface,
     },
@@ -34,21 +35,34 @@ use crate::{
     consensus::{ConsensusConstants, ConsensusManager},
     mempool::{async_mempool, Mempool},
     proof_of_work::{Difficulty, PowAlgorithm},
-    transactions::{transaction::TransactionKernel, types::HashOutput},
+    transactions::{
+        transaction::{Transaction, TransactionKernel},
+        types::{Commitment, HashOutput, Signature},
+    },
 };
 use log::*;
 use std::{
+    cmp,
+    collections::HashMap,
     fmt::{Display, Error, Formatter},
     sync::Arc,
+    time::Duration,
 };
 use strum_macros::Display;
 use tari_common_types::types::BlockHash;
 use tari_comms::peer_manager::NodeId;
 use tari_crypto::tari_utilities::{hash::Hashable, hex::Hex};
-use tokio::sync::Semaphore;
+use tokio::sync::{Mutex, Semaphore};
+use ttl_cache::TtlCache;
 
 const LOG_TARGET: &str = "c::bn::comms_interface::inbound_handler";
 const MAX_HEADERS_PER_RESPONSE: u32 = 100;
+const MAX_BLOCKS_PER_RESPONSE: u64 = 100;
+const MAX_ORPHAN_HEADERS_PER_RESPONSE: usize = 1_000;
+const RECENTLY_PROPAGATED_CAPACITY: usize = 100;
+const RECENTLY_PROPAGATED_TTL: Duration = Duration::from_secs(5);
+const BLOCK_TEMPLATE_CACHE_CAPACITY: usize = 10;
+const DEFAULT_BLOCK_TEMPLATE_CACHE_MAX_AGE: Duration = Duration::from_secs(1);
 
 /// Events that can be published on the Validated Block Event Stream
 /// Broadcast is to notify subscribers if this is a valid propagated block event
@@ -58,6 +72,11 @@ pub enum BlockEvent {
     AddBlockFailed(Arc<Block>, Broadcast),
     BlockSyncComplete(Arc<ChainBlock>),
     BlockSyncRewind(Vec<Arc<ChainBlock>>),
+    /// A block was kept as an orphan because switching to its chain would remove `depth` blocks from the main chain,
+    /// which exceeds the configured maximum reorg depth.
+    ReorgRejectedTooDeep {
+        depth: u64,
+    },
 }
 
 /// Used to notify if the block event is for a propagated block.
@@ -98,6 +117,13 @@ pub struct InboundNodeCommsHandlers<T> {
     consensus_manager: ConsensusManager,
     new_block_request_semaphore: Arc<Semaphore>,
     outbound_nci: OutboundNodeCommsInterface,
+    max_reorg_depth: Option<u64>,
+    recently_propagated: Arc<Mutex<TtlCache<BlockHash, ()>>>,
+    request_counters: Option<Arc<RequestCounters>>,
+    block_template_cache: Arc<Mutex<TtlCache<(PowAlgorithm, u64), NewBlockTemplate>>>,
+    block_template_cache_max_age: Duration,
+    empty_template_on_mempool_error: bool,
+    block_propagation_fanout: Option<usize>,
 }
 
 impl<T> InboundNodeCommsHandlers<T>
@@ -118,12 +144,61 @@ where T: BlockchainBackend + 'static
             consensus_manager,
             new_block_request_semaphore: Arc::new(Semaphore::new(1)),
             outbound_nci,
+            max_reorg_depth: None,
+            recently_propagated: Arc::new(Mutex::new(TtlCache::new(RECENTLY_PROPAGATED_CAPACITY))),
+            request_counters: None,
+            block_template_cache: Arc::new(Mutex::new(TtlCache::new(BLOCK_TEMPLATE_CACHE_CAPACITY))),
+            block_template_cache_max_age: DEFAULT_BLOCK_TEMPLATE_CACHE_MAX_AGE,
+            empty_template_on_mempool_error: true,
+            block_propagation_fanout: None,
         }
     }
 
+    /// Refuse to reorg to a chain that would remove more than `max_reorg_depth` blocks from the main chain. Blocks
+    /// that would trigger such a reorg are kept as orphans.
+    pub fn with_max_reorg_depth(mut self, max_reorg_depth: u64) -> Self {
+        self.max_reorg_depth = Some(max_reorg_depth);
+        self
+    }
+
+    /// Count every handled request in the given `RequestCounters`. Requests are not counted if this is not set.
+    pub fn with_request_counters(mut self, request_counters: Arc<RequestCounters>) -> Self {
+        self.request_counters = Some(request_counters);
+        self
+    }
+
+    /// Reuse a new block template for repeated template requests within `max_age`, as long as the tip has not
+    /// changed. A zero `max_age` disables the cache.
+    pub fn with_block_template_cache_max_age(mut self, max_age: Duration) -> Self {
+        self.block_template_cache_max_age = max_age;
+        self
+    }
+
+    /// If true, a block template without any mempool transactions is built when transactions cannot be retrieved from
+    /// the mempool. If false, the template request fails instead.
+    pub fn with_empty_template_on_mempool_error(mut self, empty_template_on_mempool_error: bool) -> Self {
+        self.empty_template_on_mempool_error = empty_template_on_mempool_error;
+        self
+    }
+
+    /// Propagate new blocks to at most `fanout` randomly selected connected base nodes instead of all connected peers.
+    /// Blocks that cause a reorg are always propagated to all connected peers so that the network converges quickly.
+    pub fn with_block_propagation_fanout(mut self, fanout: usize) -> Self {
+        self.block_propagation_fanout = Some(fanout);
+        self
+    }
+
+    /// Returns a snapshot of the number of requests handled for each request variant, if request counting is enabled.
+    pub fn request_counts(&self) -> Option<HashMap<&'static str, u64>> {
+        self.request_counters.as_ref().map(|counters| counters.snapshot())
+    }
+
     /// Handle inbound node comms requests from remote nodes and local services.
     pub async fn handle_request(&self, request: NodeCommsRequest) -> Result<NodeCommsResponse, CommsInterfaceError> {
         debug!(target: LOG_TARGET, "Handling remote request {}", request);
+        if let Some(request_counters) = &self.request_counters {
+            request_counters.incr(request.name());
+        }
         match request {
             NodeCommsRequest::GetChainMetadata => Ok(NodeCommsResponse::ChainMetadata(
                 self.blockchain_db.get_chain_metadata().await?,
@@ -260,6 +335,25 @@ where T: BlockchainBackend + 'static
                 }
                 Ok(NodeCommsResponse::HistoricalBlocks(blocks))
             },
+            NodeCommsRequest::FetchBlocksInRange { start, end } => {
+                debug!(target: LOG_TARGET, "A peer has requested blocks {} to {}", start, end);
+                if end.saturating_sub(start) >= MAX_BLOCKS_PER_RESPONSE {
+                    return Err(CommsInterfaceError::RequestTooLarge(format!(
+                        "Cannot fetch more than {} blocks in a range",
+                        MAX_BLOCKS_PER_RESPONSE
+                    )));
+                }
+                // As with FetchMatchingBlocks, only the blocks that we have are returned, so the range is clamped to
+                // the blocks that are stored.
+                let metadata = self.blockchain_db.get_chain_metadata().await?;
+                let start = cmp::max(start, metadata.pruned_height());
+                let end = cmp::min(end, metadata.height_of_longest_chain());
+                if start > end {
+                    return Ok(NodeCommsResponse::HistoricalBlocks(Vec::new()));
+                }
+                let blocks = self.blockchain_db.fetch_blocks(start..=end).await?;
+                Ok(NodeCommsResponse::HistoricalBlocks(blocks))
+            },
             NodeCommsRequest::FetchBlocksWithHashes(block_hashes) => {
                 let mut blocks = Vec::with_capacity(block_hashes.len());
                 for block_hash in block_hashes {
@@ -358,11 +452,47 @@ where T: BlockchainBackend + 'static
                     request.max_weight
                 };
 
-                let transactions = async_mempool::retrieve(self.mempool.clone(), asking_weight)
-                    .await?
-                    .into_iter()
-                    .map(|tx| Arc::try_unwrap(tx).unwrap_or_else(|tx| (*tx).clone()))
-                    .collect::<Vec<_>>();
+                // Templates with excluded transactions are built specifically for this request, so the cache is
+                // bypassed
+                let use_cache = request.exclude_kernel_sigs.is_empty();
+                let cache_key = (request.algo, asking_weight);
+                if use_cache {
+                    if let Some(block_template) = self.get_cached_block_template(&cache_key, &header.prev_hash).await {
+                        debug!(
+                            target: LOG_TARGET,
+                            "New block template requested at height {}, serving from cache",
+                            block_template.header.height,
+                        );
+                        return Ok(NodeCommsResponse::NewBlockTemplate {
+                            block_template,
+                            from_cache: true,
+                        });
+                    }
+                }
+
+                let mut is_degraded = false;
+                let transactions = match async_mempool::retrieve(self.mempool.clone(), asking_weight).await {
+                    Ok(transactions) => transactions
+                        .into_iter()
+                        .map(|tx| Arc::try_unwrap(tx).unwrap_or_else(|tx| (*tx).clone()))
+                        .collect::<Vec<_>>(),
+                    Err(err) if self.empty_template_on_mempool_error => {
+                        warn!(
+                            target: LOG_TARGET,
+                            "Could not retrieve transactions from the mempool ({}). Building an empty block template.",
+                            err
+                        );
+                        is_degraded = true;
+                        Vec::new()
+                    },
+                    Err(err) => return Err(err.into()),
+                };
+
+                let transactions = if use_cache {
+                    transactions
+                } else {
+                    exclude_transactions(transactions, &request.exclude_kernel_sigs)
+                };
 
                 debug!(
                     target: LOG_TARGET,
@@ -384,7 +514,14 @@ where T: BlockchainBackend + 'static
                     "New block template requested at height {}", block_template.header.height,
                 );
                 trace!(target: LOG_TARGET, "{}", block_template);
-                Ok(NodeCommsResponse::NewBlockTemplate(block_template))
+                // Don't reuse an empty template once the mempool is available again
+                if !is_degraded && use_cache {
+                    self.cache_block_template(cache_key, block_template.clone()).await;
+                }
+                Ok(NodeCommsResponse::NewBlockTemplate {
+                    block_template,
+                    from_cache: false,
+                })
             },
             NodeCommsRequest::GetNewBlock(block_template) => {
                 let block = self.blockchain_db.prepare_block_merkle_roots(block_template).await?;
@@ -412,6 +549,121 @@ where T: BlockchainBackend + 'static
 
                 Ok(NodeCommsResponse::TransactionKernels(kernels))
             },
+            NodeCommsRequest::FetchDeletedBitmap { height } => {
+                let metadata = self.blockchain_db.get_chain_metadata().await?;
+                let block_hash = match height {
+                    Some(height) if height > metadata.height_of_longest_chain() => {
+                        return Err(CommsInterfaceError::BlockHeaderNotFound(height));
+                    },
+                    Some(height) => self
+                        .blockchain_db
+                        .fetch_header(height)
+                        .await?
+                        .ok_or(CommsInterfaceError::BlockHeaderNotFound(height))?
+                        .hash(),
+                    None => metadata.best_block().clone(),
+                };
+                let deleted = self.blockchain_db.fetch_complete_deleted_bitmap_at(block_hash).await?;
+                Ok(NodeCommsResponse::DeletedBitmap(deleted.into_bitmap().serialize()))
+            },
+            NodeCommsRequest::GetAccumulatedDifficulty(height) => {
+                let metadata = self.blockchain_db.get_chain_metadata().await?;
+                if height > metadata.height_of_longest_chain() {
+                    return Err(CommsInterfaceError::BlockHeaderNotFound(height));
+                }
+                let header = self.blockchain_db.fetch_chain_header(height).await?;
+                let accumulated_data = header.accumulated_data();
+                Ok(NodeCommsResponse::AccumulatedDifficulty {
+                    monero: accumulated_data.accumulated_monero_difficulty,
+                    sha3: accumulated_data.accumulated_sha_difficulty,
+                })
+            },
+            NodeCommsRequest::GetAccumulatedDifficulties { start, end } => {
+                if end.saturating_sub(start) >= MAX_HEADERS_PER_RESPONSE as u64 {
+                    return Err(CommsInterfaceError::RequestTooLarge(format!(
+                        "Cannot fetch more than {} accumulated difficulties in a range",
+                        MAX_HEADERS_PER_RESPONSE
+                    )));
+                }
+                let metadata = self.blockchain_db.get_chain_metadata().await?;
+                if end > metadata.height_of_longest_chain() {
+                    return Err(CommsInterfaceError::BlockHeaderNotFound(end));
+                }
+                if start > end {
+                    return Ok(NodeCommsResponse::AccumulatedDifficulties(Vec::new()));
+                }
+                let difficulties = self
+                    .blockchain_db
+                    .fetch_chain_headers(start..=end)
+                    .await?
+                    .iter()
+                    .map(|header| {
+                        let accumulated_data = header.accumulated_data();
+                        (
+                            header.height(),
+                            accumulated_data.accumulated_monero_difficulty,
+                            accumulated_data.accumulated_sha_difficulty,
+                        )
+                    })
+                    .collect();
+                Ok(NodeCommsResponse::AccumulatedDifficulties(difficulties))
+            },
+            NodeCommsRequest::GetMempoolStats => {
+                let stats = async_mempool::stats(self.mempool.clone()).await?;
+                let total_fees = async_mempool::unconfirmed_fees(self.mempool.clone()).await?;
+                Ok(NodeCommsResponse::MempoolStats {
+                    unconfirmed_txs: stats.unconfirmed_txs as u64,
+                    total_weight: stats.total_weight,
+                    total_fees,
+                })
+            },
+            NodeCommsRequest::FetchBlockBodies(heights) => {
+                if heights.len() as u64 > MAX_BLOCKS_PER_RESPONSE {
+                    return Err(CommsInterfaceError::RequestTooLarge(format!(
+                        "Cannot fetch more than {} block bodies",
+                        MAX_BLOCKS_PER_RESPONSE
+                    )));
+                }
+                let mut bodies = Vec::with_capacity(heights.len());
+                for height in heights {
+                    // As with FetchMatchingBlocks, blocks that are not available are omitted. Pruned blocks are
+                    // omitted too, since their bodies are incomplete.
+                    match self
+                        .blockchain_db
+                        .fetch_block(height)
+                        .await
+                        .and_then(|block| block.try_into_block())
+                    {
+                        Ok(block) => bodies.push((height, block.body)),
+                        Err(e) => debug!(
+                            target: LOG_TARGET,
+                            "Could not provide requested block body {} because: {}", height, e
+                        ),
+                    }
+                }
+                Ok(NodeCommsResponse::BlockBodies(bodies))
+            },
+            NodeCommsRequest::FetchOrphanHeaders => {
+                let mut headers = self.blockchain_db.fetch_orphan_headers().await?;
+                if headers.len() > MAX_ORPHAN_HEADERS_PER_RESPONSE {
+                    warn!(
+                        target: LOG_TARGET,
+                        "Orphan pool contains {} blocks, which is unexpectedly large. Only the first {} will be returned.",
+                        headers.len(),
+                        MAX_ORPHAN_HEADERS_PER_RESPONSE
+                    );
+                    headers.truncate(MAX_ORPHAN_HEADERS_PER_RESPONSE);
+                }
+                Ok(NodeCommsResponse::OrphanHeaders(headers))
+            },
+            NodeCommsRequest::FetchUtxoStatus(commitment) => {
+                let status = self.blockchain_db.fetch_utxo_status(commitment).await?;
+                Ok(NodeCommsResponse::UtxoStatus(status))
+            },
+            NodeCommsRequest::BlockExists(hash) => {
+                let exists = self.blockchain_db.block_exists(hash).await?;
+                Ok(NodeCommsResponse::BlockExists(exists))
+            },
         }
     }
 
@@ -473,13 +725,14 @@ where T: BlockchainBackend + 'static
         }
     }
 
-    /// Handle inbound blocks from remote nodes and local services.
+    /// Handle inbound blocks from remote nodes and local services. Returns the hash of the block and the result of
+    /// adding it to the blockchain.
     pub async fn handle_block(
         &self,
         block: Arc<Block>,
         broadcast: Broadcast,
         source_peer: Option<NodeId>,
-    ) -> Result<BlockHash, CommsInterfaceError> {
+    ) -> Result<(BlockHash, BlockAddResult), CommsInterfaceError> {
         let block_hash = block.hash();
         let block_height = block.header.height;
         info!(
@@ -493,6 +746,25 @@ where T: BlockchainBackend + 'static
                 .unwrap_or_else(|| "local services".to_string())
         );
         trace!(target: LOG_TARGET, "Block: {}", block);
+        if let Some(max_reorg_depth) = self.max_reorg_depth {
+            // Only blocks that would make their branch the strongest chain trigger a reorg
+            if let Some(depth) = self.blockchain_db.fetch_reorg_depth(block.clone()).await? {
+                if depth > max_reorg_depth {
+                    error!(
+                        target: LOG_TARGET,
+                        "Block #{} ({}) would trigger a reorg of depth {} which exceeds the maximum reorg depth of {}. \
+                         The block is kept as an orphan and the chain will NOT switch until this is investigated.",
+                        block_height,
+                        block_hash.to_hex(),
+                        depth,
+                        max_reorg_depth
+                    );
+                    self.blockchain_db.add_orphan_block(block).await?;
+                    self.publish_block_event(BlockEvent::ReorgRejectedTooDeep { depth });
+                    return Ok((block_hash, BlockAddResult::OrphanBlock));
+                }
+            }
+        }
         let add_block_result = self.blockchain_db.add_block(block.clone()).await;
         // Create block event on block event stream
         match add_block_result {
@@ -505,22 +777,40 @@ where T: BlockchainBackend + 'static
                     BlockAddResult::OrphanBlock => false,
                     BlockAddResult::ChainReorg { .. } => true,
                 };
+                if should_propagate {
+                    // The tip has changed, so any cached block templates are stale
+                    self.block_template_cache.lock().await.clear();
+                }
 
                 self.blockchain_db.cleanup_orphans().await?;
 
-                self.publish_block_event(BlockEvent::ValidBlockAdded(block, block_add_result, broadcast));
+                self.publish_block_event(BlockEvent::ValidBlockAdded(block, block_add_result.clone(), broadcast));
 
                 if should_propagate && broadcast.is_true() {
-                    info!(
-                        target: LOG_TARGET,
-                        "Propagate block ({}) to network.",
-                        block_hash.to_hex()
-                    );
-                    let exclude_peers = source_peer.into_iter().collect();
-                    let new_block = NewBlock::new(block_hash.clone());
-                    self.outbound_nci.propagate_block(new_block, exclude_peers).await?;
+                    if self.mark_propagated(&block_hash).await {
+                        info!(
+                            target: LOG_TARGET,
+                            "Propagate block ({}) to network.",
+                            block_hash.to_hex()
+                        );
+                        let exclude_peers = source_peer.into_iter().collect();
+                        let new_block = NewBlock::new(block_hash.clone());
+                        let fanout = match block_add_result {
+                            BlockAddResult::ChainReorg { .. } => None,
+                            _ => self.block_propagation_fanout,
+                        };
+                        self.outbound_nci
+                            .propagate_block_with_fanout(new_block, exclude_peers, fanout)
+                            .await?;
+                    } else {
+                        debug!(
+                            target: LOG_TARGET,
+                            "Block ({}) was propagated recently, not propagating again.",
+                            block_hash.to_hex()
+                        );
+                    }
                 }
-                Ok(block_hash)
+                Ok((block_hash, block_add_result))
             },
             Err(e) => {
                 warn!(
@@ -536,6 +826,40 @@ where T: BlockchainBackend + 'static
         }
     }
 
+    /// Returns a cached block template for the given (algo, max weight) key if it was built on top of `tip_hash`.
+    async fn get_cached_block_template(
+        &self,
+        cache_key: &(PowAlgorithm, u64),
+        tip_hash: &BlockHash,
+    ) -> Option<NewBlockTemplate> {
+        let block_template_cache = self.block_template_cache.lock().await;
+        block_template_cache
+            .get(cache_key)
+            .filter(|block_template| block_template.header.prev_hash == *tip_hash)
+            .cloned()
+    }
+
+    async fn cache_block_template(&self, cache_key: (PowAlgorithm, u64), block_template: NewBlockTemplate) {
+        if self.block_template_cache_max_age == Duration::from_secs(0) {
+            return;
+        }
+        self.block_template_cache
+            .lock()
+            .await
+            .insert(cache_key, block_template, self.block_template_cache_max_age);
+    }
+
+    /// Records that the block is being propagated. Returns false if the block was already propagated within the last
+    /// `RECENTLY_PROPAGATED_TTL`, in which case it should not be propagated again.
+    async fn mark_propagated(&self, block_hash: &BlockHash) -> bool {
+        let mut recently_propagated = self.recently_propagated.lock().await;
+        if recently_propagated.contains_key(block_hash) {
+            return false;
+        }
+        recently_propagated.insert(block_hash.clone(), (), RECENTLY_PROPAGATED_TTL);
+        true
+    }
+
     fn publish_block_event(&self, event: BlockEvent) {
         if let Err(event) = self.block_event_sender.send(Arc::new(event)) {
             debug!(target: LOG_TARGET, "No event subscribers. Event {} dropped.", event.0)
@@ -562,6 +886,40 @@ where T: BlockchainBackend + 'static
     }
 }
 
+/// Removes the transactions that contain any of the excluded kernels from the list. Transactions that spend outputs of
+/// removed transactions are also removed, since they would be invalid in a block without them.
+fn exclude_transactions(transactions: Vec<Transaction>, exclude_kernel_sigs: &[Signature]) -> Vec<Transaction> {
+    let num_transactions = transactions.len();
+    let (mut excluded, mut remaining): (Vec<_>, Vec<_>) = transactions.into_iter().partition(|tx| {
+        tx.body
+            .kernels()
+            .iter()
+            .any(|kernel| exclude_kernel_sigs.contains(&kernel.excess_sig))
+    });
+    let mut excluded_outputs = Vec::<Commitment>::new();
+    while !excluded.is_empty() {
+        excluded_outputs.extend(
+            excluded
+                .iter()
+                .flat_map(|tx| tx.body.outputs().iter().map(|output| output.commitment.clone())),
+        );
+        let (dependants, rest) = remaining.into_iter().partition(|tx| {
+            tx.body
+                .inputs()
+                .iter()
+                .any(|input| excluded_outputs.contains(&input.commitment))
+        });
+        excluded = dependants;
+        remaining = rest;
+    }
+    debug!(
+        target: LOG_TARGET,
+        "Excluded {} transaction(s) from the new block template",
+        num_transactions - remaining.len()
+    );
+    remaining
+}
+
 impl<T> Clone for InboundNodeCommsHandlers<T> {
     fn clone(&self) -> Self {
         Self {
@@ -571,6 +929,13 @@ impl<T> Clone for InboundNodeCommsHandlers<T> {
             consensus_manager: self.consensus_manager.clone(),
             new_block_request_semaphore: self.new_block_request_semaphore.clone(),
             outbound_nci: self.outbound_nci.clone(),
+            max_reorg_depth: self.max_reorg_depth,
+            recently_propagated: self.recently_propagated.clone(),
+            request_counters: self.request_counters.clone(),
+            block_template_cache: self.block_template_cache.clone(),
+            block_template_cache_max_age: self.block_template_cache_max_age,
+            empty_template_on_mempool_error: self.empty_template_on_mempool_error,
+            block_propagation_fanout: self.block_propagation_fanout,
         }
     }
 }
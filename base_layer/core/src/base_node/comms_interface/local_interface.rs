@@ -25,13 +25,16 @@ use crate::{
         error::CommsInterfaceError,
         BlockEvent,
         Broadcast,
+        ChainComparison,
         NodeCommsRequest,
         NodeCommsResponse,
     },
     blocks::{Block, BlockHeader, NewBlockTemplate},
-    chain_storage::HistoricalBlock,
-    proof_of_work::PowAlgorithm,
+    chain_storage::{BlockAddResult, HistoricalBlock, UtxoStatus},
+    proof_of_work::{Difficulty, PowAlgorithm},
     transactions::{
+        aggregated_body::AggregateBody,
+        tari_amount::MicroTari,
         transaction::{TransactionKernel, TransactionOutput},
         types::{Commitment, HashOutput, Signature},
     },
@@ -50,7 +53,7 @@ use crate::base_node::comms_interface::comms_request::GetNewBlockTemplateRequest
 #[derive(Clone)]
 pub struct LocalNodeCommsInterface {
     request_sender: SenderService<NodeCommsRequest, Result<NodeCommsResponse, CommsInterfaceError>>,
-    block_sender: SenderService<(Block, Broadcast), Result<BlockHash, CommsInterfaceError>>,
+    block_sender: SenderService<(Block, Broadcast), Result<(BlockHash, BlockAddResult), CommsInterfaceError>>,
     block_event_sender: BlockEventSender,
 }
 
@@ -58,7 +61,7 @@ impl LocalNodeCommsInterface {
     /// Construct a new LocalNodeCommsInterface with the specified SenderService.
     pub fn new(
         request_sender: SenderService<NodeCommsRequest, Result<NodeCommsResponse, CommsInterfaceError>>,
-        block_sender: SenderService<(Block, Broadcast), Result<BlockHash, CommsInterfaceError>>,
+        block_sender: SenderService<(Block, Broadcast), Result<(BlockHash, BlockAddResult), CommsInterfaceError>>,
         block_event_sender: BlockEventSender,
     ) -> Self {
         Self {
@@ -80,6 +83,46 @@ impl LocalNodeCommsInterface {
         }
     }
 
+    /// Request the number of unconfirmed transactions, the total transaction weight and the total fees of the
+    /// unconfirmed transactions in the mempool, in that order.
+    pub async fn get_mempool_stats(&mut self) -> Result<(u64, u64, MicroTari), CommsInterfaceError> {
+        match self.request_sender.call(NodeCommsRequest::GetMempoolStats).await?? {
+            NodeCommsResponse::MempoolStats {
+                unconfirmed_txs,
+                total_weight,
+                total_fees,
+            } => Ok((unconfirmed_txs, total_weight, total_fees)),
+            _ => Err(CommsInterfaceError::UnexpectedApiResponse),
+        }
+    }
+
+    /// Request whether the output with the given commitment is unspent, spent or unknown to the base node
+    pub async fn fetch_utxo_status(&mut self, commitment: Commitment) -> Result<UtxoStatus, CommsInterfaceError> {
+        match self
+            .request_sender
+            .call(NodeCommsRequest::FetchUtxoStatus(commitment))
+            .await??
+        {
+            NodeCommsResponse::UtxoStatus(status) => Ok(status),
+            _ => Err(CommsInterfaceError::UnexpectedApiResponse),
+        }
+    }
+
+    /// Compare the local chain to the chain described by `other_metadata`, e.g. the chain metadata of a peer
+    pub async fn compare_to(&mut self, other_metadata: ChainMetadata) -> Result<ChainComparison, CommsInterfaceError> {
+        let local_metadata = self.get_metadata().await?;
+        Ok(ChainComparison::compare(&local_metadata, &other_metadata))
+    }
+
+    /// Request whether the block with the given hash is stored, either in the main chain or as an orphan. This is much
+    /// cheaper than fetching the block.
+    pub async fn block_exists(&mut self, hash: BlockHash) -> Result<bool, CommsInterfaceError> {
+        match self.request_sender.call(NodeCommsRequest::BlockExists(hash)).await?? {
+            NodeCommsResponse::BlockExists(exists) => Ok(exists),
+            _ => Err(CommsInterfaceError::UnexpectedApiResponse),
+        }
+    }
+
     /// Request the block header of the current tip at the block height
     pub async fn get_blocks(&mut self, block_heights: Vec<u64>) -> Result<Vec<HistoricalBlock>, CommsInterfaceError> {
         match self
@@ -92,6 +135,96 @@ impl LocalNodeCommsInterface {
         }
     }
 
+    /// Request only the transaction bodies of the blocks at the given heights. Each body is returned with the height of
+    /// its block. Blocks that are not stored, or that have been pruned, are omitted. At most 100 heights may be
+    /// requested.
+    pub async fn get_block_bodies(
+        &mut self,
+        block_heights: Vec<u64>,
+    ) -> Result<Vec<(u64, AggregateBody)>, CommsInterfaceError> {
+        match self
+            .request_sender
+            .call(NodeCommsRequest::FetchBlockBodies(block_heights))
+            .await??
+        {
+            NodeCommsResponse::BlockBodies(bodies) => Ok(bodies),
+            _ => Err(CommsInterfaceError::UnexpectedApiResponse),
+        }
+    }
+
+    /// Request the headers of the blocks currently held in the orphan pool, ordered by height. At most 1,000 headers
+    /// are returned.
+    pub async fn get_orphan_headers(&mut self) -> Result<Vec<BlockHeader>, CommsInterfaceError> {
+        match self.request_sender.call(NodeCommsRequest::FetchOrphanHeaders).await?? {
+            NodeCommsResponse::OrphanHeaders(headers) => Ok(headers),
+            _ => Err(CommsInterfaceError::UnexpectedApiResponse),
+        }
+    }
+
+    /// Request the blocks from `start` to `end` (inclusive). Blocks that are not stored are omitted. Ranges spanning
+    /// more than 100 blocks are rejected.
+    pub async fn get_blocks_in_range(
+        &mut self,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<HistoricalBlock>, CommsInterfaceError> {
+        match self
+            .request_sender
+            .call(NodeCommsRequest::FetchBlocksInRange { start, end })
+            .await??
+        {
+            NodeCommsResponse::HistoricalBlocks(blocks) => Ok(blocks),
+            _ => Err(CommsInterfaceError::UnexpectedApiResponse),
+        }
+    }
+
+    /// Request the serialized deleted (spent) output bitmap as it was at the given block height, or at the chain tip if
+    /// no height is given.
+    pub async fn fetch_deleted_bitmap(&mut self, height: Option<u64>) -> Result<Vec<u8>, CommsInterfaceError> {
+        match self
+            .request_sender
+            .call(NodeCommsRequest::FetchDeletedBitmap { height })
+            .await??
+        {
+            NodeCommsResponse::DeletedBitmap(deleted) => Ok(deleted),
+            _ => Err(CommsInterfaceError::UnexpectedApiResponse),
+        }
+    }
+
+    /// Request the accumulated monero and sha3 difficulties, in that order, of the block at the given height. An error
+    /// is returned if the height is above the chain tip.
+    pub async fn get_accumulated_difficulty(
+        &mut self,
+        height: u64,
+    ) -> Result<(Difficulty, Difficulty), CommsInterfaceError> {
+        match self
+            .request_sender
+            .call(NodeCommsRequest::GetAccumulatedDifficulty(height))
+            .await??
+        {
+            NodeCommsResponse::AccumulatedDifficulty { monero, sha3 } => Ok((monero, sha3)),
+            _ => Err(CommsInterfaceError::UnexpectedApiResponse),
+        }
+    }
+
+    /// Request the accumulated monero and sha3 difficulties of the blocks from `start` to `end` (inclusive) as
+    /// (height, monero, sha3) tuples, ordered by height. An error is returned if `end` is above the chain tip or if the
+    /// range spans more than 100 blocks.
+    pub async fn get_accumulated_difficulties(
+        &mut self,
+        start: u64,
+        end: u64,
+    ) -> Result<Vec<(u64, Difficulty, Difficulty)>, CommsInterfaceError> {
+        match self
+            .request_sender
+            .call(NodeCommsRequest::GetAccumulatedDifficulties { start, end })
+            .await??
+        {
+            NodeCommsResponse::AccumulatedDifficulties(difficulties) => Ok(difficulties),
+            _ => Err(CommsInterfaceError::UnexpectedApiResponse),
+        }
+    }
+
     /// Request the block header of the current tip at the block height
     pub async fn get_headers(&mut self, block_heights: Vec<u64>) -> Result<Vec<BlockHeader>, CommsInterfaceError> {
         match self
@@ -104,22 +237,40 @@ impl LocalNodeCommsInterface {
         }
     }
 
-    /// Request the construction of a new mineable block template from the base node service.
+    /// Request the construction of a new mineable block template from the base node service. Also returns whether the
+    /// template was served from the base node's block template cache.
     pub async fn get_new_block_template(
         &mut self,
         pow_algorithm: PowAlgorithm,
         max_weight: u64,
-    ) -> Result<NewBlockTemplate, CommsInterfaceError> {
+    ) -> Result<(NewBlockTemplate, bool), CommsInterfaceError> {
+        self.get_new_block_template_excluding(pow_algorithm, max_weight, Vec::new())
+            .await
+    }
+
+    /// Request a new block template that does not include any mempool transactions with the given kernel excess
+    /// signatures. Transactions that depend on an excluded transaction are also excluded. Templates with exclusions
+    /// are never served from the template cache.
+    pub async fn get_new_block_template_excluding(
+        &mut self,
+        pow_algorithm: PowAlgorithm,
+        max_weight: u64,
+        exclude_kernel_sigs: Vec<Signature>,
+    ) -> Result<(NewBlockTemplate, bool), CommsInterfaceError> {
         let request = GetNewBlockTemplateRequest {
             algo: pow_algorithm,
             max_weight,
+            exclude_kernel_sigs,
         };
         match self
             .request_sender
             .call(NodeCommsRequest::GetNewBlockTemplate(request))
             .await??
         {
-            NodeCommsResponse::NewBlockTemplate(new_block_template) => Ok(new_block_template),
+            NodeCommsResponse::NewBlockTemplate {
+                block_template,
+                from_cache,
+            } => Ok((block_template, from_cache)),
             _ => Err(CommsInterfaceError::UnexpectedApiResponse),
         }
     }
@@ -148,8 +299,13 @@ impl LocalNodeCommsInterface {
         }
     }
 
-    /// Submit a block to the base node service. Internal_only flag will prevent propagation.
-    pub async fn submit_block(&mut self, block: Block, propagate: Broadcast) -> Result<BlockHash, CommsInterfaceError> {
+    /// Submit a block to the base node service. Internal_only flag will prevent propagation. Returns the hash of the
+    /// block and the result of adding it to the blockchain.
+    pub async fn submit_block(
+        &mut self,
+        block: Block,
+        propagate: Broadcast,
+    ) -> Result<(BlockHash, BlockAddResult), CommsInterfaceError> {
         self.block_sender.call((block, propagate)).await?
     }
 
@@ -20,6 +20,9 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+mod chain_comparison;
+pub use chain_comparison::ChainComparison;
+
 mod comms_request;
 pub use comms_request::{GetNewBlockTemplateRequest, MmrStateRequest, NodeCommsRequest};
 
@@ -32,6 +35,9 @@ pub use error::CommsInterfaceError;
 mod inbound_handlers;
 pub use inbound_handlers::{BlockEvent, Broadcast, InboundNodeCommsHandlers};
 
+mod request_counters;
+pub use request_counters::RequestCounters;
+
 mod local_interface;
 pub use local_interface::{BlockEventReceiver, BlockEventSender, LocalNodeCommsInterface};
 
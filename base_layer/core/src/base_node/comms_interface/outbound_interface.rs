@@ -38,7 +38,7 @@ pub const LOG_TARGET: &str = "c::bn::comms_interface::outbound_interface";
 #[derive(Clone)]
 pub struct OutboundNodeCommsInterface {
     request_sender: SenderService<(NodeCommsRequest, Option<NodeId>), Result<NodeCommsResponse, CommsInterfaceError>>,
-    block_sender: UnboundedSender<(NewBlock, Vec<NodeId>)>,
+    block_sender: UnboundedSender<(NewBlock, Vec<NodeId>, Option<usize>)>,
 }
 
 impl OutboundNodeCommsInterface {
@@ -48,7 +48,7 @@ impl OutboundNodeCommsInterface {
             (NodeCommsRequest, Option<NodeId>),
             Result<NodeCommsResponse, CommsInterfaceError>,
         >,
-        block_sender: UnboundedSender<(NewBlock, Vec<NodeId>)>,
+        block_sender: UnboundedSender<(NewBlock, Vec<NodeId>, Option<usize>)>,
     ) -> Self {
         Self {
             request_sender,
@@ -233,9 +233,20 @@ impl OutboundNodeCommsInterface {
         &self,
         new_block: NewBlock,
         exclude_peers: Vec<NodeId>,
+    ) -> Result<(), CommsInterfaceError> {
+        self.propagate_block_with_fanout(new_block, exclude_peers, None).await
+    }
+
+    /// Transmit a block to at most `fanout` randomly selected connected base nodes, excluding the provided peers. The
+    /// block is sent to all connected peers if `fanout` is `None`.
+    pub async fn propagate_block_with_fanout(
+        &self,
+        new_block: NewBlock,
+        exclude_peers: Vec<NodeId>,
+        fanout: Option<usize>,
     ) -> Result<(), CommsInterfaceError> {
         self.block_sender
-            .unbounded_send((new_block, exclude_peers))
+            .unbounded_send((new_block, exclude_peers, fanout))
             .map_err(|err| {
                 CommsInterfaceError::InternalChannelError(format!("Failed to send on block_sender: {}", err))
             })
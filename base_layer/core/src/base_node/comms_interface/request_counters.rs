@@ -0,0 +1,67 @@
+// Copyright 2021. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+/// Counts the number of `NodeCommsRequest`s handled by the `InboundNodeCommsHandlers`, keyed by request variant name.
+#[derive(Debug, Default)]
+pub struct RequestCounters {
+    counts: Mutex<HashMap<&'static str, u64>>,
+}
+
+impl RequestCounters {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Increment the count for the given request name.
+    pub fn incr(&self, request_name: &'static str) {
+        let mut counts = self.counts.lock().unwrap_or_else(PoisonError::into_inner);
+        *counts.entry(request_name).or_insert(0) += 1;
+    }
+
+    /// Returns a snapshot of the number of requests handled for each request variant seen so far.
+    pub fn snapshot(&self) -> HashMap<&'static str, u64> {
+        self.counts.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_counts_requests_by_name() {
+        let counters = RequestCounters::new();
+        assert!(counters.snapshot().is_empty());
+        counters.incr("FetchHeaders");
+        counters.incr("FetchHeaders");
+        counters.incr("GetChainMetadata");
+        let snapshot = counters.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot["FetchHeaders"], 2);
+        assert_eq!(snapshot["GetChainMetadata"], 1);
+    }
+}
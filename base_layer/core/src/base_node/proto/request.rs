@@ -27,7 +27,9 @@ use crate::{
         base_node as proto,
         base_node::{
             base_node_service_request::Request as ProtoNodeCommsRequest,
+            BlockHeightRange,
             BlockHeights,
+            DeletedBitmapRequest,
             FetchHeadersAfter as ProtoFetchHeadersAfter,
             HashOutputs,
         },
@@ -54,6 +56,10 @@ impl TryInto<ci::NodeCommsRequest> for ProtoNodeCommsRequest {
             FetchMatchingUtxos(hash_outputs) => ci::NodeCommsRequest::FetchMatchingUtxos(hash_outputs.outputs),
             FetchMatchingTxos(hash_outputs) => ci::NodeCommsRequest::FetchMatchingTxos(hash_outputs.outputs),
             FetchMatchingBlocks(block_heights) => ci::NodeCommsRequest::FetchMatchingBlocks(block_heights.heights),
+            FetchBlocksInRange(range) => ci::NodeCommsRequest::FetchBlocksInRange {
+                start: range.start,
+                end: range.end,
+            },
             FetchBlocksWithHashes(block_hashes) => ci::NodeCommsRequest::FetchBlocksWithHashes(block_hashes.outputs),
             FetchBlocksWithKernels(signatures) => {
                 let mut sigs = Vec::new();
@@ -75,6 +81,11 @@ impl TryInto<ci::NodeCommsRequest> for ProtoNodeCommsRequest {
                 let request = GetNewBlockTemplateRequest {
                     algo: PowAlgorithm::try_from(message.algo)?,
                     max_weight: message.max_weight,
+                    exclude_kernel_sigs: message
+                        .exclude_kernel_sigs
+                        .into_iter()
+                        .map(|sig| Signature::try_from(sig).map_err(|err: ByteArrayError| err.to_string()))
+                        .collect::<Result<_, _>>()?,
                 };
                 ci::NodeCommsRequest::GetNewBlockTemplate(request)
             },
@@ -82,6 +93,19 @@ impl TryInto<ci::NodeCommsRequest> for ProtoNodeCommsRequest {
             FetchKernelByExcessSig(sig) => ci::NodeCommsRequest::FetchKernelByExcessSig(
                 Signature::try_from(sig).map_err(|err: ByteArrayError| err.to_string())?,
             ),
+            FetchDeletedBitmap(request) => ci::NodeCommsRequest::FetchDeletedBitmap { height: request.height },
+            GetAccumulatedDifficulty(height) => ci::NodeCommsRequest::GetAccumulatedDifficulty(height),
+            GetMempoolStats(_) => ci::NodeCommsRequest::GetMempoolStats,
+            FetchBlockBodies(block_heights) => ci::NodeCommsRequest::FetchBlockBodies(block_heights.heights),
+            FetchOrphanHeaders(_) => ci::NodeCommsRequest::FetchOrphanHeaders,
+            FetchUtxoStatus(commitment) => ci::NodeCommsRequest::FetchUtxoStatus(
+                Commitment::try_from(commitment).map_err(|err: ByteArrayError| err.to_string())?,
+            ),
+            BlockExists(hash) => ci::NodeCommsRequest::BlockExists(hash),
+            GetAccumulatedDifficulties(range) => ci::NodeCommsRequest::GetAccumulatedDifficulties {
+                start: range.start,
+                end: range.end,
+            },
         };
         Ok(request)
     }
@@ -100,6 +124,9 @@ impl From<ci::NodeCommsRequest> for ProtoNodeCommsRequest {
             FetchMatchingUtxos(hash_outputs) => ProtoNodeCommsRequest::FetchMatchingUtxos(hash_outputs.into()),
             FetchMatchingTxos(hash_outputs) => ProtoNodeCommsRequest::FetchMatchingTxos(hash_outputs.into()),
             FetchMatchingBlocks(block_heights) => ProtoNodeCommsRequest::FetchMatchingBlocks(block_heights.into()),
+            FetchBlocksInRange { start, end } => {
+                ProtoNodeCommsRequest::FetchBlocksInRange(BlockHeightRange { start, end })
+            },
             FetchBlocksWithHashes(block_hashes) => ProtoNodeCommsRequest::FetchBlocksWithHashes(block_hashes.into()),
             FetchBlocksWithKernels(signatures) => {
                 let sigs = signatures.into_iter().map(Into::into).collect();
@@ -115,10 +142,21 @@ impl From<ci::NodeCommsRequest> for ProtoNodeCommsRequest {
                 ProtoNodeCommsRequest::GetNewBlockTemplate(proto::NewBlockTemplateRequest {
                     algo: request.algo as u64,
                     max_weight: request.max_weight,
+                    exclude_kernel_sigs: request.exclude_kernel_sigs.into_iter().map(Into::into).collect(),
                 })
             },
             GetNewBlock(block_template) => ProtoNodeCommsRequest::GetNewBlock(block_template.into()),
             FetchKernelByExcessSig(signature) => ProtoNodeCommsRequest::FetchKernelByExcessSig(signature.into()),
+            FetchDeletedBitmap { height } => ProtoNodeCommsRequest::FetchDeletedBitmap(DeletedBitmapRequest { height }),
+            GetAccumulatedDifficulty(height) => ProtoNodeCommsRequest::GetAccumulatedDifficulty(height),
+            GetMempoolStats => ProtoNodeCommsRequest::GetMempoolStats(true),
+            FetchUtxoStatus(commitment) => ProtoNodeCommsRequest::FetchUtxoStatus(commitment.into()),
+            FetchBlockBodies(block_heights) => ProtoNodeCommsRequest::FetchBlockBodies(block_heights.into()),
+            FetchOrphanHeaders => ProtoNodeCommsRequest::FetchOrphanHeaders(true),
+            BlockExists(hash) => ProtoNodeCommsRequest::BlockExists(hash),
+            GetAccumulatedDifficulties { start, end } => {
+                ProtoNodeCommsRequest::GetAccumulatedDifficulties(BlockHeightRange { start, end })
+            },
         }
     }
 }
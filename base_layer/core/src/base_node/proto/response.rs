@@ -24,22 +24,30 @@ pub use crate::proto::base_node::base_node_service_response::Response as ProtoNo
 use crate::{
     base_node::comms_interface as ci,
     blocks::BlockHeader,
-    chain_storage::HistoricalBlock,
+    chain_storage::{HistoricalBlock, UtxoStatus},
     proof_of_work::Difficulty,
     proto,
     proto::{
         base_node as base_node_proto,
         base_node::{
+            AccumulatedDifficulties as ProtoAccumulatedDifficulties,
+            AccumulatedDifficulty as ProtoAccumulatedDifficulty,
+            BlockAccumulatedDifficulty as ProtoBlockAccumulatedDifficulty,
+            BlockBodies as ProtoBlockBodies,
+            BlockBody as ProtoBlockBody,
             BlockHeaders as ProtoBlockHeaders,
             HistoricalBlocks as ProtoHistoricalBlocks,
+            MempoolStats as ProtoMempoolStats,
             MmrNodes as ProtoMmrNodes,
             NewBlockResponse as ProtoNewBlockResponse,
             TransactionKernels as ProtoTransactionKernels,
             TransactionOutputs as ProtoTransactionOutputs,
+            UtxoStatus as ProtoUtxoStatus,
         },
         core as core_proto_types,
     },
     tari_utilities::convert::try_convert_all,
+    transactions::tari_amount::MicroTari,
 };
 use std::{
     convert::TryInto,
@@ -75,7 +83,11 @@ impl TryInto<ci::NodeCommsResponse> for ProtoNodeCommsResponse {
                 let blocks = try_convert_all(blocks.blocks)?;
                 ci::NodeCommsResponse::HistoricalBlocks(blocks)
             },
-            NewBlockTemplate(block_template) => ci::NodeCommsResponse::NewBlockTemplate(block_template.try_into()?),
+            // Whether a template was served from the cache is only known to the node that built it
+            NewBlockTemplate(block_template) => ci::NodeCommsResponse::NewBlockTemplate {
+                block_template: block_template.try_into()?,
+                from_cache: false,
+            },
             NewBlock(block) => ci::NodeCommsResponse::NewBlock {
                 success: block.success,
                 error: Some(block.error),
@@ -86,6 +98,44 @@ impl TryInto<ci::NodeCommsResponse> for ProtoNodeCommsResponse {
             },
             TargetDifficulty(difficulty) => ci::NodeCommsResponse::TargetDifficulty(Difficulty::from(difficulty)),
             MmrNodes(response) => ci::NodeCommsResponse::MmrNodes(response.added, response.deleted),
+            DeletedBitmap(deleted) => ci::NodeCommsResponse::DeletedBitmap(deleted),
+            AccumulatedDifficulty(difficulty) => ci::NodeCommsResponse::AccumulatedDifficulty {
+                monero: Difficulty::from(difficulty.monero),
+                sha3: Difficulty::from(difficulty.sha3),
+            },
+            MempoolStats(stats) => ci::NodeCommsResponse::MempoolStats {
+                unconfirmed_txs: stats.unconfirmed_txs,
+                total_weight: stats.total_weight,
+                total_fees: MicroTari::from(stats.total_fees),
+            },
+            UtxoStatus(status) => {
+                let status =
+                    ProtoUtxoStatus::from_i32(status).ok_or_else(|| format!("Invalid UtxoStatus value: {}", status))?;
+                ci::NodeCommsResponse::UtxoStatus(status.into())
+            },
+            BlockBodies(bodies) => {
+                let bodies = bodies
+                    .bodies
+                    .into_iter()
+                    .map(|b| {
+                        let body = b.body.ok_or_else(|| "Block body not provided".to_string())?;
+                        Ok((b.height, body.try_into()?))
+                    })
+                    .collect::<Result<_, String>>()?;
+                ci::NodeCommsResponse::BlockBodies(bodies)
+            },
+            OrphanHeaders(headers) => {
+                let headers = try_convert_all(headers.headers)?;
+                ci::NodeCommsResponse::OrphanHeaders(headers)
+            },
+            BlockExists(exists) => ci::NodeCommsResponse::BlockExists(exists),
+            AccumulatedDifficulties(difficulties) => ci::NodeCommsResponse::AccumulatedDifficulties(
+                difficulties
+                    .difficulties
+                    .into_iter()
+                    .map(|d| (d.height, Difficulty::from(d.monero), Difficulty::from(d.sha3)))
+                    .collect(),
+            ),
         };
 
         Ok(response)
@@ -119,7 +169,7 @@ impl From<ci::NodeCommsResponse> for ProtoNodeCommsResponse {
                 let historical_blocks = historical_blocks.into_iter().map(Into::into).collect();
                 ProtoNodeCommsResponse::HistoricalBlocks(historical_blocks)
             },
-            NewBlockTemplate(block_template) => ProtoNodeCommsResponse::NewBlockTemplate(block_template.into()),
+            NewBlockTemplate { block_template, .. } => ProtoNodeCommsResponse::NewBlockTemplate(block_template.into()),
             NewBlock { success, error, block } => ProtoNodeCommsResponse::NewBlock(ProtoNewBlockResponse {
                 success,
                 error: error.unwrap_or_else(|| "".to_string()),
@@ -127,6 +177,49 @@ impl From<ci::NodeCommsResponse> for ProtoNodeCommsResponse {
             }),
             TargetDifficulty(difficulty) => ProtoNodeCommsResponse::TargetDifficulty(difficulty.as_u64()),
             MmrNodes(added, deleted) => ProtoNodeCommsResponse::MmrNodes(ProtoMmrNodes { added, deleted }),
+            DeletedBitmap(deleted) => ProtoNodeCommsResponse::DeletedBitmap(deleted),
+            AccumulatedDifficulty { monero, sha3 } => {
+                ProtoNodeCommsResponse::AccumulatedDifficulty(ProtoAccumulatedDifficulty {
+                    monero: monero.as_u64(),
+                    sha3: sha3.as_u64(),
+                })
+            },
+            MempoolStats {
+                unconfirmed_txs,
+                total_weight,
+                total_fees,
+            } => ProtoNodeCommsResponse::MempoolStats(ProtoMempoolStats {
+                unconfirmed_txs,
+                total_weight,
+                total_fees: total_fees.into(),
+            }),
+            UtxoStatus(status) => ProtoNodeCommsResponse::UtxoStatus(ProtoUtxoStatus::from(status) as i32),
+            BlockBodies(bodies) => ProtoNodeCommsResponse::BlockBodies(ProtoBlockBodies {
+                bodies: bodies
+                    .into_iter()
+                    .map(|(height, body)| ProtoBlockBody {
+                        height,
+                        body: Some(body.into()),
+                    })
+                    .collect(),
+            }),
+            OrphanHeaders(headers) => {
+                let orphan_headers = headers.into_iter().map(Into::into).collect();
+                ProtoNodeCommsResponse::OrphanHeaders(orphan_headers)
+            },
+            BlockExists(exists) => ProtoNodeCommsResponse::BlockExists(exists),
+            AccumulatedDifficulties(difficulties) => {
+                ProtoNodeCommsResponse::AccumulatedDifficulties(ProtoAccumulatedDifficulties {
+                    difficulties: difficulties
+                        .into_iter()
+                        .map(|(height, monero, sha3)| ProtoBlockAccumulatedDifficulty {
+                            height,
+                            monero: monero.as_u64(),
+                            sha3: sha3.as_u64(),
+                        })
+                        .collect(),
+                })
+            },
         }
     }
 }
@@ -175,6 +268,26 @@ impl TryInto<Option<HistoricalBlock>> for base_node_proto::HistoricalBlockRespon
     }
 }
 
+impl From<UtxoStatus> for ProtoUtxoStatus {
+    fn from(status: UtxoStatus) -> Self {
+        match status {
+            UtxoStatus::Unspent => ProtoUtxoStatus::Unspent,
+            UtxoStatus::Spent => ProtoUtxoStatus::Spent,
+            UtxoStatus::Unknown => ProtoUtxoStatus::Unknown,
+        }
+    }
+}
+
+impl From<ProtoUtxoStatus> for UtxoStatus {
+    fn from(status: ProtoUtxoStatus) -> Self {
+        match status {
+            ProtoUtxoStatus::Unspent => UtxoStatus::Unspent,
+            ProtoUtxoStatus::Spent => UtxoStatus::Spent,
+            ProtoUtxoStatus::Unknown => UtxoStatus::Unknown,
+        }
+    }
+}
+
 //---------------------------------- Collection impls --------------------------------------------//
 
 // The following allow `Iterator::collect` to collect into these repeated types
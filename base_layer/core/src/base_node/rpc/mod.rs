@@ -34,6 +34,7 @@ use crate::proto::{
         TxQueryResponse,
         TxSubmissionResponse,
     },
+    mempool::StatsResponse,
     types::{Signature, Transaction},
 };
 #[cfg(feature = "base_node")]
@@ -72,6 +73,9 @@ pub trait BaseNodeWalletService: Send + Sync + 'static {
 
     #[rpc(method = 5)]
     async fn get_tip_info(&self, request: Request<()>) -> Result<Response<TipInfoResponse>, RpcStatus>;
+
+    #[rpc(method = 6)]
+    async fn get_mempool_stats(&self, request: Request<()>) -> Result<Response<StatsResponse>, RpcStatus>;
 }
 
 #[cfg(feature = "base_node")]
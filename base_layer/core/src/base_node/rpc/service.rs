@@ -37,6 +37,7 @@ use crate::{
             TxSubmissionRejectionReason,
             TxSubmissionResponse,
         },
+        mempool::StatsResponse as StatsResponseProto,
         types::{Signature as SignatureProto, Transaction as TransactionProto},
     },
     transactions::{transaction::Transaction, types::Signature},
@@ -325,4 +326,14 @@ impl<B: BlockchainBackend + 'static> BaseNodeWalletService for BaseNodeWalletRpc
             is_synced,
         }))
     }
+
+    async fn get_mempool_stats(&self, _request: Request<()>) -> Result<Response<StatsResponseProto>, RpcStatus> {
+        let stats = self
+            .mempool()
+            .get_stats()
+            .await
+            .map_err(RpcStatus::log_internal_error(LOG_TARGET))?;
+
+        Ok(Response::new(stats.into()))
+    }
 }
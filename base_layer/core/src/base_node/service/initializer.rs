@@ -36,6 +36,7 @@ use crate::{
 use futures::{channel::mpsc, future, Stream, StreamExt};
 use log::*;
 use std::{convert::TryFrom, sync::Arc};
+use tari_comms::connectivity::ConnectivityRequester;
 use tari_comms_dht::Dht;
 use tari_p2p::{
     comms_connector::{PeerMessage, SubscriptionFactory},
@@ -162,14 +163,22 @@ where T: BlockchainBackend + 'static
             local_block_sender_service,
             block_event_sender.clone(),
         );
-        let inbound_nch = InboundNodeCommsHandlers::new(
+        let config = self.config;
+        let mut inbound_nch = InboundNodeCommsHandlers::new(
             block_event_sender,
             self.blockchain_db.clone(),
             self.mempool.clone(),
             self.consensus_manager.clone(),
             outbound_nci.clone(),
-        );
-        let config = self.config;
+        )
+        .with_block_template_cache_max_age(config.block_template_cache_max_age)
+        .with_empty_template_on_mempool_error(config.empty_template_on_mempool_error);
+        if let Some(max_reorg_depth) = config.max_reorg_depth {
+            inbound_nch = inbound_nch.with_max_reorg_depth(max_reorg_depth);
+        }
+        if let Some(fanout) = config.block_propagation_fanout {
+            inbound_nch = inbound_nch.with_block_propagation_fanout(fanout);
+        }
 
         // Register handle to OutboundNodeCommsInterface before waiting for handles to be ready
         context.register_handle(outbound_nci);
@@ -180,6 +189,7 @@ where T: BlockchainBackend + 'static
             let outbound_message_service = dht.outbound_requester();
 
             let state_machine = handles.expect_handle::<StateMachineHandle>();
+            let connectivity = handles.expect_handle::<ConnectivityRequester>();
 
             let streams = BaseNodeStreams {
                 outbound_request_stream,
@@ -190,8 +200,14 @@ where T: BlockchainBackend + 'static
                 local_request_stream,
                 local_block_stream,
             };
-            let service =
-                BaseNodeService::new(outbound_message_service, inbound_nch, config, state_machine).start(streams);
+            let service = BaseNodeService::new(
+                outbound_message_service,
+                inbound_nch,
+                config,
+                state_machine,
+                connectivity,
+            )
+            .start(streams);
             futures::pin_mut!(service);
             future::select(service, handles.get_shutdown_signal()).await;
             info!(target: LOG_TARGET, "Base Node Service shutdown");
@@ -34,7 +34,7 @@ use crate::{
         StateMachineHandle,
     },
     blocks::{Block, NewBlock},
-    chain_storage::BlockchainBackend,
+    chain_storage::{BlockAddResult, BlockchainBackend},
     proto as shared_protos,
     proto::{base_node as proto, base_node::base_node_service_request::Request},
 };
@@ -55,7 +55,10 @@ use tari_common_types::{
     types::BlockHash,
     waiting_requests::{generate_request_key, RequestKey, WaitingRequests},
 };
-use tari_comms::peer_manager::NodeId;
+use tari_comms::{
+    connectivity::{ConnectivityRequester, ConnectivitySelection},
+    peer_manager::NodeId,
+};
 use tari_comms_dht::{
     domain_message::OutboundDomainMessage,
     envelope::NodeDestination,
@@ -79,6 +82,18 @@ pub struct BaseNodeServiceConfig {
     pub fetch_utxos_timeout: Duration,
     /// The fraction of responses that need to be received for a corresponding service request to be finalize.
     pub desired_response_fraction: f32,
+    /// The maximum number of main chain blocks that a reorg triggered by a propagated block may remove. `None`
+    /// allows reorgs of any depth.
+    pub max_reorg_depth: Option<u64>,
+    /// The maximum time that a new block template is reused for identical template requests while the tip is
+    /// unchanged. A zero duration disables template caching.
+    pub block_template_cache_max_age: Duration,
+    /// Build a block template without mempool transactions if the mempool cannot be queried, instead of failing the
+    /// template request.
+    pub empty_template_on_mempool_error: bool,
+    /// The maximum number of connected base nodes that a new block is propagated to. `None` propagates to all
+    /// connected peers.
+    pub block_propagation_fanout: Option<usize>,
 }
 
 impl Default for BaseNodeServiceConfig {
@@ -88,6 +103,10 @@ impl Default for BaseNodeServiceConfig {
             fetch_blocks_timeout: Duration::from_secs(150),
             fetch_utxos_timeout: Duration::from_secs(600),
             desired_response_fraction: 0.6,
+            max_reorg_depth: None,
+            block_template_cache_max_age: Duration::from_secs(1),
+            empty_template_on_mempool_error: true,
+            block_propagation_fanout: None,
         }
     }
 }
@@ -99,7 +118,7 @@ pub(super) struct BaseNodeStreams<SOutReq, SInReq, SInRes, SBlockIn, SLocalReq,
     pub outbound_request_stream: SOutReq,
     /// Blocks to be propagated out to the network. The second element of the tuple is a list of peers to exclude from
     /// this round of propagation
-    pub outbound_block_stream: UnboundedReceiver<(NewBlock, Vec<NodeId>)>,
+    pub outbound_block_stream: UnboundedReceiver<(NewBlock, Vec<NodeId>, Option<usize>)>,
     /// `BaseNodeRequest` messages received from external peers
     pub inbound_request_stream: SInReq,
     /// `BaseNodeResponse` messages received from external peers
@@ -123,6 +142,7 @@ pub(super) struct BaseNodeService<B> {
     timeout_receiver_stream: Option<Receiver<RequestKey>>,
     config: BaseNodeServiceConfig,
     state_machine_handle: StateMachineHandle,
+    connectivity: ConnectivityRequester,
 }
 
 impl<B> BaseNodeService<B>
@@ -133,6 +153,7 @@ where B: BlockchainBackend + 'static
         inbound_nch: InboundNodeCommsHandlers<B>,
         config: BaseNodeServiceConfig,
         state_machine_handle: StateMachineHandle,
+        connectivity: ConnectivityRequester,
     ) -> Self {
         let (timeout_sender, timeout_receiver) = channel(100);
         Self {
@@ -143,6 +164,7 @@ where B: BlockchainBackend + 'static
             timeout_receiver_stream: Some(timeout_receiver),
             config,
             state_machine_handle,
+            connectivity,
         }
     }
 
@@ -158,7 +180,8 @@ where B: BlockchainBackend + 'static
         SInRes: Stream<Item = DomainMessage<proto::BaseNodeServiceResponse>>,
         SBlockIn: Stream<Item = DomainMessage<NewBlock>>,
         SLocalReq: Stream<Item = RequestContext<NodeCommsRequest, Result<NodeCommsResponse, CommsInterfaceError>>>,
-        SLocalBlock: Stream<Item = RequestContext<(Block, Broadcast), Result<BlockHash, CommsInterfaceError>>>,
+        SLocalBlock:
+            Stream<Item = RequestContext<(Block, Broadcast), Result<(BlockHash, BlockAddResult), CommsInterfaceError>>>,
     {
         let outbound_request_stream = streams.outbound_request_stream.fuse();
         pin_mut!(outbound_request_stream);
@@ -188,8 +211,8 @@ where B: BlockchainBackend + 'static
                 },
 
                 // Outbound block messages from the OutboundNodeCommsInterface
-                (block, excluded_peers) = outbound_block_stream.select_next_some() => {
-                    self.spawn_handle_outbound_block(block, excluded_peers);
+                (block, excluded_peers, fanout) = outbound_block_stream.select_next_some() => {
+                    self.spawn_handle_outbound_block(block, excluded_peers, fanout);
                 },
 
                 // Incoming request messages from the Comms layer
@@ -262,10 +285,18 @@ where B: BlockchainBackend + 'static
         });
     }
 
-    fn spawn_handle_outbound_block(&self, new_block: NewBlock, excluded_peers: Vec<NodeId>) {
+    fn spawn_handle_outbound_block(&self, new_block: NewBlock, excluded_peers: Vec<NodeId>, fanout: Option<usize>) {
         let outbound_message_service = self.outbound_message_service.clone();
+        let connectivity = self.connectivity.clone();
         task::spawn(async move {
-            let result = handle_outbound_block(outbound_message_service, new_block, excluded_peers).await;
+            let result = handle_outbound_block(
+                outbound_message_service,
+                connectivity,
+                new_block,
+                excluded_peers,
+                fanout,
+            )
+            .await;
 
             if let Err(e) = result {
                 error!(target: LOG_TARGET, "Failed to handle outbound block message {:?}", e);
@@ -360,7 +391,7 @@ where B: BlockchainBackend + 'static
 
     fn spawn_handle_local_block(
         &self,
-        block_context: RequestContext<(Block, Broadcast), Result<BlockHash, CommsInterfaceError>>,
+        block_context: RequestContext<(Block, Broadcast), Result<(BlockHash, BlockAddResult), CommsInterfaceError>>,
     ) {
         let inbound_nch = self.inbound_nch.clone();
         task::spawn(async move {
@@ -539,6 +570,7 @@ async fn handle_outbound_request(
             if let Some(r) = service_request.request.clone() {
                 match r {
                     Request::FetchMatchingBlocks(_) |
+                    Request::FetchBlocksInRange(_) |
                     Request::FetchBlocksWithHashes(_) |
                     Request::FetchBlocksWithKernels(_) |
                     Request::FetchBlocksWithUtxos(_) => {
@@ -606,20 +638,42 @@ async fn handle_outbound_request(
 
 async fn handle_outbound_block(
     mut outbound_message_service: OutboundMessageRequester,
+    mut connectivity: ConnectivityRequester,
     new_block: NewBlock,
     exclude_peers: Vec<NodeId>,
+    fanout: Option<usize>,
 ) -> Result<(), CommsInterfaceError> {
-    outbound_message_service
-        .flood(
-            NodeDestination::Unknown,
-            OutboundEncryption::ClearText,
-            exclude_peers,
-            OutboundDomainMessage::new(
-                TariMessageType::NewBlock,
-                shared_protos::core::NewBlock::from(new_block),
-            ),
-        )
-        .await?;
+    let new_block = shared_protos::core::NewBlock::from(new_block);
+    match fanout {
+        Some(n) => {
+            // As with flood, only connected base nodes are selected
+            let connections = connectivity
+                .select_connections(ConnectivitySelection::random_nodes(n, exclude_peers))
+                .await?;
+            for conn in connections {
+                outbound_message_service
+                    .send_message(
+                        SendMessageParams::new()
+                            .direct_node_id(conn.peer_node_id().clone())
+                            .with_destination(NodeDestination::Unknown)
+                            .with_encryption(OutboundEncryption::ClearText)
+                            .finish(),
+                        OutboundDomainMessage::new(TariMessageType::NewBlock, new_block.clone()),
+                    )
+                    .await?;
+            }
+        },
+        None => {
+            outbound_message_service
+                .flood(
+                    NodeDestination::Unknown,
+                    OutboundEncryption::ClearText,
+                    exclude_peers,
+                    OutboundDomainMessage::new(TariMessageType::NewBlock, new_block),
+                )
+                .await?;
+        },
+    }
     Ok(())
 }
 
@@ -291,18 +291,27 @@ impl Display for BlockSyncInfo {
 #[derive(Clone, Debug, PartialEq)]
 pub struct HorizonSyncInfo {
     pub sync_peers: Vec<NodeId>,
+    /// The height that the horizon state is being synced to
+    pub horizon_sync_height: u64,
     pub status: HorizonSyncStatus,
 }
 
 impl HorizonSyncInfo {
-    pub fn new(sync_peers: Vec<NodeId>, status: HorizonSyncStatus) -> HorizonSyncInfo {
-        HorizonSyncInfo { sync_peers, status }
+    pub fn new(sync_peers: Vec<NodeId>, horizon_sync_height: u64, status: HorizonSyncStatus) -> HorizonSyncInfo {
+        HorizonSyncInfo {
+            sync_peers,
+            horizon_sync_height,
+            status,
+        }
     }
 }
 
 impl Display for HorizonSyncInfo {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        fmt.write_str("Syncing horizon state from the following peers: \n")?;
+        fmt.write_str(&format!(
+            "Syncing horizon state to height {} from the following peers: \n",
+            self.horizon_sync_height
+        ))?;
         for peer in &self.sync_peers {
             fmt.write_str(&format!("{}\n", peer))?;
         }
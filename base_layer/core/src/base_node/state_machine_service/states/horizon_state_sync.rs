@@ -82,7 +82,11 @@ impl HorizonStateSync {
             return StateEvent::HorizonStateSynchronized;
         }
 
-        let info = HorizonSyncInfo::new(vec![self.sync_peer.peer_node_id().clone()], HorizonSyncStatus::Starting);
+        let info = HorizonSyncInfo::new(
+            vec![self.sync_peer.peer_node_id().clone()],
+            horizon_sync_height,
+            HorizonSyncStatus::Starting,
+        );
         shared.set_state_info(StateInfo::HorizonSync(info));
 
         let prover = CryptoFactories::default().range_proof;
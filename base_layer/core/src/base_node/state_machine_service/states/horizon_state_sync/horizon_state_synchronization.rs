@@ -145,6 +145,7 @@ impl<'a, B: BlockchainBackend + 'static> HorizonStateSynchronization<'a, B> {
 
         let info = HorizonSyncInfo::new(
             vec![self.sync_peer.peer_node_id().clone()],
+            self.horizon_sync_height,
             HorizonSyncStatus::Kernels(local_num_kernels, remote_num_kernels),
         );
         self.shared.set_state_info(StateInfo::HorizonSync(info));
@@ -239,6 +240,7 @@ impl<'a, B: BlockchainBackend + 'static> HorizonStateSynchronization<'a, B> {
             if mmr_position % 100 == 0 || mmr_position == self.num_kernels {
                 let info = HorizonSyncInfo::new(
                     vec![self.sync_peer.peer_node_id().clone()],
+                    self.horizon_sync_height,
                     HorizonSyncStatus::Kernels(mmr_position, self.num_kernels),
                 );
                 self.shared.set_state_info(StateInfo::HorizonSync(info));
@@ -270,6 +272,7 @@ impl<'a, B: BlockchainBackend + 'static> HorizonStateSynchronization<'a, B> {
 
         let info = HorizonSyncInfo::new(
             vec![self.sync_peer.peer_node_id().clone()],
+            self.horizon_sync_height,
             HorizonSyncStatus::Outputs(local_num_outputs, self.num_outputs),
         );
         self.shared.set_state_info(StateInfo::HorizonSync(info));
@@ -501,6 +504,7 @@ impl<'a, B: BlockchainBackend + 'static> HorizonStateSynchronization<'a, B> {
             if mmr_position % 100 == 0 || mmr_position == self.num_outputs {
                 let info = HorizonSyncInfo::new(
                     vec![self.sync_peer.peer_node_id().clone()],
+                    self.horizon_sync_height,
                     HorizonSyncStatus::Outputs(mmr_position, self.num_outputs),
                 );
                 self.shared.set_state_info(StateInfo::HorizonSync(info));
@@ -522,6 +526,7 @@ impl<'a, B: BlockchainBackend + 'static> HorizonStateSynchronization<'a, B> {
 
         let info = HorizonSyncInfo::new(
             vec![self.sync_peer.peer_node_id().clone()],
+            self.horizon_sync_height,
             HorizonSyncStatus::Finalizing,
         );
         self.shared.set_state_info(StateInfo::HorizonSync(info));
@@ -23,6 +23,7 @@
 use crate::{
     base_node::{
         chain_metadata_service::{ChainMetadataEvent, PeerChainMetadata},
+        comms_interface::ChainComparison,
         state_machine_service::{
             states::{BlockSync, HeaderSync, StateEvent, StateEvent::FatalError, StateInfo, SyncStatus, Waiting},
             BaseNodeStateMachine,
@@ -284,7 +285,7 @@ fn determine_sync_mode(
     use SyncStatus::*;
     let network_tip_accum_difficulty = network.accumulated_difficulty();
     let local_tip_accum_difficulty = local.accumulated_difficulty();
-    if local_tip_accum_difficulty < network_tip_accum_difficulty {
+    if ChainComparison::compare(local, &network) == ChainComparison::Behind {
         let local_tip_height = local.height_of_longest_chain();
         let network_tip_height = network.height_of_longest_chain();
         info!(
@@ -176,18 +176,26 @@ impl BlockHeader {
         heights
     }
 
+    /// Given a slice of headers (in reverse order), returns the periods between each consecutive pair of headers.
+    /// Negative periods are returned as zero.
+    pub fn timing_intervals(headers: &[BlockHeader]) -> Vec<u64> {
+        headers
+            .windows(2)
+            .map(|next| match next[0].timestamp.checked_sub(next[1].timestamp) {
+                Some(delta) => delta.as_u64(),
+                None => 0u64,
+            })
+            .collect()
+    }
+
     /// Given a slice of headers (in reverse order), calculate the maximum, minimum and average periods between them
     pub fn timing_stats(headers: &[BlockHeader]) -> (u64, u64, f64) {
         if headers.len() < 2 {
             (0, 0, 0.0)
         } else {
-            let (max, min) = headers.windows(2).fold((0u64, std::u64::MAX), |(max, min), next| {
-                let dt = match next[0].timestamp.checked_sub(next[1].timestamp) {
-                    Some(delta) => delta.as_u64(),
-                    None => 0u64,
-                };
-                (max.max(dt), min.min(dt))
-            });
+            let (max, min) = Self::timing_intervals(headers)
+                .into_iter()
+                .fold((0u64, std::u64::MAX), |(max, min), dt| (max.max(dt), min.min(dt)));
 
             let dt = headers.first().unwrap().timestamp - headers.last().unwrap().timestamp;
             let n = headers.len() - 1;
@@ -374,6 +382,20 @@ mod test {
         assert!((avg - 100f64).abs() < error_margin);
     }
 
+    #[test]
+    fn test_timing_intervals() {
+        let headers = vec![500, 350, 300, 310, 100u64]
+            .into_iter()
+            .map(|t| BlockHeader {
+                timestamp: EpochTime::from(t),
+                ..BlockHeader::default()
+            })
+            .collect::<Vec<BlockHeader>>();
+        assert_eq!(BlockHeader::timing_intervals(&headers), vec![150, 50, 0, 210]);
+        assert!(BlockHeader::timing_intervals(&headers[..1]).is_empty());
+        assert!(BlockHeader::timing_intervals(&[]).is_empty());
+    }
+
     #[test]
     fn timing_negative_blocks() {
         let headers = vec![150, 90, 100u64]
@@ -38,6 +38,7 @@ use crate::{
         MmrTree,
         PrunedOutput,
         TargetDifficulties,
+        UtxoStatus,
     },
     common::rolling_vec::RollingVec,
     proof_of_work::{PowAlgorithm, TargetDifficultyWindow},
@@ -143,6 +144,8 @@ impl<B: BlockchainBackend + 'static> AsyncBlockchainDb<B> {
 
     make_async_fn!(fetch_utxos(hashes: Vec<HashOutput>) -> Vec<Option<(TransactionOutput, bool)>>, "fetch_utxos");
 
+    make_async_fn!(fetch_utxo_status(commitment: Commitment) -> UtxoStatus, "fetch_utxo_status");
+
     make_async_fn!(fetch_utxos_by_mmr_position(start: u64, end: u64, deleted: Arc<Bitmap>) -> (Vec<PrunedOutput>, Bitmap), "fetch_utxos_by_mmr_position");
 
     //---------------------------------- Kernel --------------------------------------------//
@@ -193,6 +196,10 @@ impl<B: BlockchainBackend + 'static> AsyncBlockchainDb<B> {
     //---------------------------------- Block --------------------------------------------//
     make_async_fn!(add_block(block: Arc<Block>) -> BlockAddResult, "add_block");
 
+    make_async_fn!(add_orphan_block(block: Arc<Block>) -> (), "add_orphan_block");
+
+    make_async_fn!(fetch_reorg_depth(block: Arc<Block>) -> Option<u64>, "fetch_reorg_depth");
+
     make_async_fn!(cleanup_orphans() -> (), "cleanup_orphans");
 
     make_async_fn!(cleanup_all_orphans() -> (), "cleanup_all_orphans");
@@ -205,6 +212,8 @@ impl<B: BlockchainBackend + 'static> AsyncBlockchainDb<B> {
 
     make_async_fn!(fetch_orphan(hash: HashOutput) -> Block, "fetch_orphan");
 
+    make_async_fn!(fetch_orphan_headers() -> Vec<BlockHeader>, "fetch_orphan_headers");
+
     make_async_fn!(fetch_block_by_hash(hash: HashOutput) -> Option<HistoricalBlock>, "fetch_block_by_hash");
 
     make_async_fn!(fetch_block_with_kernel(excess_sig: Signature) -> Option<HistoricalBlock>, "fetch_block_with_kernel");
@@ -16,7 +16,7 @@ use crate::{
     },
     transactions::{
         transaction::{TransactionInput, TransactionKernel, TransactionOutput},
-        types::{HashOutput, Signature},
+        types::{Commitment, HashOutput, Signature},
     },
 };
 use croaring::Bitmap;
@@ -108,6 +108,10 @@ pub trait BlockchainBackend: Send + Sync {
         output_hash: &HashOutput,
     ) -> Result<Option<(TransactionOutput, u32, u64)>, ChainStorageError>;
 
+    /// Fetch the hashes of all outputs in the main chain with the given commitment, whether or not they have been
+    /// spent. Outputs that were synced in pruned form have no commitment and are not returned.
+    fn fetch_output_hashes_by_commitment(&self, commitment: &Commitment) -> Result<Vec<HashOutput>, ChainStorageError>;
+
     /// Fetch all outputs in a block
     fn fetch_outputs_in_block(&self, header_hash: &HashOutput) -> Result<Vec<PrunedOutput>, ChainStorageError>;
 
@@ -122,6 +126,8 @@ pub trait BlockchainBackend: Send + Sync {
     fn fetch_mmr_leaf_index(&self, tree: MmrTree, hash: &Hash) -> Result<Option<u32>, ChainStorageError>;
     /// Returns the number of blocks in the block orphan pool.
     fn orphan_count(&self) -> Result<usize, ChainStorageError>;
+    /// Returns the headers of all blocks in the block orphan pool, ordered by height.
+    fn fetch_orphan_headers(&self) -> Result<Vec<BlockHeader>, ChainStorageError>;
     /// Returns the stored header with the highest corresponding height.
     fn fetch_last_header(&self) -> Result<BlockHeader, ChainStorageError>;
     /// Returns the stored header with the highest corresponding height.
@@ -41,6 +41,7 @@ use crate::{
         Optional,
         OrNotFound,
         TargetDifficulties,
+        UtxoStatus,
     },
     common::rolling_vec::RollingVec,
     consensus::{chain_strength_comparer::ChainStrengthComparer, ConsensusConstants, ConsensusManager},
@@ -307,6 +308,25 @@ where B: BlockchainBackend
         Ok(result)
     }
 
+    /// Returns whether an output with the given commitment is unspent, spent or does not exist in the main chain. If
+    /// the commitment has been reused, it is unspent if any of the outputs with that commitment is unspent. Outputs
+    /// that were synced in pruned form (i.e. spent before the pruning horizon) are reported as unknown.
+    pub fn fetch_utxo_status(&self, commitment: Commitment) -> Result<UtxoStatus, ChainStorageError> {
+        let db = self.db_read_access()?;
+        let output_hashes = db.fetch_output_hashes_by_commitment(&commitment)?;
+        let deleted = db.fetch_deleted_bitmap()?;
+        let mut status = UtxoStatus::Unknown;
+        for output_hash in output_hashes {
+            if let Some(mmr_position) = db.fetch_mmr_leaf_index(MmrTree::Utxo, &output_hash)? {
+                if !deleted.bitmap().contains(mmr_position) {
+                    return Ok(UtxoStatus::Unspent);
+                }
+                status = UtxoStatus::Spent;
+            }
+        }
+        Ok(status)
+    }
+
     pub fn fetch_kernel_by_excess(
         &self,
         excess: &[u8],
@@ -592,6 +612,12 @@ where B: BlockchainBackend
         db.orphan_count()
     }
 
+    /// Returns the headers of all blocks currently in the orphan pool, ordered by height.
+    pub fn fetch_orphan_headers(&self) -> Result<Vec<BlockHeader>, ChainStorageError> {
+        let db = self.db_read_access()?;
+        db.fetch_orphan_headers()
+    }
+
     /// Returns the set of target difficulties for the specified proof of work algorithm. The calculated target
     /// difficulty will be for the given height i.e calculated from the previous header backwards until the target
     /// difficulty window is populated according to consensus constants for the given height.
@@ -739,6 +765,75 @@ where B: BlockchainBackend
         Ok(block_add_result)
     }
 
+    /// Returns the number of main chain blocks that would be removed if `block` was added, i.e. the depth of the chain
+    /// reorg it would trigger. `None` is returned if adding the block would not make its branch the strongest chain,
+    /// which is the case if the block is already in the main chain, does not connect to the main chain, has an invalid
+    /// header or does not have more accumulated difficulty than the current tip. Orphans that would be connected to
+    /// the main chain by `block` are not taken into account.
+    pub fn fetch_reorg_depth(&self, block: Arc<Block>) -> Result<Option<u64>, ChainStorageError> {
+        let db = self.db_read_access()?;
+        let hash = block.hash();
+        if db.contains(&DbKey::BlockHash(hash.clone()))? {
+            return Ok(None);
+        }
+        let parent = match db
+            .fetch_chain_header_in_all_chains(&block.header.prev_hash)
+            .optional()?
+        {
+            Some(parent) => parent,
+            None => return Ok(None),
+        };
+        // Blocks with an invalid header are rejected when they are added
+        let achieved_target = match self
+            .validators
+            .header
+            .validate(&*db, &block.header, &self.difficulty_calculator)
+        {
+            Ok(achieved_target) => achieved_target,
+            Err(_) => return Ok(None),
+        };
+        let accumulated_data = BlockHeaderAccumulatedData::builder(parent.accumulated_data())
+            .with_hash(hash.clone())
+            .with_achieved_target_difficulty(achieved_target)
+            .with_total_kernel_offset(block.header.total_kernel_offset.clone())
+            .build()?;
+        let candidate = ChainHeader::try_construct(block.header.clone(), accumulated_data).ok_or_else(|| {
+            ChainStorageError::InvalidOperation(format!(
+                "Attempt to create mismatched ChainHeader with hash {}",
+                hash.to_hex()
+            ))
+        })?;
+        let tip_header = db.fetch_tip_header()?;
+        if self
+            .consensus_manager
+            .chain_strength_comparer()
+            .compare(&candidate, &tip_header) !=
+            Ordering::Greater
+        {
+            return Ok(None);
+        }
+
+        // Follow the branch back through the orphan pool until it meets the main chain
+        let mut fork_header = parent;
+        while !db.contains(&DbKey::BlockHash(fork_header.hash().clone()))? {
+            fork_header = db.fetch_chain_header_in_all_chains(&fork_header.header().prev_hash)?;
+        }
+        Ok(Some(tip_header.height().saturating_sub(fork_header.height())))
+    }
+
+    /// Validates the block and stores it in the orphan pool without considering it as a candidate for the main chain.
+    /// Nothing is written if the orphan is already stored.
+    pub fn add_orphan_block(&self, block: Arc<Block>) -> Result<(), ChainStorageError> {
+        self.validators.orphan.validate(&block)?;
+        let mut db = self.db_write_access()?;
+        if db.contains(&DbKey::OrphanBlock(block.hash()))? {
+            return Ok(());
+        }
+        let mut txn = DbTransaction::new();
+        txn.insert_orphan(block);
+        db.write(txn)
+    }
+
     /// Clean out the entire orphan pool
     pub fn cleanup_orphans(&self) -> Result<(), ChainStorageError> {
         let mut db = self.db_write_access()?;
@@ -62,6 +62,7 @@ use crate::{
             LMDB_DB_ORPHAN_PARENT_MAP_INDEX,
             LMDB_DB_TXOS_HASH_TO_INDEX,
             LMDB_DB_UTXOS,
+            LMDB_DB_UTXO_COMMITMENT_INDEX,
             LMDB_DB_UTXO_MMR_SIZE_INDEX,
         },
         BlockchainBackend,
@@ -126,6 +127,7 @@ pub struct LMDBDatabase {
     utxos_db: DatabaseRef,
     inputs_db: DatabaseRef,
     txos_hash_to_index_db: DatabaseRef,
+    utxo_commitment_index: DatabaseRef,
     kernels_db: DatabaseRef,
     kernel_excess_index: DatabaseRef,
     kernel_excess_sig_index: DatabaseRef,
@@ -152,6 +154,7 @@ impl LMDBDatabase {
             utxos_db: get_database(&store, LMDB_DB_UTXOS)?,
             inputs_db: get_database(&store, LMDB_DB_INPUTS)?,
             txos_hash_to_index_db: get_database(&store, LMDB_DB_TXOS_HASH_TO_INDEX)?,
+            utxo_commitment_index: get_database(&store, LMDB_DB_UTXO_COMMITMENT_INDEX)?,
             kernels_db: get_database(&store, LMDB_DB_KERNELS)?,
             kernel_excess_index: get_database(&store, LMDB_DB_KERNEL_EXCESS_INDEX)?,
             kernel_excess_sig_index: get_database(&store, LMDB_DB_KERNEL_EXCESS_SIG_INDEX)?,
@@ -167,9 +170,41 @@ impl LMDBDatabase {
             _file_lock: Arc::new(file_lock),
         };
 
+        res.build_utxo_commitment_index_if_empty()?;
+
         Ok(res)
     }
 
+    /// Populates the UTXO commitment index from the stored outputs if the index is empty, i.e. for a database that was
+    /// created before the index existed. Pruned outputs do not have a commitment and are not indexed.
+    fn build_utxo_commitment_index_if_empty(&self) -> Result<(), ChainStorageError> {
+        let txn = self.read_transaction()?;
+        if lmdb_len(&txn, &self.utxo_commitment_index)? > 0 {
+            return Ok(());
+        }
+        let entries = lmdb_filter_map_values(&txn, &self.utxos_db, |row: TransactionOutputRowData| {
+            Ok(row.output.map(|output| (output.commitment, row.hash)))
+        })?;
+        drop(txn);
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        info!(
+            target: LOG_TARGET,
+            "Building the UTXO commitment index for {} outputs",
+            entries.len()
+        );
+        let write_txn = self.write_transaction()?;
+        for (commitment, hash) in entries {
+            lmdb_insert_dup(&write_txn, &self.utxo_commitment_index, commitment.as_bytes(), &hash)?;
+        }
+        write_txn
+            .commit()
+            .map_err(|e| ChainStorageError::AccessError(e.to_string()))?;
+        Ok(())
+    }
+
     /// Try to establish a read lock on the LMDB database. If an exclusive write lock has been previously acquired, this
     /// method will block until that lock is released.
     fn read_transaction(&self) -> Result<ReadTransaction<'_>, ChainStorageError> {
@@ -375,6 +410,14 @@ impl LMDBDatabase {
         let key = OutputKey::new(header_hash.clone(), mmr_position);
         let key_string = key.get_key();
 
+        // A commitment may be reused once the previous output with that commitment has been spent, so the index keeps
+        // every output hash for the commitment
+        lmdb_insert_dup(
+            txn,
+            &*self.utxo_commitment_index,
+            output.commitment.as_bytes(),
+            &output_hash,
+        )?;
         lmdb_insert(
             txn,
             &*self.txos_hash_to_index_db,
@@ -709,6 +752,15 @@ impl LMDBDatabase {
         for utxo in rows {
             trace!(target: LOG_TARGET, "Deleting UTXO `{}`", to_hex(&utxo.hash));
             lmdb_delete(&write_txn, &self.txos_hash_to_index_db, utxo.hash.as_slice())?;
+            if let Some(output) = utxo.output {
+                // Any earlier output with the same commitment keeps its own entry
+                lmdb_delete_key_value(
+                    &write_txn,
+                    &self.utxo_commitment_index,
+                    output.commitment.as_bytes(),
+                    &utxo.hash,
+                )?;
+            }
         }
         debug!(target: LOG_TARGET, "Deleting kernels...");
         let kernels =
@@ -1110,6 +1162,7 @@ pub fn create_lmdb_database<P: AsRef<Path>>(path: P, config: LMDBConfig) -> Resu
         .add_database(LMDB_DB_UTXOS, flags)
         .add_database(LMDB_DB_INPUTS, flags)
         .add_database(LMDB_DB_TXOS_HASH_TO_INDEX, flags)
+        .add_database(LMDB_DB_UTXO_COMMITMENT_INDEX, flags | db::DUPSORT)
         .add_database(LMDB_DB_KERNELS, flags)
         .add_database(LMDB_DB_KERNEL_EXCESS_INDEX, flags)
         .add_database(LMDB_DB_KERNEL_EXCESS_SIG_INDEX, flags)
@@ -1657,6 +1710,11 @@ impl BlockchainBackend for LMDBDatabase {
         }
     }
 
+    fn fetch_output_hashes_by_commitment(&self, commitment: &Commitment) -> Result<Vec<HashOutput>, ChainStorageError> {
+        let txn = self.read_transaction()?;
+        lmdb_get_multiple(&txn, &self.utxo_commitment_index, commitment.as_bytes())
+    }
+
     fn fetch_outputs_in_block(&self, header_hash: &HashOutput) -> Result<Vec<PrunedOutput>, ChainStorageError> {
         let txn = self.read_transaction()?;
         Ok(
@@ -1707,6 +1765,14 @@ impl BlockchainBackend for LMDBDatabase {
         lmdb_len(&txn, &self.orphans_db)
     }
 
+    fn fetch_orphan_headers(&self) -> Result<Vec<BlockHeader>, ChainStorageError> {
+        trace!(target: LOG_TARGET, "Get orphan headers");
+        let txn = self.read_transaction()?;
+        let mut headers = lmdb_filter_map_values(&txn, &self.orphans_db, |block: Block| Ok(Some(block.header)))?;
+        headers.sort_by_key(|h| h.height);
+        Ok(headers)
+    }
+
     /// Finds and returns the last stored header.
     fn fetch_last_header(&self) -> Result<BlockHeader, ChainStorageError> {
         let txn = self.read_transaction()?;
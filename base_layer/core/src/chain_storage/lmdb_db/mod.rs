@@ -39,6 +39,7 @@ pub const LMDB_DB_BLOCK_HASHES: &str = "block_hashes";
 pub const LMDB_DB_UTXOS: &str = "utxos";
 pub const LMDB_DB_INPUTS: &str = "inputs";
 pub const LMDB_DB_TXOS_HASH_TO_INDEX: &str = "txos_hash_to_index";
+pub const LMDB_DB_UTXO_COMMITMENT_INDEX: &str = "utxo_commitment_index";
 pub const LMDB_DB_KERNELS: &str = "kernels";
 pub const LMDB_DB_KERNEL_EXCESS_INDEX: &str = "kernel_excess_index";
 pub const LMDB_DB_KERNEL_EXCESS_SIG_INDEX: &str = "kernel_excess_sig_index";
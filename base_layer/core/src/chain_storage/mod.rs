@@ -94,3 +94,6 @@ pub use lmdb_db::{
 
 mod target_difficulties;
 pub use target_difficulties::TargetDifficulties;
+
+mod utxo_status;
+pub use utxo_status::UtxoStatus;
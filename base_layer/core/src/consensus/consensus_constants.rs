@@ -23,7 +23,10 @@
 use crate::{
     consensus::{network::NetworkConsensus, KERNEL_WEIGHT, WEIGHT_PER_OUTPUT},
     proof_of_work::{Difficulty, PowAlgorithm},
-    transactions::tari_amount::{uT, MicroTari, T},
+    transactions::{
+        tari_amount::{uT, MicroTari, T},
+        transaction::KernelFeatures,
+    },
 };
 use chrono::{DateTime, Duration, Utc};
 use std::{collections::HashMap, ops::Add};
@@ -62,6 +65,8 @@ pub struct ConsensusConstants {
     proof_of_work: HashMap<PowAlgorithm, PowAlgorithmConstants>,
     /// This is to keep track of the value inside of the genesis block
     faucet_value: MicroTari,
+    /// The kernel feature flags that are recognised by consensus. Kernels with any other flags set are invalid.
+    permitted_kernel_features: KernelFeatures,
 }
 
 /// This is just a convenience  wrapper to put all the info into a hashmap per diff algo
@@ -183,6 +188,11 @@ impl ConsensusConstants {
         self.max_randomx_seed_height
     }
 
+    /// The kernel feature flags that are recognised by consensus.
+    pub fn permitted_kernel_features(&self) -> KernelFeatures {
+        self.permitted_kernel_features
+    }
+
     pub fn localnet() -> Vec<Self> {
         let difficulty_block_window = 90;
         let mut algos = HashMap::new();
@@ -212,6 +222,7 @@ impl ConsensusConstants {
             max_randomx_seed_height: std::u64::MAX,
             proof_of_work: algos,
             faucet_value: (5000 * 4000) * T,
+            permitted_kernel_features: KernelFeatures::all(),
         }]
     }
 
@@ -245,6 +256,7 @@ impl ConsensusConstants {
             max_randomx_seed_height: std::u64::MAX,
             proof_of_work: algos,
             faucet_value: (5000 * 4000) * T,
+            permitted_kernel_features: KernelFeatures::all(),
         }]
     }
 
@@ -305,6 +317,7 @@ impl ConsensusConstants {
                 max_randomx_seed_height: std::u64::MAX,
                 proof_of_work: algos,
                 faucet_value: (5000 * 4000) * T,
+                permitted_kernel_features: KernelFeatures::all(),
             },
             ConsensusConstants {
                 effective_from_height: 1400,
@@ -320,6 +333,7 @@ impl ConsensusConstants {
                 max_randomx_seed_height: std::u64::MAX,
                 proof_of_work: algos2,
                 faucet_value: (5000 * 4000) * T,
+                permitted_kernel_features: KernelFeatures::all(),
             },
         ]
     }
@@ -353,6 +367,7 @@ impl ConsensusConstants {
             max_randomx_seed_height: std::u64::MAX,
             proof_of_work: algos,
             faucet_value: (5000 * 4000) * T,
+            permitted_kernel_features: KernelFeatures::all(),
         }]
     }
 
@@ -386,6 +401,7 @@ impl ConsensusConstants {
             max_randomx_seed_height: std::u64::MAX,
             proof_of_work: algos,
             faucet_value: MicroTari::from(0),
+            permitted_kernel_features: KernelFeatures::all(),
         }]
     }
 }
@@ -443,6 +459,11 @@ impl ConsensusConstantsBuilder {
         self
     }
 
+    pub fn with_permitted_kernel_features(mut self, features: KernelFeatures) -> Self {
+        self.consensus.permitted_kernel_features = features;
+        self
+    }
+
     pub fn with_emission_amounts(
         mut self,
         intial_amount: MicroTari,
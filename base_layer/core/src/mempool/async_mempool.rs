@@ -23,7 +23,7 @@
 use crate::{
     blocks::Block,
     mempool::{error::MempoolError, Mempool, StateResponse, StatsResponse, TxStorageResponse},
-    transactions::{transaction::Transaction, types::Signature},
+    transactions::{tari_amount::MicroTari, transaction::Transaction, types::Signature},
 };
 use std::sync::Arc;
 
@@ -64,5 +64,6 @@ make_async!(process_reorg(removed_blocks: Vec<Arc<Block>>, new_blocks: Vec<Arc<B
 make_async!(snapshot() -> Vec<Arc<Transaction>>);
 make_async!(retrieve(total_weight: u64) -> Vec<Arc<Transaction>>);
 make_async!(has_tx_with_excess_sig(excess_sig: Signature) -> TxStorageResponse);
+make_async!(unconfirmed_fees() -> MicroTari);
 make_async!(stats() -> StatsResponse);
 make_async!(state() -> StateResponse);
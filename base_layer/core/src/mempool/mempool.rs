@@ -30,7 +30,7 @@ use crate::{
         StatsResponse,
         TxStorageResponse,
     },
-    transactions::{transaction::Transaction, types::Signature},
+    transactions::{tari_amount::MicroTari, transaction::Transaction, types::Signature},
     validation::MempoolTransactionValidation,
 };
 use std::sync::{Arc, RwLock};
@@ -107,6 +107,14 @@ impl Mempool {
             .has_tx_with_excess_sig(excess_sig)
     }
 
+    /// Returns the total fees of the unconfirmed transactions in the Mempool.
+    pub fn unconfirmed_fees(&self) -> Result<MicroTari, MempoolError> {
+        self.pool_storage
+            .read()
+            .map_err(|e| MempoolError::BackendError(e.to_string()))?
+            .unconfirmed_fees()
+    }
+
     /// Gathers and returns the stats of the Mempool.
     pub fn stats(&self) -> Result<StatsResponse, MempoolError> {
         self.pool_storage
@@ -31,7 +31,7 @@ use crate::{
         StatsResponse,
         TxStorageResponse,
     },
-    transactions::{transaction::Transaction, types::Signature},
+    transactions::{tari_amount::MicroTari, transaction::Transaction, types::Signature},
     validation::{MempoolTransactionValidation, ValidationError},
 };
 use log::*;
@@ -223,6 +223,11 @@ impl MempoolStorage {
         Ok(self.unconfirmed_pool.calculate_weight() + self.reorg_pool.calculate_weight()?)
     }
 
+    /// Returns the total fees of the unconfirmed transactions in the Mempool.
+    pub fn unconfirmed_fees(&self) -> Result<MicroTari, MempoolError> {
+        Ok(self.unconfirmed_pool.calculate_total_fees())
+    }
+
     /// Gathers and returns the stats of the Mempool.
     pub fn stats(&self) -> Result<StatsResponse, MempoolError> {
         Ok(StatsResponse {
@@ -230,6 +235,7 @@ impl MempoolStorage {
             unconfirmed_txs: self.unconfirmed_pool.len(),
             reorg_txs: self.reorg_pool.len()?,
             total_weight: self.calculate_weight()?,
+            fee_per_gram: self.unconfirmed_pool.fee_per_gram_stats(),
         })
     }
 
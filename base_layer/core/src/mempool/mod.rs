@@ -72,7 +72,7 @@ mod sync_protocol;
 #[cfg(feature = "base_node")]
 pub use sync_protocol::MempoolSyncInitializer;
 
-use crate::transactions::{transaction::Transaction, types::Signature};
+use crate::transactions::{tari_amount::MicroTari, transaction::Transaction, types::Signature};
 use core::fmt::{Display, Error, Formatter};
 use serde::{Deserialize, Serialize};
 use tari_crypto::tari_utilities::hex::Hex;
@@ -83,14 +83,34 @@ pub struct StatsResponse {
     pub unconfirmed_txs: usize,
     pub reorg_txs: usize,
     pub total_weight: u64,
+    pub fee_per_gram: FeePerGramStats,
 }
 
 impl Display for StatsResponse {
     fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
         write!(
             fmt,
-            "Mempool stats: Total transactions: {}, Unconfirmed: {}, Published: {}, Total Weight: {}",
-            self.total_txs, self.unconfirmed_txs, self.reorg_txs, self.total_weight
+            "Mempool stats: Total transactions: {}, Unconfirmed: {}, Published: {}, Total Weight: {}, Fee per gram: {}",
+            self.total_txs, self.unconfirmed_txs, self.reorg_txs, self.total_weight, self.fee_per_gram
+        )
+    }
+}
+
+/// The lowest, average and highest fee per gram of the unconfirmed transactions in the mempool. All values are zero
+/// when the unconfirmed pool is empty.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct FeePerGramStats {
+    pub min_fee_per_gram: MicroTari,
+    pub avg_fee_per_gram: MicroTari,
+    pub max_fee_per_gram: MicroTari,
+}
+
+impl Display for FeePerGramStats {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        write!(
+            fmt,
+            "min: {}, avg: {}, max: {}",
+            self.min_fee_per_gram, self.avg_fee_per_gram, self.max_fee_per_gram
         )
     }
 }
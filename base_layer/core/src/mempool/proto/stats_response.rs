@@ -20,7 +20,10 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::mempool::{proto::mempool::StatsResponse as ProtoStatsResponse, StatsResponse};
+use crate::{
+    mempool::{proto::mempool::StatsResponse as ProtoStatsResponse, FeePerGramStats, StatsResponse},
+    transactions::tari_amount::MicroTari,
+};
 use std::convert::TryFrom;
 
 impl TryFrom<ProtoStatsResponse> for StatsResponse {
@@ -32,6 +35,11 @@ impl TryFrom<ProtoStatsResponse> for StatsResponse {
             unconfirmed_txs: stats.unconfirmed_txs as usize,
             reorg_txs: stats.reorg_txs as usize,
             total_weight: stats.total_weight,
+            fee_per_gram: FeePerGramStats {
+                min_fee_per_gram: MicroTari(stats.min_fee_per_gram),
+                avg_fee_per_gram: MicroTari(stats.avg_fee_per_gram),
+                max_fee_per_gram: MicroTari(stats.max_fee_per_gram),
+            },
         })
     }
 }
@@ -43,6 +51,9 @@ impl From<StatsResponse> for ProtoStatsResponse {
             unconfirmed_txs: stats.unconfirmed_txs as u64,
             reorg_txs: stats.reorg_txs as u64,
             total_weight: stats.total_weight,
+            min_fee_per_gram: stats.fee_per_gram.min_fee_per_gram.0,
+            avg_fee_per_gram: stats.fee_per_gram.avg_fee_per_gram.0,
+            max_fee_per_gram: stats.fee_per_gram.max_fee_per_gram.0,
         }
     }
 }
@@ -41,7 +41,10 @@ fn setup() -> (MempoolRpcService, MempoolMockState, RpcRequestMock, TempDir) {
 
 mod get_stats {
     use super::*;
-    use crate::mempool::{MempoolService, StatsResponse};
+    use crate::{
+        mempool::{FeePerGramStats, MempoolService, StatsResponse},
+        transactions::tari_amount::MicroTari,
+    };
 
     #[tokio_macros::test_basic]
     async fn it_returns_the_stats() {
@@ -52,6 +55,11 @@ mod get_stats {
 
             reorg_txs: 5,
             total_weight: 6,
+            fee_per_gram: FeePerGramStats {
+                min_fee_per_gram: MicroTari(7),
+                avg_fee_per_gram: MicroTari(8),
+                max_fee_per_gram: MicroTari(9),
+            },
         };
         mempool.set_get_stats_response(expected_stats.clone()).await;
 
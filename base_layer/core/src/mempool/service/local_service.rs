@@ -132,6 +132,7 @@ mod test {
             unconfirmed_txs: 3,
             reorg_txs: 4,
             total_weight: 1000,
+            fee_per_gram: Default::default(),
         }
     }
 
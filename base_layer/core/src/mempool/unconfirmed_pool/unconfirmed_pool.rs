@@ -26,8 +26,10 @@ use crate::{
         consts::{MEMPOOL_UNCONFIRMED_POOL_STORAGE_CAPACITY, MEMPOOL_UNCONFIRMED_POOL_WEIGHT_TRANSACTION_SKIP_COUNT},
         priority::{FeePriority, PrioritizedTransaction},
         unconfirmed_pool::UnconfirmedPoolError,
+        FeePerGramStats,
     },
     transactions::{
+        tari_amount::MicroTari,
         transaction::Transaction,
         types::{HashOutput, Signature},
     },
@@ -460,6 +462,43 @@ impl UnconfirmedPool {
             .fold(0, |weight, (_, ptx)| weight + ptx.transaction.calculate_weight())
     }
 
+    /// Returns the total fees of all transactions stored in the pool.
+    pub fn calculate_total_fees(&self) -> MicroTari {
+        self.txs_by_signature
+            .values()
+            .map(|ptx| ptx.transaction.body.get_total_fee())
+            .sum()
+    }
+
+    /// Returns the lowest, average and highest fee per gram of the transactions stored in the pool. The average is
+    /// weighted by transaction weight.
+    pub fn fee_per_gram_stats(&self) -> FeePerGramStats {
+        if self.txs_by_signature.is_empty() {
+            return FeePerGramStats::default();
+        }
+        let mut min_fee_per_gram = std::f64::MAX;
+        let mut max_fee_per_gram = 0f64;
+        let mut total_fees = 0u64;
+        let mut total_weight = 0u64;
+        for ptx in self.txs_by_signature.values() {
+            let fee_per_gram = ptx.transaction.calculate_ave_fee_per_gram();
+            min_fee_per_gram = min_fee_per_gram.min(fee_per_gram);
+            max_fee_per_gram = max_fee_per_gram.max(fee_per_gram);
+            total_fees += ptx.transaction.body.get_total_fee().0;
+            total_weight += ptx.transaction.calculate_weight();
+        }
+        let avg_fee_per_gram = if total_weight == 0 {
+            0
+        } else {
+            total_fees / total_weight
+        };
+        FeePerGramStats {
+            min_fee_per_gram: MicroTari(min_fee_per_gram as u64),
+            avg_fee_per_gram: MicroTari(avg_fee_per_gram),
+            max_fee_per_gram: MicroTari(max_fee_per_gram as u64),
+        }
+    }
+
     #[cfg(test)]
     /// Returns false if there are any inconsistencies in the internal mempool state, otherwise true
     fn check_status(&self) -> bool {
@@ -481,7 +520,6 @@ mod test {
         transactions::{
             fee::Fee,
             helpers::{TestParams, UtxoTestParams},
-            tari_amount::MicroTari,
             transaction::KernelFeatures,
             types::{CryptoFactories, HashDigest},
             SenderTransactionProtocol,
@@ -550,6 +588,31 @@ mod test {
         assert!(unconfirmed_pool.check_status());
     }
 
+    #[test]
+    fn test_fee_per_gram_stats() {
+        let mut unconfirmed_pool = UnconfirmedPool::new(UnconfirmedPoolConfig::default());
+        assert_eq!(unconfirmed_pool.fee_per_gram_stats(), FeePerGramStats::default());
+
+        let tx1 = Arc::new(tx!(MicroTari(5_000), fee: MicroTari(20), inputs: 2, outputs: 1).0);
+        let tx2 = Arc::new(tx!(MicroTari(5_000), fee: MicroTari(50), inputs: 3, outputs: 1).0);
+        let tx3 = Arc::new(tx!(MicroTari(5_000), fee: MicroTari(100), inputs: 1, outputs: 1).0);
+        unconfirmed_pool
+            .insert_txs(vec![tx1.clone(), tx2.clone(), tx3.clone()])
+            .unwrap();
+
+        let stats = unconfirmed_pool.fee_per_gram_stats();
+        assert_eq!(
+            stats.min_fee_per_gram,
+            MicroTari(tx1.calculate_ave_fee_per_gram() as u64)
+        );
+        assert_eq!(
+            stats.max_fee_per_gram,
+            MicroTari(tx3.calculate_ave_fee_per_gram() as u64)
+        );
+        assert!(stats.min_fee_per_gram <= stats.avg_fee_per_gram);
+        assert!(stats.avg_fee_per_gram <= stats.max_fee_per_gram);
+    }
+
     #[test]
     fn test_double_spend_inputs() {
         let (tx1, _, _) = tx!(MicroTari(5_000), fee: MicroTari(50), inputs: 1, outputs: 1);
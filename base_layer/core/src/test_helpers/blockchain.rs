@@ -45,7 +45,7 @@ use crate::{
     consensus::{chain_strength_comparer::ChainStrengthComparerBuilder, ConsensusConstantsBuilder, ConsensusManager},
     transactions::{
         transaction::{TransactionInput, TransactionKernel, TransactionOutput},
-        types::{CryptoFactories, HashOutput, Signature},
+        types::{Commitment, CryptoFactories, HashOutput, Signature},
     },
     validation::{
         block_validators::{BodyOnlyValidator, OrphanBlockValidator},
@@ -251,6 +251,10 @@ impl BlockchainBackend for TempDatabase {
         self.db.fetch_output(output_hash)
     }
 
+    fn fetch_output_hashes_by_commitment(&self, commitment: &Commitment) -> Result<Vec<HashOutput>, ChainStorageError> {
+        self.db.fetch_output_hashes_by_commitment(commitment)
+    }
+
     fn fetch_outputs_in_block(&self, header_hash: &HashOutput) -> Result<Vec<PrunedOutput>, ChainStorageError> {
         self.db.fetch_outputs_in_block(header_hash)
     }
@@ -271,6 +275,10 @@ impl BlockchainBackend for TempDatabase {
         self.db.orphan_count()
     }
 
+    fn fetch_orphan_headers(&self) -> Result<Vec<BlockHeader>, ChainStorageError> {
+        self.db.fetch_orphan_headers()
+    }
+
     fn fetch_last_header(&self) -> Result<BlockHeader, ChainStorageError> {
         self.db.fetch_last_header()
     }
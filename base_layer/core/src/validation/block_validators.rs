@@ -30,7 +30,13 @@ use crate::{
         types::CryptoFactories,
     },
     validation::{
-        helpers::{check_accounting_balance, check_block_weight, check_coinbase_output, is_all_unique_and_sorted},
+        helpers::{
+            check_accounting_balance,
+            check_block_weight,
+            check_coinbase_output,
+            check_sorting,
+            is_all_unique_and_sorted,
+        },
         traits::PostOrphanBodyValidation,
         CandidateBlockBodyValidation,
         OrphanValidation,
@@ -164,13 +170,16 @@ impl<B: BlockchainBackend> PostOrphanBodyValidation<B> for BodyOnlyValidator {
     }
 }
 
-// This function checks for duplicate inputs and outputs. There should be no duplicate inputs or outputs in a block
+// This function checks that the inputs and outputs are sorted and that there are no duplicate inputs or outputs in a
+// block
 fn check_sorting_and_duplicates(body: &AggregateBody) -> Result<(), ValidationError> {
+    check_sorting(body)?;
+    // Once sorting is known to be correct, any remaining failure is a duplicate
     if !is_all_unique_and_sorted(body.inputs()) {
-        return Err(ValidationError::UnsortedOrDuplicateInput);
+        return Err(ValidationError::DuplicateInput);
     }
     if !is_all_unique_and_sorted(body.outputs()) {
-        return Err(ValidationError::UnsortedOrDuplicateOutput);
+        return Err(ValidationError::DuplicateOutput);
     }
 
     Ok(())
@@ -319,9 +328,12 @@ impl<B: BlockchainBackend> BlockValidator<B> {
     fn check_inputs(&self, block: &Block) -> Result<(), ValidationError> {
         let inputs = block.body.inputs();
         for (i, input) in inputs.iter().enumerate() {
-            // Check for duplicates and/or incorrect sorting
-            if i > 0 && input <= &inputs[i - 1] {
-                return Err(ValidationError::UnsortedOrDuplicateInput);
+            // Check for incorrect sorting and duplicates
+            if i > 0 && input < &inputs[i - 1] {
+                return Err(ValidationError::UnsortedInput);
+            }
+            if i > 0 && input == &inputs[i - 1] {
+                return Err(ValidationError::DuplicateInput);
             }
 
             // Check maturity
@@ -347,8 +359,11 @@ impl<B: BlockchainBackend> BlockValidator<B> {
                 coinbase_output = Some(output);
             }
 
-            if j > 0 && output <= &outputs[j - 1] {
-                return Err(ValidationError::UnsortedOrDuplicateOutput);
+            if j > 0 && output < &outputs[j - 1] {
+                return Err(ValidationError::UnsortedOutput);
+            }
+            if j > 0 && output == &outputs[j - 1] {
+                return Err(ValidationError::DuplicateOutput);
             }
         }
 
@@ -24,8 +24,13 @@ use crate::{
     blocks::{block_header::BlockHeaderValidationError, BlockValidationError},
     chain_storage::ChainStorageError,
     proof_of_work::{monero_rx::MergeMineError, PowError},
-    transactions::{transaction::TransactionError, types::HashOutput},
+    transactions::{
+        tari_amount::MicroTari,
+        transaction::{KernelFeatures, TransactionError},
+        types::HashOutput,
+    },
 };
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -61,16 +66,39 @@ pub enum ValidationError {
     ValidatingGenesis,
     #[error("Previous block hash not found")]
     PreviousHashNotFound,
-    #[error("Duplicate or unsorted input found in block body")]
-    UnsortedOrDuplicateInput,
-    #[error("Duplicate or unsorted output found in block body")]
-    UnsortedOrDuplicateOutput,
+    #[error("Duplicate input found in body")]
+    DuplicateInput,
+    #[error("Duplicate output found in body")]
+    DuplicateOutput,
+    #[error("Unsorted input found in body")]
+    UnsortedInput,
+    #[error("Unsorted output found in body")]
+    UnsortedOutput,
     #[error("Error in merge mine data:{0}")]
     MergeMineError(#[from] MergeMineError),
     #[error("Contains an input with an invalid mined-height in body")]
     InvalidMinedHeight,
     #[error("Maximum transaction weight exceeded")]
     MaxTransactionWeightExceeded,
+    #[error("Transaction contains a kernel with invalid features: {0:?}")]
+    InvalidKernelFeatures(KernelFeatures),
+    #[error("Transaction does not contain any kernels")]
+    NoKernels,
+    #[error("Transaction contains a kernel with an empty excess")]
+    EmptyKernelExcess,
+    #[error("Transaction contains a kernel with an empty excess signature")]
+    EmptyKernelSignature,
+    #[error("Transaction validation exceeded the time budget of {0:?}")]
+    ValidationTimeout(Duration),
+    #[error("Transaction fee per gram of {fee_per_gram} is below the minimum of {min_fee_per_gram}")]
+    FeeTooLow {
+        fee_per_gram: MicroTari,
+        min_fee_per_gram: MicroTari,
+    },
+    #[error("Transaction has a weight of zero")]
+    ZeroWeightTransaction,
+    #[error("Transaction value overflows or exceeds the total emission")]
+    ValueOutOfRange,
     #[error("End of time: {0}")]
     EndOfTimeError(String),
     #[error("Expected block height to be {expected}, but was {block_height}")]
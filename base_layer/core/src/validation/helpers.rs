@@ -38,7 +38,7 @@ use crate::{
         PowAlgorithm,
         PowError,
     },
-    transactions::types::CryptoFactories,
+    transactions::{aggregated_body::AggregateBody, types::CryptoFactories},
     validation::ValidationError,
 };
 use log::*;
@@ -237,6 +237,25 @@ pub fn check_coinbase_output(
         .map_err(ValidationError::from)
 }
 
+/// Checks that the inputs and outputs of the body are sorted. Duplicates are allowed, so that they can be reported
+/// separately.
+pub fn check_sorting(body: &AggregateBody) -> Result<(), ValidationError> {
+    if !is_sorted(body.inputs()) {
+        warn!(target: LOG_TARGET, "Validation failed due to unsorted inputs");
+        return Err(ValidationError::UnsortedInput);
+    }
+    if !is_sorted(body.outputs()) {
+        warn!(target: LOG_TARGET, "Validation failed due to unsorted outputs");
+        return Err(ValidationError::UnsortedOutput);
+    }
+
+    Ok(())
+}
+
+pub fn is_sorted<I: AsRef<[T]>, T: PartialOrd>(items: I) -> bool {
+    items.as_ref().windows(2).all(|pair| pair[0] <= pair[1])
+}
+
 pub fn is_all_unique_and_sorted<I: AsRef<[T]>, T: PartialOrd>(items: I) -> bool {
     let items = items.as_ref();
     if items.is_empty() {
@@ -258,6 +277,35 @@ pub fn is_all_unique_and_sorted<I: AsRef<[T]>, T: PartialOrd>(items: I) -> bool
 mod test {
     use super::*;
 
+    #[cfg(test)]
+    mod is_sorted {
+        use super::*;
+
+        #[test]
+        fn it_returns_true_when_nothing_to_compare() {
+            assert!(is_sorted::<_, usize>(&[]));
+            assert!(is_sorted(&[1]));
+        }
+
+        #[test]
+        fn it_returns_true_when_sorted() {
+            let v = [1, 2, 3, 4, 5];
+            assert!(is_sorted(&v));
+        }
+
+        #[test]
+        fn it_returns_true_when_sorted_with_duplicates() {
+            let v = [1, 2, 3, 4, 4];
+            assert!(is_sorted(&v));
+        }
+
+        #[test]
+        fn it_returns_false_when_unsorted() {
+            let v = [2, 1, 3, 4, 5];
+            assert!(!is_sorted(&v));
+        }
+    }
+
     #[cfg(test)]
     mod is_all_unique_and_sorted {
         use super::*;
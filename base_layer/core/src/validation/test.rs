@@ -24,10 +24,34 @@ use crate::{
     blocks::BlockHeader,
     consensus::ConsensusManagerBuilder,
     test_helpers::{blockchain::create_store_with_consensus, create_chain_header},
-    validation::header_iter::HeaderIter,
+    transactions::{aggregated_body::AggregateBody, tari_amount::uT},
+    tx,
+    validation::{header_iter::HeaderIter, helpers::check_sorting, ValidationError},
 };
 use tari_common::configuration::Network;
 
+#[test]
+fn check_sorting_rejects_unique_but_unsorted_inputs_and_outputs() {
+    let (tx, _, _) = tx!(5000*uT, fee: 50*uT, inputs: 2, outputs: 2);
+    assert!(check_sorting(&tx.body).is_ok());
+
+    let mut inputs = tx.body.inputs().clone();
+    inputs.reverse();
+    let body = AggregateBody::new(inputs, tx.body.outputs().clone(), tx.body.kernels().clone());
+    assert!(matches!(check_sorting(&body), Err(ValidationError::UnsortedInput)));
+
+    let mut outputs = tx.body.outputs().clone();
+    outputs.reverse();
+    let body = AggregateBody::new(tx.body.inputs().clone(), outputs, tx.body.kernels().clone());
+    assert!(matches!(check_sorting(&body), Err(ValidationError::UnsortedOutput)));
+
+    // Duplicates are sorted, so they are left to the duplicate checks
+    let mut inputs = tx.body.inputs().clone();
+    inputs.insert(0, inputs[0].clone());
+    let body = AggregateBody::new(inputs, tx.body.outputs().clone(), tx.body.kernels().clone());
+    assert!(check_sorting(&body).is_ok());
+}
+
 #[test]
 fn header_iter_empty_and_invalid_height() {
     let consensus_manager = ConsensusManagerBuilder::new(Network::LocalNet).build();
@@ -48,6 +48,13 @@ pub trait PostOrphanBodyValidation<B>: Send + Sync {
 
 pub trait MempoolTransactionValidation: Send + Sync {
     fn validate(&self, transaction: &Transaction) -> Result<(), ValidationError>;
+
+    /// Validate a batch of transactions, returning a result for each transaction in the same order. The default
+    /// validates each transaction in turn; validators that read from the database can override this to hold a single
+    /// read lock for the whole batch.
+    fn validate_batch(&self, transactions: &[Transaction]) -> Vec<Result<(), ValidationError>> {
+        transactions.iter().map(|tx| self.validate(tx)).collect()
+    }
 }
 
 pub trait OrphanValidation: Send + Sync {
@@ -21,17 +21,28 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::{
-    chain_storage::{BlockchainBackend, BlockchainDatabase, MmrTree},
+    chain_storage::{BlockchainBackend, BlockchainDatabase, DeletedBitmap, MmrTree},
+    consensus::{ConsensusConstants, ConsensusManager},
     crypto::tari_utilities::Hashable,
-    transactions::{transaction::Transaction, types::CryptoFactories},
-    validation::{MempoolTransactionValidation, ValidationError},
+    transactions::{
+        tari_amount::MicroTari,
+        transaction::{KernelFeatures, Transaction},
+        types::{Commitment, CryptoFactories, Signature},
+    },
+    validation::{helpers::check_sorting, MempoolTransactionValidation, ValidationError},
 };
 use log::*;
+use std::{
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    time::{Duration, Instant},
+};
+use tari_common_types::{chain_metadata::ChainMetadata, types::BlockHash};
 
 pub const LOG_TARGET: &str = "c::val::transaction_validators";
 
 /// This validator will check the internal consistency of the transaction.
 ///
+/// 1. There is at least one kernel and every kernel has a non-empty excess and excess signature
 /// 1. The sum of inputs, outputs and fees equal the (public excess value + offset)
 /// 1. The signature signs the canonical message with the private excess
 /// 1. Range proofs of the outputs are valid
@@ -49,6 +60,7 @@ impl TxInternalConsistencyValidator {
 
 impl MempoolTransactionValidation for TxInternalConsistencyValidator {
     fn validate(&self, tx: &Transaction) -> Result<(), ValidationError> {
+        verify_kernels_not_empty(tx)?;
         tx.validate_internal_consistency(&self.factories, None)
             .map_err(ValidationError::TransactionError)?;
         Ok(())
@@ -58,6 +70,7 @@ impl MempoolTransactionValidation for TxInternalConsistencyValidator {
 /// This validator will check the transaction against the current consensus rules.
 ///
 /// 1. The transaction weight should not exceed the maximum weight for 1 block
+/// 1. Kernel features must be permitted by consensus and may not include the coinbase flag
 #[derive(Clone)]
 pub struct TxConsensusValidator<B> {
     db: BlockchainDatabase<B>,
@@ -76,34 +89,161 @@ impl<B: BlockchainBackend> MempoolTransactionValidation for TxConsensusValidator
         if tx.calculate_weight() > consensus_constants.get_max_block_weight_excluding_coinbase() {
             return Err(ValidationError::MaxTransactionWeightExceeded);
         }
+        verify_kernel_features(tx, consensus_constants)?;
+
+        Ok(())
+    }
+}
+
+/// This validator will reject transactions that pay less than the minimum fee per gram of transaction weight, which
+/// gives the node operator control over spam. Transactions without any weight are always rejected.
+#[derive(Clone)]
+pub struct TxMinFeePerGramValidator {
+    min_fee_per_gram: MicroTari,
+}
+
+impl TxMinFeePerGramValidator {
+    pub fn new(min_fee_per_gram: MicroTari) -> Self {
+        Self { min_fee_per_gram }
+    }
+}
+
+impl MempoolTransactionValidation for TxMinFeePerGramValidator {
+    fn validate(&self, tx: &Transaction) -> Result<(), ValidationError> {
+        let weight = tx.calculate_weight();
+        if weight == 0 {
+            warn!(target: LOG_TARGET, "Transaction validation failed due to zero weight");
+            return Err(ValidationError::ZeroWeightTransaction);
+        }
+        let fee_per_gram = tx.body.get_total_fee() / weight;
+        if fee_per_gram < self.min_fee_per_gram {
+            return Err(ValidationError::FeeTooLow {
+                fee_per_gram,
+                min_fee_per_gram: self.min_fee_per_gram,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// This validator is a cheap sanity check on the values of a transaction that should be run before the expensive
+/// internal consistency check. Output values are hidden in their commitments, so the plain text kernel fees are the
+/// only values that can be checked up front. Their sum may not overflow and may not exceed the total emission at the
+/// current tip: a transaction can never spend more than all the Tari in existence.
+#[derive(Clone)]
+pub struct TxValueRangeValidator<B> {
+    rules: ConsensusManager,
+    db: BlockchainDatabase<B>,
+}
+
+impl<B: BlockchainBackend> TxValueRangeValidator<B> {
+    pub fn new(rules: ConsensusManager, db: BlockchainDatabase<B>) -> Self {
+        Self { rules, db }
+    }
+}
+
+impl<B: BlockchainBackend> MempoolTransactionValidation for TxValueRangeValidator<B> {
+    fn validate(&self, tx: &Transaction) -> Result<(), ValidationError> {
+        let max_value = self.rules.get_total_emission_at(self.db.get_height()?);
+        let mut total = MicroTari::from(0);
+        for kernel in tx.body.kernels() {
+            total = total.checked_add(kernel.fee).ok_or_else(|| {
+                warn!(target: LOG_TARGET, "Transaction validation failed due to overflowing fees");
+                ValidationError::ValueOutOfRange
+            })?;
+        }
+        if total > max_value {
+            warn!(
+                target: LOG_TARGET,
+                "Transaction validation failed due to a total fee of {} exceeding the total emission of {}",
+                total,
+                max_value
+            );
+            return Err(ValidationError::ValueOutOfRange);
+        }
 
         Ok(())
     }
 }
 
 /// This validator assumes that the transaction was already validated and it will skip this step. It will only check, in
-/// order,: All inputs exist in the backend, All timelocks (kernel lock heights and output maturities) have passed
+/// order,: All inputs exist in the backend, All timelocks (kernel lock heights and output maturities) have passed, The
+/// inputs and outputs are sorted and contain no duplicates
+///
+/// The deleted bitmap is cached for the current tip, so that a burst of validations at the same tip only decodes it
+/// once. Clones share the cache.
 #[derive(Clone)]
 pub struct TxInputAndMaturityValidator<B> {
     db: BlockchainDatabase<B>,
+    deleted_bitmap_cache: Arc<RwLock<Option<(BlockHash, Arc<DeletedBitmap>)>>>,
 }
 
 impl<B: BlockchainBackend> TxInputAndMaturityValidator<B> {
     pub fn new(db: BlockchainDatabase<B>) -> Self {
-        Self { db }
+        Self {
+            db,
+            deleted_bitmap_cache: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Returns the deleted bitmap at the given tip, fetching it from the backend only if the tip has changed since it
+    /// was cached
+    fn fetch_deleted_bitmap(&self, db: &B, tip_hash: &BlockHash) -> Result<Arc<DeletedBitmap>, ValidationError> {
+        {
+            let cache = self.deleted_bitmap_cache.read().map_err(|e| {
+                ValidationError::custom_error(format!("Read lock on deleted bitmap cache failed: {}", e))
+            })?;
+            if let Some((hash, deleted)) = cache.as_ref() {
+                if hash == tip_hash {
+                    return Ok(deleted.clone());
+                }
+            }
+        }
+
+        let deleted = Arc::new(db.fetch_deleted_bitmap()?);
+        let mut cache = self
+            .deleted_bitmap_cache
+            .write()
+            .map_err(|e| ValidationError::custom_error(format!("Write lock on deleted bitmap cache failed: {}", e)))?;
+        *cache = Some((tip_hash.clone(), deleted.clone()));
+        Ok(deleted)
+    }
+
+    fn validate_with_db(&self, tx: &Transaction, db: &B, metadata: &ChainMetadata) -> Result<(), ValidationError> {
+        let deleted = self.fetch_deleted_bitmap(db, metadata.best_block())?;
+        verify_not_stxos(tx, db, &deleted)?;
+        check_not_duplicate_txos(tx, db)?;
+        verify_timelocks(tx, metadata.height_of_longest_chain())?;
+        check_sorting(&tx.body)?;
+        verify_no_duplicated_inputs_outputs(tx)?;
+        Ok(())
     }
 }
 
 impl<B: BlockchainBackend> MempoolTransactionValidation for TxInputAndMaturityValidator<B> {
     fn validate(&self, tx: &Transaction) -> Result<(), ValidationError> {
         let db = self.db.db_read_access()?;
-        verify_not_stxos(tx, &*db)?;
-        check_not_duplicate_txos(tx, &*db)?;
+        let metadata = db.fetch_chain_metadata()?;
+        self.validate_with_db(tx, &*db, &metadata)
+    }
 
-        let tip_height = db.fetch_chain_metadata()?.height_of_longest_chain();
-        verify_timelocks(tx, tip_height)?;
-        verify_no_duplicated_inputs_outputs(tx)?;
-        Ok(())
+    fn validate_batch(&self, transactions: &[Transaction]) -> Vec<Result<(), ValidationError>> {
+        // Every transaction is validated against the same view of the chain under a single read lock
+        let db_and_metadata = self.db.db_read_access().and_then(|db| {
+            let metadata = db.fetch_chain_metadata()?;
+            Ok((db, metadata))
+        });
+        match db_and_metadata {
+            Ok((db, metadata)) => transactions
+                .iter()
+                .map(|tx| self.validate_with_db(tx, &*db, &metadata))
+                .collect(),
+            Err(err) => transactions
+                .iter()
+                .map(|_| Err(ValidationError::FatalStorageError(err.to_string())))
+                .collect(),
+        }
     }
 }
 
@@ -116,9 +256,52 @@ fn verify_timelocks(tx: &Transaction, current_height: u64) -> Result<(), Validat
     Ok(())
 }
 
+// This function is a cheap sanity check on the kernels so that obviously malformed transactions are rejected before
+// the expensive range proof verification of the internal consistency check is done.
+fn verify_kernels_not_empty(tx: &Transaction) -> Result<(), ValidationError> {
+    let kernels = tx.body.kernels();
+    if kernels.is_empty() {
+        warn!(target: LOG_TARGET, "Transaction validation failed due to missing kernels");
+        return Err(ValidationError::NoKernels);
+    }
+    for kernel in kernels {
+        if kernel.excess == Commitment::default() {
+            warn!(target: LOG_TARGET, "Transaction validation failed due to empty kernel excess");
+            return Err(ValidationError::EmptyKernelExcess);
+        }
+        if kernel.excess_sig == Signature::default() {
+            warn!(
+                target: LOG_TARGET,
+                "Transaction validation failed due to empty kernel excess signature"
+            );
+            return Err(ValidationError::EmptyKernelSignature);
+        }
+    }
+    Ok(())
+}
+
+// This function checks that every kernel only uses feature flags recognised by consensus and that none of them are
+// coinbase kernels, which are only valid as part of a block.
+fn verify_kernel_features(tx: &Transaction, consensus_constants: &ConsensusConstants) -> Result<(), ValidationError> {
+    let permitted = consensus_constants.permitted_kernel_features();
+    for kernel in tx.body.kernels() {
+        if !permitted.contains(kernel.features) || kernel.features.contains(KernelFeatures::COINBASE_KERNEL) {
+            warn!(
+                target: LOG_TARGET,
+                "Transaction validation failed due to invalid kernel features: {:?}", kernel.features
+            );
+            return Err(ValidationError::InvalidKernelFeatures(kernel.features));
+        }
+    }
+    Ok(())
+}
+
 // This function checks that the inputs exists in the UTXO set but do not exist in the STXO set.
-fn verify_not_stxos<B: BlockchainBackend>(tx: &Transaction, db: &B) -> Result<(), ValidationError> {
-    let deleted = db.fetch_deleted_bitmap()?;
+fn verify_not_stxos<B: BlockchainBackend>(
+    tx: &Transaction,
+    db: &B,
+    deleted: &DeletedBitmap,
+) -> Result<(), ValidationError> {
     let mut not_found_input = Vec::new();
     for input in tx.body.inputs() {
         if let Some((_, index, _height)) = db.fetch_output(&input.output_hash())? {
@@ -167,30 +350,152 @@ fn check_not_duplicate_txos<B: BlockchainBackend>(transaction: &Transaction, db:
 fn verify_no_duplicated_inputs_outputs(tx: &Transaction) -> Result<(), ValidationError> {
     if tx.body.contains_duplicated_inputs() {
         warn!(target: LOG_TARGET, "Transaction validation failed due to double input");
-        return Err(ValidationError::UnsortedOrDuplicateInput);
+        return Err(ValidationError::DuplicateInput);
     }
     if tx.body.contains_duplicated_outputs() {
         warn!(target: LOG_TARGET, "Transaction validation failed due to double output");
-        return Err(ValidationError::UnsortedOrDuplicateOutput);
+        return Err(ValidationError::DuplicateOutput);
     }
     Ok(())
 }
 
+type NamedValidator = (String, Box<dyn MempoolTransactionValidation>);
+
+/// Runs a list of named validators in order. Clones share the same list, so validators can be added or removed at
+/// runtime (e.g. to toggle spam validators) without restarting the node.
+#[derive(Clone)]
 pub struct MempoolValidator {
-    validators: Vec<Box<dyn MempoolTransactionValidation>>,
+    validators: Arc<RwLock<Vec<NamedValidator>>>,
+    timeout: Option<Duration>,
 }
 
 impl MempoolValidator {
-    pub fn new(validators: Vec<Box<dyn MempoolTransactionValidation>>) -> Self {
-        Self { validators }
+    pub fn new(validators: Vec<(&str, Box<dyn MempoolTransactionValidation>)>) -> Self {
+        let validators = validators
+            .into_iter()
+            .map(|(name, validator)| (name.to_string(), validator))
+            .collect();
+        Self {
+            validators: Arc::new(RwLock::new(validators)),
+            timeout: None,
+        }
+    }
+
+    /// The names of the validators, in the order that they are run
+    pub fn validator_names(&self) -> Result<Vec<String>, ValidationError> {
+        Ok(self.read_validators()?.iter().map(|(name, _)| name.clone()).collect())
+    }
+
+    /// Append a validator to the list. If a validator with the same name exists it is replaced in place.
+    pub fn add_validator(
+        &self,
+        name: &str,
+        validator: Box<dyn MempoolTransactionValidation>,
+    ) -> Result<(), ValidationError> {
+        let mut validators = self.write_validators()?;
+        match validators.iter_mut().find(|(n, _)| n == name) {
+            Some(existing) => existing.1 = validator,
+            None => validators.push((name.to_string(), validator)),
+        }
+        Ok(())
+    }
+
+    /// Remove the validator with the given name. Returns false if no such validator exists.
+    pub fn remove_validator(&self, name: &str) -> Result<bool, ValidationError> {
+        let mut validators = self.write_validators()?;
+        let len = validators.len();
+        validators.retain(|(n, _)| n != name);
+        Ok(validators.len() != len)
+    }
+
+    fn read_validators(&self) -> Result<RwLockReadGuard<Vec<NamedValidator>>, ValidationError> {
+        self.validators
+            .read()
+            .map_err(|e| ValidationError::custom_error(format!("Read lock on mempool validators failed: {}", e)))
+    }
+
+    fn write_validators(&self) -> Result<RwLockWriteGuard<Vec<NamedValidator>>, ValidationError> {
+        self.validators
+            .write()
+            .map_err(|e| ValidationError::custom_error(format!("Write lock on mempool validators failed: {}", e)))
+    }
+
+    /// Limit the cumulative time spent validating a single transaction. The inner validators are synchronous, so the
+    /// budget is checked before each validator is called and validation is aborted once it has been exceeded.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
     }
 }
 
 impl MempoolTransactionValidation for MempoolValidator {
     fn validate(&self, transaction: &Transaction) -> Result<(), ValidationError> {
-        for v in &self.validators {
+        let timer = Instant::now();
+        for (_, v) in self.read_validators()?.iter() {
+            if let Some(timeout) = self.timeout {
+                if timer.elapsed() > timeout {
+                    warn!(
+                        target: LOG_TARGET,
+                        "Transaction validation aborted after {:.2?}, exceeding the budget of {:.2?}",
+                        timer.elapsed(),
+                        timeout
+                    );
+                    return Err(ValidationError::ValidationTimeout(timeout));
+                }
+            }
             v.validate(transaction)?;
         }
         Ok(())
     }
+
+    fn validate_batch(&self, transactions: &[Transaction]) -> Vec<Result<(), ValidationError>> {
+        let validators = match self.read_validators() {
+            Ok(validators) => validators,
+            Err(err) => {
+                let err = err.to_string();
+                return transactions
+                    .iter()
+                    .map(|_| Err(ValidationError::custom_error(&err)))
+                    .collect();
+            },
+        };
+        let timer = Instant::now();
+        let mut results = transactions.iter().map(|_| Ok(())).collect::<Vec<_>>();
+        for (_, v) in validators.iter() {
+            // Each validator only receives the transactions that passed all the previous validators
+            let pending = (0..transactions.len())
+                .filter(|&i| results[i].is_ok())
+                .collect::<Vec<_>>();
+            if pending.is_empty() {
+                break;
+            }
+            if let Some(timeout) = self.timeout {
+                // The budget is per transaction, so the batch as a whole gets the budget of all its transactions
+                if timer.elapsed() > timeout * transactions.len() as u32 {
+                    warn!(
+                        target: LOG_TARGET,
+                        "Batch validation of {} transactions aborted after {:.2?}, exceeding the budget of {:.2?} per \
+                         transaction",
+                        transactions.len(),
+                        timer.elapsed(),
+                        timeout
+                    );
+                    for i in pending {
+                        results[i] = Err(ValidationError::ValidationTimeout(timeout));
+                    }
+                    break;
+                }
+            }
+            let batch_results = if pending.len() == transactions.len() {
+                v.validate_batch(transactions)
+            } else {
+                let batch = pending.iter().map(|&i| transactions[i].clone()).collect::<Vec<_>>();
+                v.validate_batch(&batch)
+            };
+            for (i, result) in pending.into_iter().zip(batch_results) {
+                results[i] = result;
+            }
+        }
+        results
+    }
 }
@@ -33,16 +33,19 @@ use std::ops::Deref;
 use tari_common::configuration::Network;
 use tari_core::{
     blocks::Block,
-    chain_storage::{async_db::AsyncBlockchainDb, BlockAddResult},
+    chain_storage::{async_db::AsyncBlockchainDb, BlockAddResult, UtxoStatus},
     transactions::{
         helpers::schema_to_transaction,
         tari_amount::T,
         transaction::{TransactionOutput, UnblindedOutput},
-        types::{CommitmentFactory, CryptoFactories},
+        types::{Commitment, CommitmentFactory, CryptoFactories},
     },
     txn_schema,
 };
-use tari_crypto::{commitment::HomomorphicCommitmentFactory, tari_utilities::Hashable};
+use tari_crypto::{
+    commitment::HomomorphicCommitmentFactory,
+    tari_utilities::{ByteArray, Hashable},
+};
 use tari_test_utils::runtime::test_async;
 
 /// Finds the UTXO in a block corresponding to the unblinded output. We have to search for outputs because UTXOs get
@@ -112,6 +115,26 @@ fn fetch_async_utxo() {
     });
 }
 
+#[test]
+fn fetch_async_utxo_status() {
+    let (adb, blocks, outputs, _) = create_blockchain_db_no_cut_through();
+    let factory = CommitmentFactory::default();
+    let utxo = find_utxo(&outputs[4][0], blocks[4].block(), &factory).unwrap();
+    let stxo = find_utxo(&outputs[1][0], blocks[1].block(), &factory).unwrap();
+    test_async(move |rt| {
+        let db = AsyncBlockchainDb::new(adb);
+        rt.spawn(async move {
+            let status = db.fetch_utxo_status(utxo.commitment).await.unwrap();
+            assert_eq!(status, UtxoStatus::Unspent);
+            let status = db.fetch_utxo_status(stxo.commitment).await.unwrap();
+            assert_eq!(status, UtxoStatus::Spent);
+            let unknown = Commitment::from_bytes(&[0u8; 32]).unwrap();
+            let status = db.fetch_utxo_status(unknown).await.unwrap();
+            assert_eq!(status, UtxoStatus::Unknown);
+        });
+    });
+}
+
 #[test]
 fn fetch_async_block() {
     let (db, blocks, _, _) = create_blockchain_db_no_cut_through();
@@ -176,3 +199,20 @@ fn async_add_block_fetch_orphan() {
         });
     });
 }
+
+#[test]
+fn async_fetch_orphan_headers() {
+    let (db, _, _, consensus) = create_blockchain_db_no_cut_through();
+
+    let orphan1 = create_orphan_block(9, vec![], &consensus);
+    let orphan2 = create_orphan_block(7, vec![], &consensus);
+    test_async(move |rt| {
+        let db = AsyncBlockchainDb::new(db);
+        rt.spawn(async move {
+            db.add_block(orphan1.clone().into()).await.unwrap();
+            db.add_block(orphan2.clone().into()).await.unwrap();
+            let headers = db.fetch_orphan_headers().await.unwrap();
+            assert_eq!(headers, vec![orphan2.header, orphan1.header]);
+        });
+    });
+}
@@ -50,6 +50,7 @@ use tari_core::{
         BlockchainDatabaseConfig,
         ChainStorageError,
         DbTransaction,
+        UtxoStatus,
         Validators,
     },
     consensus::{emission::Emission, ConsensusConstantsBuilder, ConsensusManagerBuilder},
@@ -61,7 +62,7 @@ use tari_core::{
         create_test_db,
     },
     transactions::{
-        helpers::{schema_to_transaction, spend_utxos},
+        helpers::{create_transaction_with, schema_to_transaction, spend_utxos, TestParams, UtxoTestParams},
         tari_amount::{uT, MicroTari, T},
         types::CryptoFactories,
     },
@@ -237,6 +238,45 @@ fn rewind_to_height() {
     assert_eq!(db.get_height().unwrap(), 1);
 }
 
+#[test]
+#[allow(clippy::identity_op)]
+fn rewind_restores_utxo_status() {
+    let network = Network::LocalNet;
+    let (mut db, mut blocks, mut outputs, consensus_manager) = create_new_blockchain(network);
+    let factories = CryptoFactories::default();
+
+    // Block 1
+    let schema = vec![txn_schema!(from: vec![outputs[0][0].clone()], to: vec![6 * T, 3 * T])];
+    generate_new_block(&mut db, &mut blocks, &mut outputs, schema, &consensus_manager).unwrap();
+    let reused = outputs[1][1].clone();
+    let commitment = reused.as_transaction_output(&factories).unwrap().commitment;
+    assert_eq!(db.fetch_utxo_status(commitment.clone()).unwrap(), UtxoStatus::Unspent);
+    // Block 2 spends the output
+    let schema = vec![txn_schema!(from: vec![reused.clone()], to: vec![1 * T])];
+    generate_new_block(&mut db, &mut blocks, &mut outputs, schema, &consensus_manager).unwrap();
+    assert_eq!(db.fetch_utxo_status(commitment.clone()).unwrap(), UtxoStatus::Spent);
+    // Block 3 creates a different output with the same commitment
+    let mut test_params = TestParams::new();
+    test_params.spend_key = reused.spending_key.clone();
+    let output = test_params.create_unblinded_output(UtxoTestParams {
+        value: reused.value,
+        ..Default::default()
+    });
+    let tx = create_transaction_with(0, 5 * uT, vec![outputs[1][0].clone()], vec![(
+        output,
+        test_params.sender_offset_private_key.clone(),
+    )]);
+    append_block(&db, &blocks[2], vec![tx], &consensus_manager, 1.into()).unwrap();
+    assert_eq!(db.fetch_utxo_status(commitment.clone()).unwrap(), UtxoStatus::Unspent);
+
+    db.rewind_to_height(2).unwrap();
+    assert_eq!(db.fetch_utxo_status(commitment.clone()).unwrap(), UtxoStatus::Spent);
+    db.rewind_to_height(1).unwrap();
+    assert_eq!(db.fetch_utxo_status(commitment.clone()).unwrap(), UtxoStatus::Unspent);
+    db.rewind_to_height(0).unwrap();
+    assert_eq!(db.fetch_utxo_status(commitment).unwrap(), UtxoStatus::Unknown);
+}
+
 #[test]
 #[ignore = "To be completed with pruned mode"]
 fn rewind_past_horizon_height() {
@@ -54,13 +54,24 @@ use tari_core::{
         fee::Fee,
         helpers::{create_unblinded_output, schema_to_transaction, spend_utxos, TestParams},
         tari_amount::{uT, MicroTari, T},
-        transaction::{KernelBuilder, OutputFeatures, Transaction, TransactionOutput},
+        transaction::{KernelBuilder, KernelFeatures, OutputFeatures, Transaction, TransactionOutput},
         transaction_protocol::{build_challenge, TransactionMetadata},
         types::{Commitment, CryptoFactories, PrivateKey, PublicKey, Signature},
     },
     tx,
     txn_schema,
-    validation::transaction_validators::{TxConsensusValidator, TxInputAndMaturityValidator},
+    validation::{
+        transaction_validators::{
+            MempoolValidator,
+            TxConsensusValidator,
+            TxInputAndMaturityValidator,
+            TxInternalConsistencyValidator,
+            TxMinFeePerGramValidator,
+            TxValueRangeValidator,
+        },
+        MempoolTransactionValidation,
+        ValidationError,
+    },
 };
 use tari_crypto::script;
 use tari_p2p::{services::liveness::LivenessConfig, tari_message::TariMessageType};
@@ -1042,6 +1053,303 @@ fn consensus_validation_large_tx() {
     assert!(matches!(response, TxStorageResponse::NotStored));
 }
 
+#[test]
+fn consensus_validation_kernel_features() {
+    let network = Network::LocalNet;
+    let (store, _, _, _) = create_new_blockchain(network);
+    let mempool_validator = TxConsensusValidator::new(store);
+
+    let (tx, _, _) = tx!(1*T, fee: 100*uT);
+    assert!(mempool_validator.validate(&tx).is_ok());
+
+    // A coinbase kernel may only be included in a block
+    let mut kernels = tx.body.kernels().clone();
+    kernels[0].features = KernelFeatures::COINBASE_KERNEL;
+    let tx = Transaction::new(
+        tx.body.inputs().clone(),
+        tx.body.outputs().clone(),
+        kernels,
+        tx.offset.clone(),
+        tx.script_offset.clone(),
+    );
+    match mempool_validator.validate(&tx) {
+        Err(ValidationError::InvalidKernelFeatures(features)) => assert_eq!(features, KernelFeatures::COINBASE_KERNEL),
+        other => panic!("Unexpected validation result: {:?}", other),
+    }
+}
+
+#[test]
+fn internal_consistency_validation_empty_kernels() {
+    let mempool_validator = TxInternalConsistencyValidator::new(CryptoFactories::default());
+    let (tx, _, _) = tx!(1*T, fee: 100*uT);
+    assert!(mempool_validator.validate(&tx).is_ok());
+
+    // These are rejected by the kernel pre-check, before any range proofs are verified
+    let no_kernels = Transaction::new(
+        tx.body.inputs().clone(),
+        tx.body.outputs().clone(),
+        Vec::new(),
+        tx.offset.clone(),
+        tx.script_offset.clone(),
+    );
+    assert!(matches!(
+        mempool_validator.validate(&no_kernels),
+        Err(ValidationError::NoKernels)
+    ));
+
+    let mut kernels = tx.body.kernels().clone();
+    kernels[0].excess_sig = Signature::default();
+    let empty_signature = Transaction::new(
+        tx.body.inputs().clone(),
+        tx.body.outputs().clone(),
+        kernels,
+        tx.offset.clone(),
+        tx.script_offset.clone(),
+    );
+    assert!(matches!(
+        mempool_validator.validate(&empty_signature),
+        Err(ValidationError::EmptyKernelSignature)
+    ));
+}
+
+#[test]
+fn min_fee_per_gram_validation() {
+    let (tx, _, _) = tx!(1*T, fee: 100*uT);
+    let fee_per_gram = tx.body.get_total_fee() / tx.calculate_weight();
+
+    // A transaction paying exactly the minimum fee per gram is accepted
+    let mempool_validator = TxMinFeePerGramValidator::new(fee_per_gram);
+    assert!(mempool_validator.validate(&tx).is_ok());
+
+    let mempool_validator = TxMinFeePerGramValidator::new(fee_per_gram + uT);
+    assert!(matches!(
+        mempool_validator.validate(&tx),
+        Err(ValidationError::FeeTooLow { .. })
+    ));
+
+    let empty_tx = Transaction::new(
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        tx.offset.clone(),
+        tx.script_offset.clone(),
+    );
+    let mempool_validator = TxMinFeePerGramValidator::new(MicroTari::from(0));
+    assert!(matches!(
+        mempool_validator.validate(&empty_tx),
+        Err(ValidationError::ZeroWeightTransaction)
+    ));
+}
+
+#[test]
+fn value_range_validation() {
+    let (mut store, mut blocks, mut outputs, consensus_manager) = create_new_blockchain(Network::LocalNet);
+    let mempool_validator = TxValueRangeValidator::new(consensus_manager.clone(), store.clone());
+    let (tx, _, _) = tx!(1*T, fee: 100*uT);
+    assert!(mempool_validator.validate(&tx).is_ok());
+
+    let with_fee = |fee: MicroTari| {
+        let mut kernels = tx.body.kernels().clone();
+        kernels[0].fee = fee;
+        Transaction::new(
+            tx.body.inputs().clone(),
+            tx.body.outputs().clone(),
+            kernels,
+            tx.offset.clone(),
+            tx.script_offset.clone(),
+        )
+    };
+    let total_emission = consensus_manager.get_total_emission_at(0);
+    assert!(mempool_validator.validate(&with_fee(total_emission)).is_ok());
+    let excessive_tx = with_fee(total_emission + uT);
+    assert!(matches!(
+        mempool_validator.validate(&excessive_tx),
+        Err(ValidationError::ValueOutOfRange)
+    ));
+
+    // The total emission is read at the current tip, so it grows as blocks are added
+    generate_new_block(&mut store, &mut blocks, &mut outputs, vec![], &consensus_manager).unwrap();
+    assert!(mempool_validator.validate(&excessive_tx).is_ok());
+
+    let mut kernels = tx.body.kernels().clone();
+    let mut overflowing_kernel = kernels[0].clone();
+    overflowing_kernel.fee = MicroTari::from(u64::MAX);
+    kernels.push(overflowing_kernel);
+    let overflowing_tx = Transaction::new(
+        tx.body.inputs().clone(),
+        tx.body.outputs().clone(),
+        kernels,
+        tx.offset.clone(),
+        tx.script_offset.clone(),
+    );
+    assert!(matches!(
+        mempool_validator.validate(&overflowing_tx),
+        Err(ValidationError::ValueOutOfRange)
+    ));
+}
+
+#[test]
+#[allow(clippy::identity_op)]
+fn input_and_maturity_batch_validation() {
+    let network = Network::LocalNet;
+    let (mut store, mut blocks, mut outputs, consensus_manager) = create_new_blockchain(network);
+    let mempool_validator = TxInputAndMaturityValidator::new(store.clone());
+    let txs = vec![txn_schema!(
+        from: vec![outputs[0][0].clone()],
+        to: vec![2 * T, 2 * T],fee: 25.into(), lock: 0, features: OutputFeatures::default()
+    )];
+    generate_new_block(&mut store, &mut blocks, &mut outputs, txs, &consensus_manager).unwrap();
+
+    let (orphan, _, _) = tx!(1*T, fee: 100*uT);
+    let tx_valid = txn_schema!(from: vec![outputs[1][0].clone()], to: vec![1*T], fee: 20*uT, lock: 0, features: OutputFeatures::default());
+    let tx_valid = spend_utxos(tx_valid).0;
+    let tx_locked = txn_schema!(from: vec![outputs[1][1].clone()], to: vec![1*T], fee: 20*uT, lock: 10, features: OutputFeatures::default());
+    let tx_locked = spend_utxos(tx_locked).0;
+
+    let batch = vec![orphan, tx_valid, tx_locked];
+    let results = mempool_validator.validate_batch(&batch);
+    assert_eq!(results.len(), batch.len());
+    assert!(matches!(results[0], Err(ValidationError::UnknownInputs(_))));
+    assert!(results[1].is_ok());
+    assert!(matches!(results[2], Err(ValidationError::MaturityError)));
+
+    // The batch gives the same results as validating each transaction on its own
+    for (tx, result) in batch.iter().zip(results) {
+        assert_eq!(mempool_validator.validate(tx).is_ok(), result.is_ok());
+    }
+    assert!(mempool_validator.validate_batch(&[]).is_empty());
+}
+
+#[test]
+#[allow(clippy::identity_op)]
+fn input_and_maturity_deleted_bitmap_cache_invalidates_on_new_tip() {
+    let network = Network::LocalNet;
+    let (mut store, mut blocks, mut outputs, consensus_manager) = create_new_blockchain(network);
+    let mempool_validator = TxInputAndMaturityValidator::new(store.clone());
+    let txs = vec![txn_schema!(
+        from: vec![outputs[0][0].clone()],
+        to: vec![2 * T, 2 * T],fee: 25.into(), lock: 0, features: OutputFeatures::default()
+    )];
+    generate_new_block(&mut store, &mut blocks, &mut outputs, txs, &consensus_manager).unwrap();
+
+    let schema = txn_schema!(from: vec![outputs[1][0].clone()], to: vec![1*T], fee: 20*uT, lock: 0, features: OutputFeatures::default());
+    let tx = spend_utxos(schema.clone()).0;
+    // The deleted bitmap is cached for the current tip
+    assert!(mempool_validator.validate(&tx).is_ok());
+    assert!(mempool_validator.validate(&tx).is_ok());
+
+    // Spending the same output in a new block changes the tip, so the cached bitmap must not be reused
+    generate_new_block(&mut store, &mut blocks, &mut outputs, vec![schema], &consensus_manager).unwrap();
+    assert!(matches!(
+        mempool_validator.validate(&tx),
+        Err(ValidationError::ContainsSTxO)
+    ));
+    // Clones share the cache and see the new tip as well
+    assert!(matches!(
+        mempool_validator.clone().validate(&tx),
+        Err(ValidationError::ContainsSTxO)
+    ));
+}
+
+struct SlowValidator(Duration);
+
+impl MempoolTransactionValidation for SlowValidator {
+    fn validate(&self, _transaction: &Transaction) -> Result<(), ValidationError> {
+        std::thread::sleep(self.0);
+        Ok(())
+    }
+}
+
+#[test]
+fn mempool_validator_timeout() {
+    let (tx, _, _) = tx!(1*T, fee: 100*uT);
+
+    let mempool_validator = MempoolValidator::new(vec![
+        ("slow_1", Box::new(SlowValidator(Duration::from_millis(50)))),
+        ("slow_2", Box::new(SlowValidator(Duration::from_millis(50)))),
+    ]);
+    assert!(mempool_validator.validate(&tx).is_ok());
+
+    let mempool_validator = mempool_validator.with_timeout(Duration::from_millis(10));
+    assert!(matches!(
+        mempool_validator.validate(&tx),
+        Err(ValidationError::ValidationTimeout(_))
+    ));
+}
+
+/// Records the size of every batch it receives and rejects single transaction validation, so that a test can tell
+/// that batches are forwarded
+#[derive(Clone, Default)]
+struct BatchRecorder(Arc<std::sync::Mutex<Vec<usize>>>);
+
+impl MempoolTransactionValidation for BatchRecorder {
+    fn validate(&self, _transaction: &Transaction) -> Result<(), ValidationError> {
+        Err(ValidationError::custom_error("Expected batch validation"))
+    }
+
+    fn validate_batch(&self, transactions: &[Transaction]) -> Vec<Result<(), ValidationError>> {
+        self.0.lock().unwrap().push(transactions.len());
+        transactions.iter().map(|_| Ok(())).collect()
+    }
+}
+
+#[test]
+fn mempool_validator_batch_validation() {
+    let (tx_high_fee, _, _) = tx!(1*T, fee: 100*uT);
+    let (tx_low_fee, _, _) = tx!(1*T, fee: 20*uT);
+    let fee_per_gram = tx_high_fee.body.get_total_fee() / tx_high_fee.calculate_weight();
+
+    let recorder = BatchRecorder::default();
+    let mempool_validator = MempoolValidator::new(vec![
+        (
+            "min_fee_per_gram",
+            Box::new(TxMinFeePerGramValidator::new(fee_per_gram)),
+        ),
+        ("recorder", Box::new(recorder.clone())),
+    ]);
+
+    let batch = vec![tx_high_fee.clone(), tx_low_fee, tx_high_fee];
+    let results = mempool_validator.validate_batch(&batch);
+    assert_eq!(results.len(), batch.len());
+    assert!(results[0].is_ok());
+    assert!(matches!(results[1], Err(ValidationError::FeeTooLow { .. })));
+    assert!(results[2].is_ok());
+    // The rejected transaction is not passed on to the next validator
+    assert_eq!(*recorder.0.lock().unwrap(), vec![2]);
+    assert!(mempool_validator.validate_batch(&[]).is_empty());
+}
+
+#[test]
+fn mempool_validator_runtime_reconfiguration() {
+    let (tx, _, _) = tx!(1*T, fee: 100*uT);
+    let fee_per_gram = tx.body.get_total_fee() / tx.calculate_weight();
+
+    let mempool_validator = MempoolValidator::new(vec![(
+        "internal_consistency",
+        Box::new(TxInternalConsistencyValidator::new(CryptoFactories::default())),
+    )]);
+    // Clones share the validator list, as the mempool would hold its own clone
+    let handle = mempool_validator.clone();
+    handle
+        .add_validator(
+            "min_fee_per_gram",
+            Box::new(TxMinFeePerGramValidator::new(fee_per_gram + uT)),
+        )
+        .unwrap();
+    assert_eq!(mempool_validator.validator_names().unwrap(), vec![
+        "internal_consistency".to_string(),
+        "min_fee_per_gram".to_string()
+    ]);
+    assert!(matches!(
+        mempool_validator.validate(&tx),
+        Err(ValidationError::FeeTooLow { .. })
+    ));
+
+    assert!(handle.remove_validator("min_fee_per_gram").unwrap());
+    assert!(!handle.remove_validator("min_fee_per_gram").unwrap());
+    assert!(mempool_validator.validate(&tx).is_ok());
+}
+
 #[test]
 fn service_request_timeout() {
     let mut runtime = Runtime::new().unwrap();
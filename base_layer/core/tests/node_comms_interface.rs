@@ -23,24 +23,49 @@
 #[allow(dead_code)]
 mod helpers;
 
+use croaring::Bitmap;
 use futures::{channel::mpsc, StreamExt};
-use helpers::block_builders::append_block;
-use std::sync::Arc;
+use helpers::block_builders::{append_block, chain_block, find_header_with_achieved_difficulty};
+use std::{sync::Arc, time::Duration};
 use tari_common::configuration::Network;
 use tari_common_types::chain_metadata::ChainMetadata;
 use tari_comms::peer_manager::NodeId;
 use tari_core::{
     base_node::{
-        comms_interface::{CommsInterfaceError, InboundNodeCommsHandlers, NodeCommsRequest, NodeCommsResponse},
+        comms_interface::{
+            BlockEvent,
+            CommsInterfaceError,
+            GetNewBlockTemplateRequest,
+            InboundNodeCommsHandlers,
+            NodeCommsRequest,
+            NodeCommsResponse,
+            RequestCounters,
+        },
         OutboundNodeCommsInterface,
     },
     blocks::{BlockBuilder, BlockHeader},
-    chain_storage::{BlockchainDatabaseConfig, DbTransaction, HistoricalBlock, Validators},
+    chain_storage::{BlockAddResult, BlockchainDatabaseConfig, DbTransaction, HistoricalBlock, Validators},
     consensus::{ConsensusManager, NetworkConsensus},
     mempool::{Mempool, MempoolConfig},
-    test_helpers::blockchain::{create_store_with_consensus_and_validators_and_config, create_test_blockchain_db},
-    transactions::{helpers::create_utxo, tari_amount::MicroTari, types::CryptoFactories},
-    validation::{mocks::MockValidator, transaction_validators::TxInputAndMaturityValidator},
+    proof_of_work::PowAlgorithm,
+    test_helpers::blockchain::{
+        create_store_with_consensus_and_validators,
+        create_store_with_consensus_and_validators_and_config,
+        create_test_blockchain_db,
+    },
+    transactions::{
+        helpers::create_utxo,
+        tari_amount::{uT, MicroTari, T},
+        transaction::Transaction,
+        types::CryptoFactories,
+    },
+    tx,
+    validation::{
+        mocks::MockValidator,
+        transaction_validators::TxInputAndMaturityValidator,
+        MempoolTransactionValidation,
+        ValidationError,
+    },
 };
 use tari_crypto::{script::TariScript, tari_utilities::hash::Hashable};
 use tari_service_framework::{reply_channel, reply_channel::Receiver};
@@ -106,6 +131,57 @@ async fn inbound_get_metadata() {
     }
 }
 
+#[tokio_macros::test]
+async fn inbound_request_counters() {
+    let store = create_test_blockchain_db();
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender.clone(),
+        store.clone().into(),
+        new_mempool(),
+        consensus_manager.clone(),
+        outbound_nci.clone(),
+    );
+    inbound_nch
+        .handle_request(NodeCommsRequest::GetChainMetadata)
+        .await
+        .unwrap();
+    assert!(inbound_nch.request_counts().is_none());
+
+    let request_counters = Arc::new(RequestCounters::new());
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.into(),
+        new_mempool(),
+        consensus_manager,
+        outbound_nci,
+    )
+    .with_request_counters(request_counters.clone());
+    inbound_nch
+        .handle_request(NodeCommsRequest::GetChainMetadata)
+        .await
+        .unwrap();
+    inbound_nch
+        .handle_request(NodeCommsRequest::GetChainMetadata)
+        .await
+        .unwrap();
+    inbound_nch
+        .handle_request(NodeCommsRequest::FetchHeaders(vec![0]))
+        .await
+        .unwrap();
+
+    let counts = inbound_nch.request_counts().unwrap();
+    assert_eq!(counts.len(), 2);
+    assert_eq!(counts["GetChainMetadata"], 2);
+    assert_eq!(counts["FetchHeaders"], 1);
+    assert_eq!(request_counters.snapshot(), counts);
+}
+
 #[tokio_macros::test]
 async fn inbound_fetch_kernel_by_excess_sig() {
     let store = create_test_blockchain_db();
@@ -359,6 +435,655 @@ async fn inbound_fetch_blocks() {
     }
 }
 
+#[tokio_macros::test]
+async fn inbound_block_exists() {
+    let store = create_test_blockchain_db();
+    let mempool = new_mempool();
+    let (block_event_sender, _) = broadcast::channel(50);
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.clone().into(),
+        mempool,
+        consensus_manager,
+        outbound_nci,
+    );
+    let block_hash = store.fetch_block(0).unwrap().block().hash();
+
+    if let Ok(NodeCommsResponse::BlockExists(exists)) = inbound_nch
+        .handle_request(NodeCommsRequest::BlockExists(block_hash))
+        .await
+    {
+        assert!(exists);
+    } else {
+        panic!();
+    }
+
+    if let Ok(NodeCommsResponse::BlockExists(exists)) = inbound_nch
+        .handle_request(NodeCommsRequest::BlockExists(vec![0u8; 32]))
+        .await
+    {
+        assert!(!exists);
+    } else {
+        panic!();
+    }
+}
+
+#[tokio_macros::test]
+async fn inbound_fetch_block_bodies() {
+    let store = create_test_blockchain_db();
+    let mempool = new_mempool();
+    let (block_event_sender, _) = broadcast::channel(50);
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.clone().into(),
+        mempool,
+        consensus_manager,
+        outbound_nci,
+    );
+    let block = store.fetch_block(0).unwrap().block().clone();
+
+    // Height 5 does not exist and is omitted
+    if let Ok(NodeCommsResponse::BlockBodies(bodies)) = inbound_nch
+        .handle_request(NodeCommsRequest::FetchBlockBodies(vec![0, 5]))
+        .await
+    {
+        assert_eq!(bodies.len(), 1);
+        assert_eq!(bodies[0].0, 0);
+        assert_eq!(bodies[0].1, block.body);
+    } else {
+        panic!();
+    }
+
+    // Requests for too many bodies are rejected outright
+    assert!(matches!(
+        inbound_nch
+            .handle_request(NodeCommsRequest::FetchBlockBodies(vec![0; 1_000]))
+            .await,
+        Err(CommsInterfaceError::RequestTooLarge(_))
+    ));
+}
+
+#[tokio_macros::test]
+async fn inbound_fetch_blocks_in_range() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let block0 = consensus_manager.get_genesis_block();
+    let validators = Validators::new(
+        MockValidator::new(true),
+        MockValidator::new(true),
+        MockValidator::new(true),
+    );
+    let store = create_store_with_consensus_and_validators(consensus_manager.clone(), validators);
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.clone().into(),
+        new_mempool(),
+        consensus_manager.clone(),
+        outbound_nci,
+    );
+
+    let block1 = append_block(&store, &block0, vec![], &consensus_manager, 1.into()).unwrap();
+    let block2 = append_block(&store, &block1, vec![], &consensus_manager, 1.into()).unwrap();
+    let _block3 = append_block(&store, &block2, vec![], &consensus_manager, 1.into()).unwrap();
+
+    if let Ok(NodeCommsResponse::HistoricalBlocks(received_blocks)) = inbound_nch
+        .handle_request(NodeCommsRequest::FetchBlocksInRange { start: 1, end: 2 })
+        .await
+    {
+        assert_eq!(received_blocks.len(), 2);
+        assert_eq!(received_blocks[0].block(), block1.block());
+        assert_eq!(received_blocks[1].block(), block2.block());
+    } else {
+        panic!();
+    }
+
+    // The end of the range is clamped to the tip
+    if let Ok(NodeCommsResponse::HistoricalBlocks(received_blocks)) = inbound_nch
+        .handle_request(NodeCommsRequest::FetchBlocksInRange { start: 2, end: 100 })
+        .await
+    {
+        assert_eq!(received_blocks.len(), 2);
+        assert_eq!(received_blocks[0].block(), block2.block());
+    } else {
+        panic!();
+    }
+
+    if let Ok(NodeCommsResponse::HistoricalBlocks(received_blocks)) = inbound_nch
+        .handle_request(NodeCommsRequest::FetchBlocksInRange { start: 10, end: 20 })
+        .await
+    {
+        assert!(received_blocks.is_empty());
+    } else {
+        panic!();
+    }
+
+    // Ranges spanning too many blocks are rejected outright
+    assert!(matches!(
+        inbound_nch
+            .handle_request(NodeCommsRequest::FetchBlocksInRange { start: 0, end: 1_000 })
+            .await,
+        Err(CommsInterfaceError::RequestTooLarge(_))
+    ));
+}
+
+#[tokio_macros::test]
+async fn inbound_handle_block_rejects_deep_reorg() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let block0 = consensus_manager.get_genesis_block();
+    let validators = Validators::new(
+        MockValidator::new(true),
+        MockValidator::new(true),
+        MockValidator::new(true),
+    );
+    let store = create_store_with_consensus_and_validators(consensus_manager.clone(), validators);
+    let (block_event_sender, mut block_event_receiver) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.clone().into(),
+        new_mempool(),
+        consensus_manager.clone(),
+        outbound_nci,
+    )
+    .with_max_reorg_depth(1);
+
+    let block1 = append_block(&store, &block0, vec![], &consensus_manager, 1.into()).unwrap();
+    let block2 = append_block(&store, &block1, vec![], &consensus_manager, 1.into()).unwrap();
+    let _block3 = append_block(&store, &block2, vec![], &consensus_manager, 1.into()).unwrap();
+
+    // A weaker fork does not trigger a reorg, so it is not subject to the reorg depth limit
+    let weak_fork_block = Arc::new(BlockHeader::from_previous(block0.header()).into_builder().build());
+    let (_, block_add_result) = inbound_nch
+        .handle_block(weak_fork_block, false.into(), None)
+        .await
+        .unwrap();
+    assert_eq!(block_add_result, BlockAddResult::OrphanBlock);
+    assert!(!matches!(
+        &*block_event_receiver.recv().await.unwrap(),
+        BlockEvent::ReorgRejectedTooDeep { .. }
+    ));
+
+    // A stronger block forking off the genesis block would remove 3 blocks from the main chain
+    let mut fork_block = BlockHeader::from_previous(block0.header()).into_builder().build();
+    find_header_with_achieved_difficulty(&mut fork_block.header, 10.into());
+    let fork_block = Arc::new(fork_block);
+    let (block_hash, block_add_result) = inbound_nch
+        .handle_block(fork_block.clone(), false.into(), None)
+        .await
+        .unwrap();
+    assert_eq!(block_hash, fork_block.hash());
+    assert_eq!(block_add_result, BlockAddResult::OrphanBlock);
+
+    if let BlockEvent::ReorgRejectedTooDeep { depth } = &*block_event_receiver.recv().await.unwrap() {
+        assert_eq!(*depth, 3);
+    } else {
+        panic!();
+    }
+    assert_eq!(store.fetch_orphan(fork_block.hash()).unwrap(), *fork_block);
+    assert_eq!(store.get_chain_metadata().unwrap().height_of_longest_chain(), 3);
+}
+
+#[tokio_macros::test]
+async fn inbound_handle_block_accepts_stronger_reorg_within_limit() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let block0 = consensus_manager.get_genesis_block();
+    let validators = Validators::new(
+        MockValidator::new(true),
+        MockValidator::new(true),
+        MockValidator::new(true),
+    );
+    let store = create_store_with_consensus_and_validators(consensus_manager.clone(), validators);
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.clone().into(),
+        new_mempool(),
+        consensus_manager.clone(),
+        outbound_nci,
+    )
+    .with_max_reorg_depth(1);
+
+    let block1 = append_block(&store, &block0, vec![], &consensus_manager, 1.into()).unwrap();
+    let block2 = append_block(&store, &block1, vec![], &consensus_manager, 1.into()).unwrap();
+    let _block3 = append_block(&store, &block2, vec![], &consensus_manager, 1.into()).unwrap();
+
+    // A stronger block forking off block 2 replaces block 3, which is within the reorg depth limit
+    let mut fork_block = BlockHeader::from_previous(block2.header()).into_builder().build();
+    find_header_with_achieved_difficulty(&mut fork_block.header, 10.into());
+    let fork_block = Arc::new(fork_block);
+    let (_, block_add_result) = inbound_nch
+        .handle_block(fork_block.clone(), false.into(), None)
+        .await
+        .unwrap();
+    assert!(matches!(block_add_result, BlockAddResult::ChainReorg { .. }));
+
+    let metadata = store.get_chain_metadata().unwrap();
+    assert_eq!(metadata.height_of_longest_chain(), 3);
+    assert_eq!(metadata.best_block(), &fork_block.hash());
+}
+
+#[tokio_macros::test]
+async fn inbound_handle_block_propagates_with_fanout() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let block0 = consensus_manager.get_genesis_block();
+    let validators = Validators::new(
+        MockValidator::new(true),
+        MockValidator::new(true),
+        MockValidator::new(true),
+    );
+    let store = create_store_with_consensus_and_validators(consensus_manager.clone(), validators);
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, mut block_receiver) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.clone().into(),
+        new_mempool(),
+        consensus_manager.clone(),
+        outbound_nci,
+    )
+    .with_block_propagation_fanout(3);
+
+    let block1 = store
+        .prepare_block_merkle_roots(chain_block(block0.block(), vec![], &consensus_manager))
+        .unwrap();
+    let block1 = Arc::new(block1);
+    let source_peer = NodeId::default();
+    let (block_hash, block_add_result) = inbound_nch
+        .handle_block(block1.clone(), true.into(), Some(source_peer.clone()))
+        .await
+        .unwrap();
+    assert_eq!(block_hash, block1.hash());
+    assert!(block_add_result.was_chain_modified());
+
+    let (new_block, excluded_peers, fanout) = block_receiver.next().await.unwrap();
+    assert_eq!(new_block.block_hash, block1.hash());
+    assert_eq!(excluded_peers, vec![source_peer]);
+    assert_eq!(fanout, Some(3));
+}
+
+#[tokio_macros::test]
+async fn inbound_fetch_deleted_bitmap() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let block0 = consensus_manager.get_genesis_block();
+    let validators = Validators::new(
+        MockValidator::new(true),
+        MockValidator::new(true),
+        MockValidator::new(true),
+    );
+    let store = create_store_with_consensus_and_validators(consensus_manager.clone(), validators);
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.clone().into(),
+        new_mempool(),
+        consensus_manager.clone(),
+        outbound_nci,
+    );
+
+    let block1 = append_block(&store, &block0, vec![], &consensus_manager, 1.into()).unwrap();
+
+    for (height, hash) in vec![(None, block1.hash()), (Some(0), block0.hash())] {
+        let expected = store.fetch_complete_deleted_bitmap_at(hash.clone()).unwrap();
+        if let Ok(NodeCommsResponse::DeletedBitmap(deleted)) = inbound_nch
+            .handle_request(NodeCommsRequest::FetchDeletedBitmap { height })
+            .await
+        {
+            assert_eq!(Bitmap::deserialize(&deleted), expected.into_bitmap());
+        } else {
+            panic!();
+        }
+    }
+
+    let result = inbound_nch
+        .handle_request(NodeCommsRequest::FetchDeletedBitmap { height: Some(2) })
+        .await;
+    assert!(matches!(result, Err(CommsInterfaceError::BlockHeaderNotFound(2))));
+}
+
+#[tokio_macros::test]
+async fn inbound_get_new_block_template_uses_cache() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let block0 = consensus_manager.get_genesis_block();
+    let validators = Validators::new(
+        MockValidator::new(true),
+        MockValidator::new(true),
+        MockValidator::new(true),
+    );
+    let store = create_store_with_consensus_and_validators(consensus_manager.clone(), validators);
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.clone().into(),
+        new_mempool(),
+        consensus_manager.clone(),
+        outbound_nci,
+    )
+    .with_block_template_cache_max_age(Duration::from_secs(60));
+
+    let request = || {
+        NodeCommsRequest::GetNewBlockTemplate(GetNewBlockTemplateRequest {
+            algo: PowAlgorithm::Sha3,
+            max_weight: 0,
+            exclude_kernel_sigs: vec![],
+        })
+    };
+
+    if let Ok(NodeCommsResponse::NewBlockTemplate { from_cache, .. }) = inbound_nch.handle_request(request()).await {
+        assert!(!from_cache);
+    } else {
+        panic!();
+    }
+    if let Ok(NodeCommsResponse::NewBlockTemplate {
+        block_template,
+        from_cache,
+    }) = inbound_nch.handle_request(request()).await
+    {
+        assert!(from_cache);
+        assert_eq!(block_template.header.height, 1);
+    } else {
+        panic!();
+    }
+
+    // A new tip invalidates the cached template
+    append_block(&store, &block0, vec![], &consensus_manager, 1.into()).unwrap();
+    if let Ok(NodeCommsResponse::NewBlockTemplate {
+        block_template,
+        from_cache,
+    }) = inbound_nch.handle_request(request()).await
+    {
+        assert!(!from_cache);
+        assert_eq!(block_template.header.height, 2);
+    } else {
+        panic!();
+    }
+}
+
+#[tokio_macros::test]
+async fn inbound_get_new_block_template_excludes_kernels() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let validators = Validators::new(
+        MockValidator::new(true),
+        MockValidator::new(true),
+        MockValidator::new(true),
+    );
+    let store = create_store_with_consensus_and_validators(consensus_manager.clone(), validators);
+    let mempool = new_mempool();
+    let (tx1, _, _) = tx!(1 * T, fee: 100 * uT);
+    let (tx2, _, _) = tx!(2 * T, fee: 100 * uT);
+    let excluded_sig = tx1.first_kernel_excess_sig().unwrap().clone();
+    mempool.insert(Arc::new(tx1)).unwrap();
+    mempool.insert(Arc::new(tx2)).unwrap();
+
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.into(),
+        mempool,
+        consensus_manager,
+        outbound_nci,
+    )
+    .with_block_template_cache_max_age(Duration::from_secs(60));
+
+    let request = |exclude_kernel_sigs| {
+        NodeCommsRequest::GetNewBlockTemplate(GetNewBlockTemplateRequest {
+            algo: PowAlgorithm::Sha3,
+            max_weight: 0,
+            exclude_kernel_sigs,
+        })
+    };
+
+    if let Ok(NodeCommsResponse::NewBlockTemplate { block_template, .. }) =
+        inbound_nch.handle_request(request(vec![])).await
+    {
+        assert_eq!(block_template.body.kernels().len(), 2);
+    } else {
+        panic!();
+    }
+
+    // The cached template must not be returned when transactions are excluded
+    if let Ok(NodeCommsResponse::NewBlockTemplate {
+        block_template,
+        from_cache,
+    }) = inbound_nch.handle_request(request(vec![excluded_sig.clone()])).await
+    {
+        assert!(!from_cache);
+        assert_eq!(block_template.body.kernels().len(), 1);
+        assert_ne!(block_template.body.kernels()[0].excess_sig, excluded_sig);
+    } else {
+        panic!();
+    }
+}
+
+/// A mempool validator that panics, which poisons the mempool lock and makes every subsequent mempool call fail
+struct PanickingValidator;
+
+impl MempoolTransactionValidation for PanickingValidator {
+    fn validate(&self, _transaction: &Transaction) -> Result<(), ValidationError> {
+        panic!("PanickingValidator");
+    }
+}
+
+#[tokio_macros::test]
+async fn inbound_get_new_block_template_mempool_unavailable() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let validators = Validators::new(
+        MockValidator::new(true),
+        MockValidator::new(true),
+        MockValidator::new(true),
+    );
+    let store = create_store_with_consensus_and_validators(consensus_manager.clone(), validators);
+    let mempool = Mempool::new(MempoolConfig::default(), Arc::new(PanickingValidator));
+    let (tx, _, _) = tx!(1 * T, fee: 100 * uT);
+    let poisoned_mempool = mempool.clone();
+    assert!(std::thread::spawn(move || poisoned_mempool.insert(Arc::new(tx)))
+        .join()
+        .is_err());
+
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.into(),
+        mempool,
+        consensus_manager,
+        outbound_nci,
+    )
+    .with_block_template_cache_max_age(Duration::from_secs(60));
+
+    let request = || {
+        NodeCommsRequest::GetNewBlockTemplate(GetNewBlockTemplateRequest {
+            algo: PowAlgorithm::Sha3,
+            max_weight: 0,
+            exclude_kernel_sigs: vec![],
+        })
+    };
+
+    if let Ok(NodeCommsResponse::NewBlockTemplate {
+        block_template,
+        from_cache,
+    }) = inbound_nch.handle_request(request()).await
+    {
+        assert!(!from_cache);
+        assert_eq!(block_template.header.height, 1);
+        assert!(block_template.body.kernels().is_empty());
+    } else {
+        panic!();
+    }
+    // The empty template is not cached
+    if let Ok(NodeCommsResponse::NewBlockTemplate { from_cache, .. }) = inbound_nch.handle_request(request()).await {
+        assert!(!from_cache);
+    } else {
+        panic!();
+    }
+
+    let inbound_nch = inbound_nch.with_empty_template_on_mempool_error(false);
+    let result = inbound_nch.handle_request(request()).await;
+    assert!(matches!(result, Err(CommsInterfaceError::MempoolError(_))));
+}
+
+#[tokio_macros::test]
+async fn inbound_get_accumulated_difficulty() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let block0 = consensus_manager.get_genesis_block();
+    let validators = Validators::new(
+        MockValidator::new(true),
+        MockValidator::new(true),
+        MockValidator::new(true),
+    );
+    let store = create_store_with_consensus_and_validators(consensus_manager.clone(), validators);
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.clone().into(),
+        new_mempool(),
+        consensus_manager.clone(),
+        outbound_nci,
+    );
+
+    let block1 = append_block(&store, &block0, vec![], &consensus_manager, 1.into()).unwrap();
+
+    if let Ok(NodeCommsResponse::AccumulatedDifficulty { monero, sha3 }) = inbound_nch
+        .handle_request(NodeCommsRequest::GetAccumulatedDifficulty(1))
+        .await
+    {
+        let accumulated_data = block1.accumulated_data();
+        assert_eq!(monero, accumulated_data.accumulated_monero_difficulty);
+        assert_eq!(sha3, accumulated_data.accumulated_sha_difficulty);
+    } else {
+        panic!();
+    }
+
+    let result = inbound_nch
+        .handle_request(NodeCommsRequest::GetAccumulatedDifficulty(2))
+        .await;
+    assert!(matches!(result, Err(CommsInterfaceError::BlockHeaderNotFound(2))));
+}
+
+#[tokio_macros::test]
+async fn inbound_get_accumulated_difficulties() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build();
+    let block0 = consensus_manager.get_genesis_block();
+    let validators = Validators::new(
+        MockValidator::new(true),
+        MockValidator::new(true),
+        MockValidator::new(true),
+    );
+    let store = create_store_with_consensus_and_validators(consensus_manager.clone(), validators);
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.clone().into(),
+        new_mempool(),
+        consensus_manager.clone(),
+        outbound_nci,
+    );
+
+    let block1 = append_block(&store, &block0, vec![], &consensus_manager, 1.into()).unwrap();
+    let block2 = append_block(&store, &block1, vec![], &consensus_manager, 1.into()).unwrap();
+
+    if let Ok(NodeCommsResponse::AccumulatedDifficulties(difficulties)) = inbound_nch
+        .handle_request(NodeCommsRequest::GetAccumulatedDifficulties { start: 1, end: 2 })
+        .await
+    {
+        assert_eq!(difficulties.len(), 2);
+        for (block, (height, monero, sha3)) in [&block1, &block2].iter().zip(difficulties) {
+            let accumulated_data = block.accumulated_data();
+            assert_eq!(height, block.height());
+            assert_eq!(monero, accumulated_data.accumulated_monero_difficulty);
+            assert_eq!(sha3, accumulated_data.accumulated_sha_difficulty);
+        }
+    } else {
+        panic!();
+    }
+
+    let result = inbound_nch
+        .handle_request(NodeCommsRequest::GetAccumulatedDifficulties { start: 1, end: 3 })
+        .await;
+    assert!(matches!(result, Err(CommsInterfaceError::BlockHeaderNotFound(3))));
+
+    let result = inbound_nch
+        .handle_request(NodeCommsRequest::GetAccumulatedDifficulties { start: 0, end: 1_000 })
+        .await;
+    assert!(matches!(result, Err(CommsInterfaceError::RequestTooLarge(_))));
+}
+
+#[tokio_macros::test]
+async fn inbound_get_mempool_stats() {
+    let consensus_manager = ConsensusManager::builder(Network::LocalNet).build();
+    let store = create_test_blockchain_db();
+    let (block_event_sender, _) = broadcast::channel(50);
+    let (request_sender, _) = reply_channel::unbounded();
+    let (block_sender, _) = mpsc::unbounded();
+    let outbound_nci = OutboundNodeCommsInterface::new(request_sender, block_sender);
+    let inbound_nch = InboundNodeCommsHandlers::new(
+        block_event_sender,
+        store.into(),
+        new_mempool(),
+        consensus_manager,
+        outbound_nci,
+    );
+
+    if let Ok(NodeCommsResponse::MempoolStats {
+        unconfirmed_txs,
+        total_weight,
+        total_fees,
+    }) = inbound_nch.handle_request(NodeCommsRequest::GetMempoolStats).await
+    {
+        assert_eq!(unconfirmed_txs, 0);
+        assert_eq!(total_weight, 0);
+        assert_eq!(total_fees, MicroTari::from(0));
+    } else {
+        panic!();
+    }
+}
+
 #[tokio_macros::test]
 #[ignore]
 // Test needs to be updated to new pruned structure.
@@ -566,6 +566,8 @@ fn service_request_timeout() {
         fetch_blocks_timeout: Default::default(),
         fetch_utxos_timeout: Default::default(),
         desired_response_fraction: Default::default(),
+        max_reorg_depth: None,
+        ..Default::default()
     };
     let temp_dir = tempdir().unwrap();
     let (mut alice_node, bob_node, _consensus_manager) = create_network_with_2_base_nodes_with_config(
@@ -635,7 +637,8 @@ fn local_get_new_block_template_and_get_new_block() {
             .local_nci
             .get_new_block_template(PowAlgorithm::Sha3, 0)
             .await
-            .unwrap();
+            .unwrap()
+            .0;
         assert_eq!(block_template.header.height, 1);
         assert_eq!(block_template.body.kernels().len(), 2);
 
@@ -705,7 +708,8 @@ fn local_get_new_block_with_zero_conf() {
             .local_nci
             .get_new_block_template(PowAlgorithm::Sha3, 0)
             .await
-            .unwrap();
+            .unwrap()
+            .0;
         assert_eq!(block_template.header.height, 1);
         assert_eq!(block_template.body.kernels().len(), 4);
         let coinbase_value = rules.get_block_reward_at(1) + block_template.body.get_total_fee();
@@ -779,7 +783,8 @@ fn local_get_new_block_with_combined_transaction() {
             .local_nci
             .get_new_block_template(PowAlgorithm::Sha3, 0)
             .await
-            .unwrap();
+            .unwrap()
+            .0;
         assert_eq!(block_template.header.height, 1);
         assert_eq!(block_template.body.kernels().len(), 4);
         let coinbase_value = rules.get_block_reward_at(1) + block_template.body.get_total_fee();
@@ -26,6 +26,7 @@ use crate::{
     transport::{TorConfig, TransportType},
     MAJOR_NETWORK_VERSION,
     MINOR_NETWORK_VERSION,
+    MIN_COMPATIBLE_MINOR_NETWORK_VERSION,
 };
 use fs2::FileExt;
 use futures::{channel::mpsc, future, Sink};
@@ -157,6 +158,25 @@ pub struct CommsConfig {
     pub auxilary_tcp_listener_address: Option<Multiaddr>,
 }
 
+impl CommsConfig {
+    /// Caps `allow_test_addresses` for the connection manager and the DHT with `Network::allows_test_addresses` of the
+    /// configured network. Test addresses are only allowed if they are enabled explicitly and the network permits them,
+    /// so they can never be enabled on MainNet. A warning is logged if they are enabled on a network that does not
+    /// permit them.
+    pub fn with_network_test_address_policy(mut self) -> Self {
+        let network_allows_test_addresses = self.network.allows_test_addresses();
+        if !network_allows_test_addresses && (self.allow_test_addresses || self.dht.allow_test_addresses) {
+            warn!(
+                target: LOG_TARGET,
+                "Test addresses are not permitted on {}. The allow_test_addresses setting is ignored.", self.network
+            );
+        }
+        self.allow_test_addresses &= network_allows_test_addresses;
+        self.dht.allow_test_addresses &= network_allows_test_addresses;
+        self
+    }
+}
+
 /// Initialize Tari Comms configured for tests
 pub async fn initialize_local_test_comms<TSink>(
     node_identity: Arc<NodeIdentity>,
@@ -552,11 +572,19 @@ impl ServiceInitializer for P2pInitializer {
             .with_node_info(NodeNetworkInfo {
                 major_version: MAJOR_NETWORK_VERSION,
                 minor_version: MINOR_NETWORK_VERSION,
+                min_compatible_minor_version: MIN_COMPATIBLE_MINOR_NETWORK_VERSION,
                 network_byte: config.network.as_byte(),
                 user_agent: config.user_agent.clone(),
             });
 
         if config.allow_test_addresses {
+            if !config.network.allows_test_addresses() {
+                warn!(
+                    target: LOG_TARGET,
+                    "Test addresses are allowed on {}. This is insecure and should only be used for testing.",
+                    config.network
+                );
+            }
             builder = builder.allow_test_addresses();
         }
 
@@ -583,3 +611,57 @@ impl ServiceInitializer for P2pInitializer {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DEFAULT_DNS_NAME_SERVER;
+    use rand::rngs::OsRng;
+
+    fn create_comms_config(network: Network, allow_test_addresses: bool) -> CommsConfig {
+        let node_identity = NodeIdentity::random(
+            &mut OsRng,
+            "/memory/0".parse().unwrap(),
+            PeerFeatures::COMMUNICATION_NODE,
+        );
+        CommsConfig {
+            network,
+            transport_type: TransportType::Memory {
+                listener_address: node_identity.public_address(),
+            },
+            node_identity: Arc::new(node_identity),
+            auxilary_tcp_listener_address: None,
+            datastore_path: Default::default(),
+            peer_database_name: "peers".to_string(),
+            max_concurrent_inbound_tasks: 100,
+            outbound_buffer_size: 100,
+            dht: DhtConfig {
+                allow_test_addresses,
+                ..Default::default()
+            },
+            allow_test_addresses,
+            listener_liveness_allowlist_cidrs: Vec::new(),
+            listener_liveness_max_sessions: 0,
+            user_agent: "tari/test".to_string(),
+            dns_seeds_name_server: DEFAULT_DNS_NAME_SERVER.parse().unwrap(),
+            peer_seeds: Default::default(),
+            dns_seeds: Default::default(),
+            dns_seeds_use_dnssec: false,
+        }
+    }
+
+    #[test]
+    fn network_test_address_policy_caps_the_config() {
+        let config = create_comms_config(Network::Weatherwax, false).with_network_test_address_policy();
+        assert!(!config.allow_test_addresses);
+        assert!(!config.dht.allow_test_addresses);
+
+        let config = create_comms_config(Network::LocalNet, true).with_network_test_address_policy();
+        assert!(config.allow_test_addresses);
+        assert!(config.dht.allow_test_addresses);
+
+        let config = create_comms_config(Network::MainNet, true).with_network_test_address_policy();
+        assert!(!config.allow_test_addresses);
+        assert!(!config.dht.allow_test_addresses);
+    }
+}
@@ -59,3 +59,6 @@ pub const MAJOR_NETWORK_VERSION: u32 = 0;
 /// Minor network version. This should change with each time the network protocol has changed in a backward-compatible
 /// way.
 pub const MINOR_NETWORK_VERSION: u32 = 0;
+/// The lowest minor network version that peers must advertise. Raising this refuses connections from peers that have
+/// not upgraded.
+pub const MIN_COMPATIBLE_MINOR_NETWORK_VERSION: u32 = 0;
@@ -20,7 +20,10 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use super::{error::LivenessError, state::Metadata};
+use super::{
+    error::LivenessError,
+    state::{LatencyStats, Metadata},
+};
 use crate::proto::liveness::MetadataKey;
 use std::sync::Arc;
 use tari_comms::peer_manager::NodeId;
@@ -41,6 +44,8 @@ pub enum LivenessRequest {
     GetAvgLatency(NodeId),
     /// Get average latency for all connected nodes
     GetNetworkAvgLatency,
+    /// Get the minimum, maximum and average latency over the samples of all connected nodes
+    GetNetworkLatencyStats,
     /// Set the metadata attached to each ping/pong message
     SetMetadataEntry(MetadataKey, Vec<u8>),
 }
@@ -54,6 +59,8 @@ pub enum LivenessResponse {
     Count(usize),
     /// Response for GetAvgLatency and GetNetworkAvgLatency
     AvgLatency(Option<u32>),
+    /// Response for GetNetworkLatencyStats
+    LatencyStats(LatencyStats),
     /// The number of active neighbouring peers
     NumActiveNeighbours(usize),
 }
@@ -166,4 +173,12 @@ impl LivenessHandle {
             _ => Err(LivenessError::UnexpectedApiResponse),
         }
     }
+
+    /// Retrieve the minimum, maximum and average latency over the samples of all connected nodes
+    pub async fn get_network_latency_stats(&mut self) -> Result<LatencyStats, LivenessError> {
+        match self.handle.call(LivenessRequest::GetNetworkLatencyStats).await?? {
+            LivenessResponse::LatencyStats(stats) => Ok(stats),
+            _ => Err(LivenessError::UnexpectedApiResponse),
+        }
+    }
 }
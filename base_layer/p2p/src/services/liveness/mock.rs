@@ -137,6 +137,11 @@ impl LivenessMock {
             GetNetworkAvgLatency => {
                 reply.send(Ok(LivenessResponse::AvgLatency(None))).unwrap();
             },
+            GetNetworkLatencyStats => {
+                reply
+                    .send(Ok(LivenessResponse::LatencyStats(Default::default())))
+                    .unwrap();
+            },
             SetMetadataEntry(_, _) => {
                 reply.send(Ok(LivenessResponse::Ok)).unwrap();
             },
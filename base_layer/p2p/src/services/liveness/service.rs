@@ -238,6 +238,10 @@ where
                 let latency = self.state.get_network_avg_latency();
                 Ok(LivenessResponse::AvgLatency(latency))
             },
+            GetNetworkLatencyStats => {
+                let stats = self.state.get_network_latency_stats();
+                Ok(LivenessResponse::LatencyStats(stats))
+            },
             SetMetadataEntry(key, value) => {
                 self.state.set_metadata_entry(key, value);
                 Ok(LivenessResponse::Ok)
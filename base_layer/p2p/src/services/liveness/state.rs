@@ -31,6 +31,15 @@ use tari_comms::peer_manager::NodeId;
 const LATENCY_SAMPLE_WINDOW_SIZE: usize = 25;
 const MAX_INFLIGHT_TTL: Duration = Duration::from_secs(20);
 
+/// Latency statistics, in milliseconds, over the latency samples of all peers. All values are zero if there are no
+/// samples.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LatencyStats {
+    pub min_ms: u32,
+    pub max_ms: u32,
+    pub avg_ms: u32,
+}
+
 /// Represents metadata in a ping/pong message.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Metadata {
@@ -191,6 +200,15 @@ impl LivenessState {
             // num_peers in map will always be > 0
             .map(|latency| latency / num_peers as u32)
     }
+
+    pub fn get_network_latency_stats(&self) -> LatencyStats {
+        let samples = self.peer_latency.values().flat_map(|latency| latency.samples());
+        LatencyStats {
+            min_ms: samples.clone().min().unwrap_or(0),
+            max_ms: samples.max().unwrap_or(0),
+            avg_ms: self.get_network_avg_latency().unwrap_or(0),
+        }
+    }
 }
 
 /// Convert `chrono::Duration` to `std::time::Duration`
@@ -231,6 +249,11 @@ impl AverageLatency {
 
         samples.iter().fold(0, |sum, x| sum + *x) / samples.len() as u32
     }
+
+    /// Returns an iterator over the recorded samples in milliseconds
+    pub fn samples(&self) -> impl Iterator<Item = u32> + Clone + '_ {
+        self.samples.iter().copied()
+    }
 }
 
 #[cfg(test)]
@@ -299,6 +322,19 @@ mod test {
         assert!(latency < 50);
     }
 
+    #[test]
+    fn get_network_latency_stats() {
+        let mut state = LivenessState::new();
+        assert_eq!(state.get_network_latency_stats(), LatencyStats::default());
+
+        state.add_latency_sample(NodeId::default(), Duration::from_millis(10));
+        state.add_latency_sample(NodeId::default(), Duration::from_millis(30));
+        let stats = state.get_network_latency_stats();
+        assert_eq!(stats.min_ms, 10);
+        assert_eq!(stats.max_ms, 30);
+        assert_eq!(stats.avg_ms, 20);
+    }
+
     #[test]
     fn set_metadata_entry() {
         let mut state = LivenessState::new();
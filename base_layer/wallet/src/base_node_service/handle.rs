@@ -28,9 +28,12 @@ use tari_comms::peer_manager::Peer;
 use std::time::Duration;
 use tari_common_types::chain_metadata::ChainMetadata;
 use tari_service_framework::reply_channel::SenderService;
-use tokio::sync::broadcast;
+use tokio::{sync::broadcast, time};
 use tower::Service;
 
+/// The default amount of time a request to the base node service may take before it is abandoned
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
 pub type BaseNodeEventSender = broadcast::Sender<Arc<BaseNodeEvent>>;
 pub type BaseNodeEventReceiver = broadcast::Receiver<Arc<BaseNodeEvent>>;
 /// API Request enum
@@ -40,6 +43,7 @@ pub enum BaseNodeServiceRequest {
     SetBaseNodePeer(Box<Peer>),
     GetBaseNodePeer,
     GetBaseNodeLatency,
+    RefreshChainMetadata,
 }
 /// API Response enum
 #[derive(Debug)]
@@ -53,6 +57,8 @@ pub enum BaseNodeServiceResponse {
 pub enum BaseNodeEvent {
     BaseNodeStateChanged(BaseNodeState),
     BaseNodePeerSet(Box<Peer>),
+    /// The selected base node peer could not be reached for several consecutive monitoring rounds
+    BaseNodePeerUnreachable(Box<Peer>),
 }
 
 /// The Base Node Service Handle is a struct that contains the interfaces used to communicate with a running
@@ -61,6 +67,7 @@ pub enum BaseNodeEvent {
 pub struct BaseNodeServiceHandle {
     handle: SenderService<BaseNodeServiceRequest, Result<BaseNodeServiceResponse, BaseNodeServiceError>>,
     event_stream_sender: BaseNodeEventSender,
+    request_timeout: Duration,
 }
 
 impl BaseNodeServiceHandle {
@@ -71,15 +78,39 @@ impl BaseNodeServiceHandle {
         Self {
             handle,
             event_stream_sender,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
         }
     }
 
+    /// Overrides the amount of time each request may take before `BaseNodeServiceError::Timeout` is returned
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
     pub fn get_event_stream_fused(&self) -> Fuse<BaseNodeEventReceiver> {
         self.event_stream_sender.subscribe().fuse()
     }
 
+    async fn call(&mut self, request: BaseNodeServiceRequest) -> Result<BaseNodeServiceResponse, BaseNodeServiceError> {
+        time::timeout(self.request_timeout, self.handle.call(request))
+            .await
+            .map_err(|_| BaseNodeServiceError::Timeout)??
+    }
+
     pub async fn get_chain_metadata(&mut self) -> Result<Option<ChainMetadata>, BaseNodeServiceError> {
-        match self.handle.call(BaseNodeServiceRequest::GetChainMetadata).await?? {
+        match self.call(BaseNodeServiceRequest::GetChainMetadata).await? {
+            BaseNodeServiceResponse::ChainMetadata(metadata) => Ok(metadata),
+            _ => Err(BaseNodeServiceError::UnexpectedApiResponse),
+        }
+    }
+
+    /// Queries the connected base node for its chain metadata immediately, bypassing the cached value and the monitor
+    /// refresh interval. This dials the base node and makes an RPC call, so it is considerably heavier than
+    /// `get_chain_metadata` and should only be used when a guaranteed-fresh value is needed, e.g. right after switching
+    /// base nodes.
+    pub async fn refresh_chain_metadata(&mut self) -> Result<Option<ChainMetadata>, BaseNodeServiceError> {
+        match self.call(BaseNodeServiceRequest::RefreshChainMetadata).await? {
             BaseNodeServiceResponse::ChainMetadata(metadata) => Ok(metadata),
             _ => Err(BaseNodeServiceError::UnexpectedApiResponse),
         }
@@ -87,9 +118,8 @@ impl BaseNodeServiceHandle {
 
     pub async fn set_base_node_peer(&mut self, peer: Peer) -> Result<(), BaseNodeServiceError> {
         match self
-            .handle
             .call(BaseNodeServiceRequest::SetBaseNodePeer(Box::new(peer)))
-            .await??
+            .await?
         {
             BaseNodeServiceResponse::BaseNodePeerSet => Ok(()),
             _ => Err(BaseNodeServiceError::UnexpectedApiResponse),
@@ -97,14 +127,14 @@ impl BaseNodeServiceHandle {
     }
 
     pub async fn get_base_node_peer(&mut self) -> Result<Option<Peer>, BaseNodeServiceError> {
-        match self.handle.call(BaseNodeServiceRequest::GetBaseNodePeer).await?? {
+        match self.call(BaseNodeServiceRequest::GetBaseNodePeer).await? {
             BaseNodeServiceResponse::BaseNodePeer(peer) => Ok(peer.map(|p| *p)),
             _ => Err(BaseNodeServiceError::UnexpectedApiResponse),
         }
     }
 
     pub async fn get_base_node_latency(&mut self) -> Result<Option<Duration>, BaseNodeServiceError> {
-        match self.handle.call(BaseNodeServiceRequest::GetBaseNodeLatency).await?? {
+        match self.call(BaseNodeServiceRequest::GetBaseNodeLatency).await? {
             BaseNodeServiceResponse::Latency(latency) => Ok(latency),
             _ => Err(BaseNodeServiceError::UnexpectedApiResponse),
         }
@@ -92,6 +92,7 @@ impl MockBaseNodeService {
             latency: None,
             online,
             base_node_peer: self.state.base_node_peer.clone(),
+            fee_per_gram_stats: None,
         }
     }
 
@@ -104,6 +105,7 @@ impl MockBaseNodeService {
             latency: None,
             online: OnlineState::Online,
             base_node_peer: None,
+            fee_per_gram_stats: None,
         }
     }
 
@@ -125,9 +127,9 @@ impl MockBaseNodeService {
                 let peer = self.state.base_node_peer.clone();
                 Ok(BaseNodeServiceResponse::BaseNodePeer(peer.map(Box::new)))
             },
-            BaseNodeServiceRequest::GetChainMetadata => Ok(BaseNodeServiceResponse::ChainMetadata(
-                self.state.chain_metadata.clone(),
-            )),
+            BaseNodeServiceRequest::GetChainMetadata | BaseNodeServiceRequest::RefreshChainMetadata => Ok(
+                BaseNodeServiceResponse::ChainMetadata(self.state.chain_metadata.clone()),
+            ),
             BaseNodeServiceRequest::GetBaseNodeLatency => Ok(BaseNodeServiceResponse::Latency(None)),
         }
     }
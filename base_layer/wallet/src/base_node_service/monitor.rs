@@ -39,7 +39,7 @@ use tari_comms::{
     protocol::rpc::RpcError,
     PeerConnection,
 };
-use tari_core::base_node::rpc::BaseNodeWalletRpcClient;
+use tari_core::{base_node::rpc::BaseNodeWalletRpcClient, mempool::StatsResponse};
 use tari_shutdown::ShutdownSignal;
 use tokio::{
     stream::StreamExt,
@@ -48,6 +48,8 @@ use tokio::{
 };
 
 const LOG_TARGET: &str = "wallet::base_node_service::chain_metadata_monitor";
+/// The number of consecutive connectivity failures to the base node peer before it is reported as unreachable
+const UNREACHABLE_FAILURE_THRESHOLD: usize = 3;
 
 pub struct BaseNodeMonitor<T> {
     interval: Duration,
@@ -56,6 +58,7 @@ pub struct BaseNodeMonitor<T> {
     connectivity_manager: ConnectivityRequester,
     event_publisher: BaseNodeEventSender,
     shutdown_signal: ShutdownSignal,
+    consecutive_failures: usize,
 }
 
 impl<T: WalletBackend + 'static> BaseNodeMonitor<T> {
@@ -74,6 +77,7 @@ impl<T: WalletBackend + 'static> BaseNodeMonitor<T> {
             connectivity_manager,
             event_publisher,
             shutdown_signal,
+            consecutive_failures: 0,
         }
     }
 
@@ -98,6 +102,7 @@ impl<T: WalletBackend + 'static> BaseNodeMonitor<T> {
                     );
 
                     self.set_offline().await;
+                    self.record_connectivity_failure().await;
                     if self.sleep_or_shutdown().await.is_err() {
                         break;
                     }
@@ -109,6 +114,7 @@ impl<T: WalletBackend + 'static> BaseNodeMonitor<T> {
                         "Base node has changed. Connecting to new base node...",
                     );
 
+                    self.consecutive_failures = 0;
                     self.set_connecting().await;
                     continue;
                 },
@@ -187,7 +193,7 @@ impl<T: WalletBackend + 'static> BaseNodeMonitor<T> {
     }
 
     async fn monitor_node(
-        &self,
+        &mut self,
         peer_node_id: NodeId,
         mut client: BaseNodeWalletRpcClient,
     ) -> Result<(), BaseNodeMonitorError> {
@@ -211,6 +217,16 @@ impl<T: WalletBackend + 'static> BaseNodeMonitor<T> {
 
             self.db.set_chain_metadata(chain_metadata.clone()).await?;
 
+            // Base nodes that predate the mempool stats RPC method will reject it, so a failure here is not treated as
+            // a connectivity failure
+            let fee_per_gram_stats = match client.get_mempool_stats().await {
+                Ok(stats) => StatsResponse::try_from(stats).ok().map(|stats| stats.fee_per_gram),
+                Err(e) => {
+                    debug!(target: LOG_TARGET, "Could not fetch mempool stats from base node: {}", e);
+                    None
+                },
+            };
+
             self.map_state(move |state| BaseNodeState {
                 chain_metadata: Some(chain_metadata),
                 is_synced: Some(is_synced),
@@ -218,8 +234,10 @@ impl<T: WalletBackend + 'static> BaseNodeMonitor<T> {
                 latency,
                 online: OnlineState::Online,
                 base_node_peer: state.base_node_peer.clone(),
+                fee_per_gram_stats,
             })
             .await;
+            self.consecutive_failures = 0;
 
             self.sleep_or_shutdown().await?;
             self.check_if_base_node_changed(&peer_node_id).await?;
@@ -247,6 +265,23 @@ impl<T: WalletBackend + 'static> BaseNodeMonitor<T> {
         }
     }
 
+    /// Counts a connectivity failure and publishes `BaseNodePeerUnreachable` once the failures reach the threshold.
+    /// The event is only published once per run of failures, the count is reset when the base node responds or changes.
+    async fn record_connectivity_failure(&mut self) {
+        self.consecutive_failures += 1;
+        if self.consecutive_failures != UNREACHABLE_FAILURE_THRESHOLD {
+            return;
+        }
+        let peer = self.state.read().await.base_node_peer.clone();
+        if let Some(peer) = peer {
+            warn!(
+                target: LOG_TARGET,
+                "Base node peer {} unreachable after {} consecutive attempts", peer.node_id, self.consecutive_failures
+            );
+            self.publish_event(BaseNodeEvent::BaseNodePeerUnreachable(Box::new(peer)));
+        }
+    }
+
     async fn set_connecting(&self) {
         self.map_state(|state| BaseNodeState {
             chain_metadata: None,
@@ -255,6 +290,7 @@ impl<T: WalletBackend + 'static> BaseNodeMonitor<T> {
             latency: None,
             online: OnlineState::Connecting,
             base_node_peer: state.base_node_peer.clone(),
+            fee_per_gram_stats: None,
         })
         .await;
     }
@@ -267,6 +303,7 @@ impl<T: WalletBackend + 'static> BaseNodeMonitor<T> {
             latency: None,
             online: OnlineState::Offline,
             base_node_peer: state.base_node_peer.clone(),
+            fee_per_gram_stats: None,
         })
         .await;
     }
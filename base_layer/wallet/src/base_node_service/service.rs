@@ -29,12 +29,13 @@ use crate::{
     base_node_service::monitor::BaseNodeMonitor,
     storage::database::{WalletBackend, WalletDatabase},
 };
-use chrono::NaiveDateTime;
-use futures::StreamExt;
+use chrono::{NaiveDateTime, Utc};
+use futures::{channel::oneshot, StreamExt};
 use log::*;
-use std::{sync::Arc, time::Duration};
+use std::{convert::TryFrom, sync::Arc, time::Duration};
 use tari_common_types::chain_metadata::ChainMetadata;
 use tari_comms::{connectivity::ConnectivityRequester, peer_manager::Peer};
+use tari_core::{base_node::rpc::BaseNodeWalletRpcClient, mempool::FeePerGramStats};
 use tari_service_framework::reply_channel::Receiver;
 use tari_shutdown::ShutdownSignal;
 use tokio::sync::RwLock;
@@ -50,6 +51,8 @@ pub struct BaseNodeState {
     pub latency: Option<Duration>,
     pub online: OnlineState,
     pub base_node_peer: Option<Peer>,
+    /// The fee per gram stats of the base node's mempool, if the base node provides them
+    pub fee_per_gram_stats: Option<FeePerGramStats>,
 }
 
 /// Connection state of the Base Node
@@ -69,6 +72,7 @@ impl Default for BaseNodeState {
             latency: None,
             online: OnlineState::Connecting,
             base_node_peer: None,
+            fee_per_gram_stats: None,
         }
     }
 }
@@ -141,14 +145,7 @@ where T: WalletBackend + 'static
         while let Some(request_context) = request_stream.next().await {
             // Incoming requests
             let (request, reply_tx) = request_context.split();
-            let response = self.handle_request(request).await.map_err(|e| {
-                error!(target: LOG_TARGET, "Error handling request: {:?}", e);
-                e
-            });
-            let _ = reply_tx.send(response).map_err(|e| {
-                warn!(target: LOG_TARGET, "Failed to send reply");
-                e
-            });
+            self.handle_request(request, reply_tx).await;
         }
 
         info!(
@@ -177,12 +174,13 @@ where T: WalletBackend + 'static
     async fn handle_request(
         &mut self,
         request: BaseNodeServiceRequest,
-    ) -> Result<BaseNodeServiceResponse, BaseNodeServiceError> {
+        reply_tx: oneshot::Sender<Result<BaseNodeServiceResponse, BaseNodeServiceError>>,
+    ) {
         debug!(
             target: LOG_TARGET,
             "Handling Wallet Base Node Service Request: {:?}", request
         );
-        match request {
+        let response = match request {
             BaseNodeServiceRequest::SetBaseNodePeer(peer) => {
                 self.set_base_node_peer(*peer).await;
                 Ok(BaseNodeServiceResponse::BaseNodePeerSet)
@@ -195,14 +193,35 @@ where T: WalletBackend + 'static
                 Some(metadata) => Ok(BaseNodeServiceResponse::ChainMetadata(Some(metadata))),
                 None => {
                     // if we don't have live state, check if we've previously stored state in the wallet db
-                    let metadata = self.db.get_chain_metadata().await?;
-                    Ok(BaseNodeServiceResponse::ChainMetadata(metadata))
+                    self.db
+                        .get_chain_metadata()
+                        .await
+                        .map(BaseNodeServiceResponse::ChainMetadata)
+                        .map_err(Into::into)
                 },
             },
             BaseNodeServiceRequest::GetBaseNodeLatency => {
                 Ok(BaseNodeServiceResponse::Latency(self.state.read().await.latency))
             },
-        }
+            BaseNodeServiceRequest::RefreshChainMetadata => {
+                // Dialing the base node can take a while, so the refresh runs off the request loop and replies once it
+                // completes
+                let refresh = refresh_chain_metadata(
+                    self.state.clone(),
+                    self.db.clone(),
+                    self.connectivity_manager.clone(),
+                    self.event_publisher.clone(),
+                );
+                tokio::spawn(async move {
+                    let response = refresh
+                        .await
+                        .map(|metadata| BaseNodeServiceResponse::ChainMetadata(Some(metadata)));
+                    send_reply(reply_tx, response);
+                });
+                return;
+            },
+        };
+        send_reply(reply_tx, response);
     }
 
     fn publish_event(&self, event: BaseNodeEvent) {
@@ -215,3 +234,73 @@ where T: WalletBackend + 'static
         });
     }
 }
+
+fn send_reply(
+    reply_tx: oneshot::Sender<Result<BaseNodeServiceResponse, BaseNodeServiceError>>,
+    response: Result<BaseNodeServiceResponse, BaseNodeServiceError>,
+) {
+    let response = response.map_err(|e| {
+        error!(target: LOG_TARGET, "Error handling request: {:?}", e);
+        e
+    });
+    let _ = reply_tx.send(response).map_err(|e| {
+        warn!(target: LOG_TARGET, "Failed to send reply");
+        e
+    });
+}
+
+/// Queries the connected base node for its tip info, bypassing the monitor interval, and updates the cached state with
+/// the result.
+async fn refresh_chain_metadata<T: WalletBackend + 'static>(
+    state: Arc<RwLock<BaseNodeState>>,
+    db: WalletDatabase<T>,
+    mut connectivity: ConnectivityRequester,
+    event_publisher: BaseNodeEventSender,
+) -> Result<ChainMetadata, BaseNodeServiceError> {
+    let node_id = state
+        .read()
+        .await
+        .base_node_peer
+        .as_ref()
+        .map(|peer| peer.node_id.clone())
+        .ok_or(BaseNodeServiceError::NoBaseNodePeer)?;
+
+    let mut conn = connectivity.dial_peer(node_id).await?;
+    let mut client = conn.connect_rpc::<BaseNodeWalletRpcClient>().await?;
+    let tip_info = client.get_tip_info().await?;
+    let latency = client.get_last_request_latency().await?;
+    let is_synced = tip_info.is_synced;
+
+    let chain_metadata = tip_info
+        .metadata
+        .ok_or_else(|| BaseNodeServiceError::InvalidBaseNodeResponse("Tip info no metadata".to_string()))
+        .and_then(|metadata| {
+            ChainMetadata::try_from(metadata).map_err(BaseNodeServiceError::InvalidBaseNodeResponse)
+        })?;
+
+    db.set_chain_metadata(chain_metadata.clone()).await?;
+
+    let new_state = {
+        let mut lock = state.write().await;
+        *lock = BaseNodeState {
+            chain_metadata: Some(chain_metadata.clone()),
+            is_synced: Some(is_synced),
+            updated: Some(Utc::now().naive_utc()),
+            latency,
+            online: OnlineState::Online,
+            base_node_peer: lock.base_node_peer.clone(),
+            fee_per_gram_stats: lock.fee_per_gram_stats,
+        };
+        lock.clone()
+    };
+    let _ = event_publisher
+        .send(Arc::new(BaseNodeEvent::BaseNodeStateChanged(new_state)))
+        .map_err(|_| {
+            trace!(
+                target: LOG_TARGET,
+                "Could not publish BaseNodeEvent as there are no subscribers"
+            )
+        });
+
+    Ok(chain_metadata)
+}
@@ -23,7 +23,7 @@
 use crate::{
     output_manager_service::{
         error::OutputManagerError,
-        service::Balance,
+        service::{Balance, BalanceByOutputType},
         storage::{database::PendingTransactionOutputs, models::KnownOneSidedPaymentScript},
         tasks::TxoValidationType,
         TxId,
@@ -50,6 +50,7 @@ use tower::Service;
 /// API Request enum
 pub enum OutputManagerRequest {
     GetBalance,
+    GetBalanceByOutputType,
     AddOutput(Box<UnblindedOutput>),
     AddOutputWithTxId((TxId, Box<UnblindedOutput>)),
     UpdateOutputMetadataSignature(Box<TransactionOutput>),
@@ -83,6 +84,7 @@ impl fmt::Display for OutputManagerRequest {
         use OutputManagerRequest::*;
         match self {
             GetBalance => write!(f, "GetBalance"),
+            GetBalanceByOutputType => write!(f, "GetBalanceByOutputType"),
             AddOutput(v) => write!(f, "AddOutput ({})", v.value),
             AddOutputWithTxId((t, v)) => write!(f, "AddOutputWithTxId ({}: {})", t, v.value),
             UpdateOutputMetadataSignature(v) => write!(
@@ -123,6 +125,7 @@ impl fmt::Display for OutputManagerRequest {
 #[derive(Debug, Clone)]
 pub enum OutputManagerResponse {
     Balance(Balance),
+    BalanceByOutputType(BalanceByOutputType),
     OutputAdded,
     OutputMetadataSignatureUpdated,
     RecipientTransactionGenerated(ReceiverTransactionProtocol),
@@ -147,7 +150,7 @@ pub enum OutputManagerResponse {
     PublicRewindKeys(Box<PublicRewindKeys>),
     FeeEstimate(MicroTari),
     RewoundOutputs(Vec<UnblindedOutput>),
-    ScanOutputs(Vec<UnblindedOutput>),
+    ScanOutputs(Vec<(UnblindedOutput, Option<Vec<u8>>)>),
     AddKnownOneSidedPaymentScript,
 }
 
@@ -239,6 +242,14 @@ impl OutputManagerHandle {
         }
     }
 
+    /// Returns the available balance broken down by the type of the unspent outputs that make it up
+    pub async fn get_balance_by_output_type(&mut self) -> Result<BalanceByOutputType, OutputManagerError> {
+        match self.handle.call(OutputManagerRequest::GetBalanceByOutputType).await?? {
+            OutputManagerResponse::BalanceByOutputType(b) => Ok(b),
+            _ => Err(OutputManagerError::UnexpectedApiResponse),
+        }
+    }
+
     pub async fn get_recipient_transaction(
         &mut self,
         sender_message: TransactionSenderMessage,
@@ -503,10 +514,12 @@ impl OutputManagerHandle {
         }
     }
 
+    /// Scan the outputs for one-sided payments to this wallet and add them as unspent outputs. Each output that was
+    /// found is returned with the payment id that the sender attached to it, if any.
     pub async fn scan_outputs_for_one_sided_payments(
         &mut self,
         outputs: Vec<TransactionOutput>,
-    ) -> Result<Vec<UnblindedOutput>, OutputManagerError> {
+    ) -> Result<Vec<(UnblindedOutput, Option<Vec<u8>>)>, OutputManagerError> {
         match self.handle.call(OutputManagerRequest::ScanOutputs(outputs)).await?? {
             OutputManagerResponse::ScanOutputs(outputs) => Ok(outputs),
             _ => Err(OutputManagerError::UnexpectedApiResponse),
@@ -63,6 +63,7 @@ use tari_core::{
         transaction::{
             KernelFeatures,
             OutputFeatures,
+            OutputFlags,
             Transaction,
             TransactionInput,
             TransactionOutput,
@@ -219,6 +220,13 @@ where TBackend: OutputManagerBackend + 'static
                     .await
                     .map(OutputManagerResponse::Balance)
             },
+            OutputManagerRequest::GetBalanceByOutputType => self
+                .resources
+                .db
+                .get_balance_by_output_type()
+                .await
+                .map(OutputManagerResponse::BalanceByOutputType)
+                .map_err(OutputManagerError::OutputManagerStorageError),
             OutputManagerRequest::GetRecipientTransaction(tsm) => self
                 .get_recipient_transaction(tsm)
                 .await
@@ -1205,11 +1213,11 @@ where TBackend: OutputManagerBackend + 'static
     async fn scan_outputs_for_one_sided_payments(
         &mut self,
         outputs: Vec<TransactionOutput>,
-    ) -> Result<Vec<UnblindedOutput>, OutputManagerError> {
+    ) -> Result<Vec<(UnblindedOutput, Option<Vec<u8>>)>, OutputManagerError> {
         let known_one_sided_payment_scripts: Vec<KnownOneSidedPaymentScript> =
             self.resources.db.get_all_known_one_sided_payment_scripts().await?;
 
-        let mut rewound_outputs: Vec<UnblindedOutput> = Vec::new();
+        let mut rewound_outputs: Vec<(UnblindedOutput, Option<Vec<u8>>)> = Vec::new();
         for output in outputs {
             let position = known_one_sided_payment_scripts
                 .iter()
@@ -1244,7 +1252,8 @@ where TBackend: OutputManagerBackend + 'static
                     let output_hex = output.commitment.to_hex();
                     match self.resources.db.add_unspent_output(db_output).await {
                         Ok(_) => {
-                            rewound_outputs.push(rewound_output);
+                            let payment_id = payment_id_from_proof_message(&rewound_result.proof_message);
+                            rewound_outputs.push((rewound_output, payment_id));
                         },
                         Err(OutputManagerStorageError::DuplicateOutput) => {
                             warn!(
@@ -1271,6 +1280,13 @@ where TBackend: OutputManagerBackend + 'static
     }
 }
 
+/// Returns the payment id that the sender of a one-sided payment embedded in the range proof message, or None if there
+/// is none. Payment ids are zero-padded to the message length, so trailing zero bytes are not part of the payment id.
+fn payment_id_from_proof_message(proof_message: &[u8]) -> Option<Vec<u8>> {
+    let len = proof_message.iter().rposition(|b| *b != 0)? + 1;
+    Some(proof_message[..len].to_vec())
+}
+
 /// Different UTXO selection strategies for choosing which UTXO's are used to fulfill a transaction
 /// TODO Investigate and implement more optimal strategies
 #[derive(Debug)]
@@ -1330,6 +1346,39 @@ impl fmt::Display for Balance {
     }
 }
 
+/// The available balance broken down by the type of the unspent outputs that make it up
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BalanceByOutputType {
+    /// Outputs with default features and the default `Nop` script
+    pub normal: MicroTari,
+    /// Coinbase outputs, including those that have not matured yet
+    pub coinbase: MicroTari,
+    /// Non-coinbase outputs with a script other than `Nop`, e.g. received one-sided payments
+    pub scripted: MicroTari,
+}
+
+impl BalanceByOutputType {
+    /// Adds the value of `output` to the balance of its output type
+    pub fn add_output(&mut self, output: &UnblindedOutput) {
+        if output.features.flags.contains(OutputFlags::COINBASE_OUTPUT) {
+            self.coinbase += output.value;
+        } else if output.script != script!(Nop) {
+            self.scripted += output.value;
+        } else {
+            self.normal += output.value;
+        }
+    }
+}
+
+impl fmt::Display for BalanceByOutputType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Normal outputs: {}", self.normal)?;
+        writeln!(f, "Coinbase outputs: {}", self.coinbase)?;
+        writeln!(f, "Scripted outputs: {}", self.scripted)?;
+        Ok(())
+    }
+}
+
 fn hash_secret_key(key: &PrivateKey) -> Vec<u8> {
     HashDigest::new().chain(key.as_bytes()).finalize().to_vec()
 }
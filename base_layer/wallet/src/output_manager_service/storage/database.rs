@@ -22,7 +22,7 @@
 
 use crate::output_manager_service::{
     error::OutputManagerStorageError,
-    service::Balance,
+    service::{Balance, BalanceByOutputType},
     storage::models::{DbUnblindedOutput, KnownOneSidedPaymentScript},
     TxId,
 };
@@ -266,6 +266,16 @@ where T: OutputManagerBackend + 'static
         Ok(())
     }
 
+    /// Returns the available balance broken down by the type of the unspent outputs that make it up
+    pub async fn get_balance_by_output_type(&self) -> Result<BalanceByOutputType, OutputManagerStorageError> {
+        let unspent_outputs = self.get_unspent_outputs().await?;
+        let mut balance = BalanceByOutputType::default();
+        for output in &unspent_outputs {
+            balance.add_output(&output.unblinded_output);
+        }
+        Ok(balance)
+    }
+
     pub async fn get_balance(&self, current_chain_tip: Option<u64>) -> Result<Balance, OutputManagerStorageError> {
         let db_clone = self.db.clone();
         let db_clone2 = self.db.clone();
@@ -53,7 +53,7 @@ pub enum TransactionServiceRequest {
     GetAnyTransaction(TxId),
     SetBaseNodePublicKey(CommsPublicKey),
     SendTransaction(CommsPublicKey, MicroTari, MicroTari, String),
-    SendOneSidedTransaction(CommsPublicKey, MicroTari, MicroTari, String),
+    SendOneSidedTransaction(CommsPublicKey, MicroTari, MicroTari, String, Option<Vec<u8>>),
     CancelTransaction(TxId),
     ImportUtxo(MicroTari, CommsPublicKey, String, Option<u64>),
     SubmitCoinSplitTransaction(TxId, Transaction, MicroTari, MicroTari, String),
@@ -64,6 +64,7 @@ pub enum TransactionServiceRequest {
     GenerateCoinbaseTransaction(MicroTari, MicroTari, u64),
     RestartTransactionProtocols,
     RestartBroadcastProtocols,
+    RebroadcastTransaction(TxId),
     GetNumConfirmationsRequired,
     SetNumConfirmationsRequired(u64),
     SetCompletedTransactionValidity(u64, bool),
@@ -92,7 +93,7 @@ impl fmt::Display for TransactionServiceRequest {
             Self::GetCompletedTransaction(t) => f.write_str(&format!("GetCompletedTransaction({})", t)),
             Self::SetBaseNodePublicKey(k) => f.write_str(&format!("SetBaseNodePublicKey ({})", k)),
             Self::SendTransaction(k, v, _, msg) => f.write_str(&format!("SendTransaction (to {}, {}, {})", k, v, msg)),
-            Self::SendOneSidedTransaction(k, v, _, msg) => {
+            Self::SendOneSidedTransaction(k, v, _, msg, _) => {
                 f.write_str(&format!("SendOneSidedTransaction (to {}, {}, {})", k, v, msg))
             },
             Self::CancelTransaction(t) => f.write_str(&format!("CancelTransaction ({})", t)),
@@ -115,6 +116,7 @@ impl fmt::Display for TransactionServiceRequest {
             },
             Self::RestartTransactionProtocols => f.write_str("RestartTransactionProtocols"),
             Self::RestartBroadcastProtocols => f.write_str("RestartBroadcastProtocols"),
+            Self::RebroadcastTransaction(t) => f.write_str(&format!("RebroadcastTransaction ({})", t)),
             Self::GetNumConfirmationsRequired => f.write_str("GetNumConfirmationsRequired"),
             Self::SetNumConfirmationsRequired(_) => f.write_str("SetNumConfirmationsRequired"),
             #[cfg(feature = "test_harness")]
@@ -159,6 +161,7 @@ pub enum TransactionServiceResponse {
     EncryptionRemoved,
     CoinbaseTransactionGenerated(Box<Transaction>),
     ProtocolsRestarted,
+    TransactionRebroadcast,
     AnyTransaction(Box<Option<WalletTransaction>>),
     NumConfirmationsRequired(u64),
     NumConfirmationsSet,
@@ -255,6 +258,21 @@ impl TransactionServiceHandle {
         amount: MicroTari,
         fee_per_gram: MicroTari,
         message: String,
+    ) -> Result<TxId, TransactionServiceError> {
+        self.send_one_sided_transaction_with_payment_id(dest_pubkey, amount, fee_per_gram, message, None)
+            .await
+    }
+
+    /// Send a one-sided transaction with an optional payment id. The payment id is embedded in the recipient's
+    /// output so that the recipient can recover it when scanning for the output. It may be at most
+    /// `REWIND_USER_MESSAGE_LENGTH` bytes long.
+    pub async fn send_one_sided_transaction_with_payment_id(
+        &mut self,
+        dest_pubkey: CommsPublicKey,
+        amount: MicroTari,
+        fee_per_gram: MicroTari,
+        message: String,
+        payment_id: Option<Vec<u8>>,
     ) -> Result<TxId, TransactionServiceError> {
         match self
             .handle
@@ -263,6 +281,7 @@ impl TransactionServiceHandle {
                 amount,
                 fee_per_gram,
                 message,
+                payment_id,
             ))
             .await??
         {
@@ -544,6 +563,19 @@ impl TransactionServiceHandle {
         }
     }
 
+    /// Restart the broadcast protocol of a single completed transaction so that it is resubmitted to the base node
+    /// mempool.
+    pub async fn rebroadcast_transaction(&mut self, tx_id: TxId) -> Result<(), TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::RebroadcastTransaction(tx_id))
+            .await??
+        {
+            TransactionServiceResponse::TransactionRebroadcast => Ok(()),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
     pub async fn validate_transactions(
         &mut self,
         retry_strategy: ValidationRetryStrategy,
@@ -58,6 +58,7 @@ where TBackend: TransactionBackend + 'static
     base_node_public_key: CommsPublicKey,
     timeout_update_receiver: Option<broadcast::Receiver<Duration>>,
     base_node_update_receiver: Option<broadcast::Receiver<CommsPublicKey>>,
+    rebroadcast_receiver: Option<broadcast::Receiver<TxId>>,
     first_rejection: bool,
 }
 
@@ -71,6 +72,7 @@ where TBackend: TransactionBackend + 'static
         base_node_public_key: CommsPublicKey,
         timeout_update_receiver: broadcast::Receiver<Duration>,
         base_node_update_receiver: broadcast::Receiver<CommsPublicKey>,
+        rebroadcast_receiver: broadcast::Receiver<TxId>,
     ) -> Self {
         Self {
             tx_id,
@@ -80,6 +82,7 @@ where TBackend: TransactionBackend + 'static
             base_node_public_key,
             timeout_update_receiver: Some(timeout_update_receiver),
             base_node_update_receiver: Some(base_node_update_receiver),
+            rebroadcast_receiver: Some(rebroadcast_receiver),
             first_rejection: false,
         }
     }
@@ -102,6 +105,14 @@ where TBackend: TransactionBackend + 'static
             })?
             .fuse();
 
+        let mut rebroadcast_receiver = self
+            .rebroadcast_receiver
+            .take()
+            .ok_or_else(|| {
+                TransactionServiceProtocolError::new(self.tx_id, TransactionServiceError::InvalidStateError)
+            })?
+            .fuse();
+
         let mut shutdown = self.resources.shutdown_signal.clone();
         // Main protocol loop
         loop {
@@ -179,6 +190,11 @@ where TBackend: TransactionBackend + 'static
                         }
                     }
                 }
+                rebroadcast = rebroadcast_receiver.select_next_some() => {
+                    if self.is_rebroadcast_requested(rebroadcast) {
+                        continue;
+                    }
+                }
                 _ = shutdown => {
                     info!(target: LOG_TARGET, "Transaction Broadcast Protocol (TxId: {}) shutting down because it received the shutdown signal", self.tx_id);
                     return Err(TransactionServiceProtocolError::new(self.tx_id, TransactionServiceError::Shutdown))
@@ -331,6 +347,11 @@ where TBackend: TransactionBackend + 'static
                             );
                         }
                     },
+                    rebroadcast = rebroadcast_receiver.select_next_some() => {
+                        if self.is_rebroadcast_requested(rebroadcast) {
+                            break;
+                        }
+                    },
                     _ = shutdown => {
                         info!(target: LOG_TARGET, "Transaction Broadcast Protocol (TxId: {}) shutting down because it received the shutdown signal", self.tx_id);
                         return Err(TransactionServiceProtocolError::new(self.tx_id, TransactionServiceError::Shutdown))
@@ -340,6 +361,31 @@ where TBackend: TransactionBackend + 'static
         }
     }
 
+    /// Check whether a rebroadcast was requested for this protocol's transaction and if so switch back to submission
+    /// mode so that the transaction is resubmitted to the base node on the next pass.
+    fn is_rebroadcast_requested(&mut self, rebroadcast: Result<TxId, broadcast::RecvError>) -> bool {
+        match rebroadcast {
+            Ok(tx_id) if tx_id == self.tx_id => {
+                info!(
+                    target: LOG_TARGET,
+                    "Transaction Broadcast protocol (TxId: {}) rebroadcast requested", self.tx_id
+                );
+                self.mode = TxBroadcastMode::TransactionSubmission;
+                true
+            },
+            Ok(_) => false,
+            Err(e) => {
+                trace!(
+                    target: LOG_TARGET,
+                    "Transaction Broadcast protocol (TxId: {}) event 'rebroadcast' triggered with error: {:?}",
+                    self.tx_id,
+                    e,
+                );
+                false
+            },
+        }
+    }
+
     /// Attempt to submit the transaction to the base node via RPC.
     /// # Returns:
     /// `Ok(true)` => Transaction was successfully submitted to UnconfirmedPool
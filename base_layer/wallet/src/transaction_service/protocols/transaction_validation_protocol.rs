@@ -105,9 +105,9 @@ where TBackend: TransactionBackend + 'static
 
         let mut shutdown = self.resources.shutdown_signal.clone();
 
-        let total_retries_str = match self.retry_strategy {
-            ValidationRetryStrategy::Limited(n) => format!("{}", n),
-            ValidationRetryStrategy::UntilSuccess => "∞".to_string(),
+        let total_retries_str = match self.retry_strategy.max_retries() {
+            Some(n) => format!("{}", n),
+            None => "∞".to_string(),
         };
 
         info!(
@@ -123,7 +123,7 @@ where TBackend: TransactionBackend + 'static
 
         // Main protocol loop
         'main: loop {
-            if let ValidationRetryStrategy::Limited(max_retries) = self.retry_strategy {
+            if let Some(max_retries) = self.retry_strategy.max_retries() {
                 if retries > max_retries {
                     info!(
                         target: LOG_TARGET,
@@ -152,6 +152,19 @@ where TBackend: TransactionBackend + 'static
                     ));
                 }
             }
+            if let Some(backoff) = self.retry_strategy.backoff_delay(retries) {
+                debug!(
+                    target: LOG_TARGET,
+                    "Transaction Validation Protocol (Id: {}) backing off for {:.2?} before retry {}", self.id, backoff, retries
+                );
+                futures::select! {
+                    _ = delay_for(backoff).fuse() => {},
+                    _ = shutdown => {
+                        info!(target: LOG_TARGET, "Transaction Validation Protocol (Id: {}) shutting down because it received the shutdown signal", self.id);
+                        return Err(TransactionServiceProtocolError::new(self.id, TransactionServiceError::Shutdown));
+                    },
+                }
+            }
             // Assume base node is synced until we achieve a connection and it tells us it is not synced
             self.base_node_synced = true;
 
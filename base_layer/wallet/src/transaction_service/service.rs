@@ -83,7 +83,12 @@ use tari_core::{
         ReceiverTransactionProtocol,
     },
 };
-use tari_crypto::{keys::DiffieHellmanSharedSecret, script, tari_utilities::ByteArray};
+use tari_crypto::{
+    keys::DiffieHellmanSharedSecret,
+    range_proof::REWIND_USER_MESSAGE_LENGTH,
+    script,
+    tari_utilities::ByteArray,
+};
 use tari_p2p::domain_message::DomainMessage;
 use tari_service_framework::{reply_channel, reply_channel::Receiver};
 use tari_shutdown::ShutdownSignal;
@@ -137,6 +142,7 @@ pub struct TransactionService<
     active_coinbase_monitoring_protocols: HashSet<u64>,
     timeout_update_publisher: broadcast::Sender<Duration>,
     base_node_update_publisher: broadcast::Sender<CommsPublicKey>,
+    rebroadcast_publisher: broadcast::Sender<TxId>,
     power_mode: PowerMode,
 }
 
@@ -187,6 +193,7 @@ where
         };
         let (timeout_update_publisher, _) = broadcast::channel(20);
         let (base_node_update_publisher, _) = broadcast::channel(20);
+        let (rebroadcast_publisher, _) = broadcast::channel(20);
 
         TransactionService {
             config,
@@ -211,6 +218,7 @@ where
             active_coinbase_monitoring_protocols: HashSet::new(),
             timeout_update_publisher,
             base_node_update_publisher,
+            rebroadcast_publisher,
             power_mode: PowerMode::Normal,
         }
     }
@@ -524,12 +532,19 @@ where
                 )
                 .await
                 .map(TransactionServiceResponse::TransactionSent),
-            TransactionServiceRequest::SendOneSidedTransaction(dest_pubkey, amount, fee_per_gram, message) => self
+            TransactionServiceRequest::SendOneSidedTransaction(
+                dest_pubkey,
+                amount,
+                fee_per_gram,
+                message,
+                payment_id,
+            ) => self
                 .send_one_sided_transaction(
                     dest_pubkey,
                     amount,
                     fee_per_gram,
                     message,
+                    payment_id,
                     transaction_broadcast_join_handles,
                 )
                 .await
@@ -649,6 +664,10 @@ where
                 .restart_broadcast_protocols(transaction_broadcast_join_handles, coinbase_monitoring_join_handles)
                 .await
                 .map(|_| TransactionServiceResponse::ProtocolsRestarted),
+            TransactionServiceRequest::RebroadcastTransaction(tx_id) => self
+                .rebroadcast_transaction(tx_id, transaction_broadcast_join_handles)
+                .await
+                .map(|_| TransactionServiceResponse::TransactionRebroadcast),
             TransactionServiceRequest::GetNumConfirmationsRequired => Ok(
                 TransactionServiceResponse::NumConfirmationsRequired(self.resources.config.num_confirmations_required),
             ),
@@ -763,6 +782,7 @@ where
         amount: MicroTari,
         fee_per_gram: MicroTari,
         message: String,
+        payment_id: Option<Vec<u8>>,
         transaction_broadcast_join_handles: &mut FuturesUnordered<
             JoinHandle<Result<u64, TransactionServiceProtocolError>>,
         >,
@@ -774,6 +794,19 @@ where
             ));
         }
 
+        // The payment id is carried in the rewindable range proof message of the recipient's output
+        let mut proof_message = [0u8; REWIND_USER_MESSAGE_LENGTH];
+        if let Some(payment_id) = payment_id {
+            if payment_id.len() > REWIND_USER_MESSAGE_LENGTH {
+                return Err(TransactionServiceError::OneSidedTransactionError(format!(
+                    "Payment id is {} bytes long, the maximum is {} bytes",
+                    payment_id.len(),
+                    REWIND_USER_MESSAGE_LENGTH
+                )));
+            }
+            proof_message[..payment_id.len()].copy_from_slice(&payment_id);
+        }
+
         // Prepare sender part of the transaction
 
         let mut stp = self
@@ -819,7 +852,7 @@ where
         let rewind_data = RewindData {
             rewind_key: rewind_key.clone(),
             rewind_blinding_key: blinding_key.clone(),
-            proof_message: [0u8; 21],
+            proof_message,
         };
 
         let rtp = ReceiverTransactionProtocol::new_with_rewindable_output(
@@ -1575,6 +1608,32 @@ where
         Ok(())
     }
 
+    /// Resubmit a single (non-cancelled) completed transaction to the base node, for instance if it appears to be stuck
+    /// in the mempool.
+    async fn rebroadcast_transaction(
+        &mut self,
+        tx_id: TxId,
+        join_handles: &mut FuturesUnordered<JoinHandle<Result<u64, TransactionServiceProtocolError>>>,
+    ) -> Result<(), TransactionServiceError> {
+        let completed_tx = self.db.get_completed_transaction(tx_id).await?;
+        if !completed_tx.valid {
+            return Err(TransactionServiceError::InvalidCompletedTransaction);
+        }
+        debug!(target: LOG_TARGET, "Rebroadcasting transaction (TxId: {})", tx_id);
+        if self.active_transaction_broadcast_protocols.contains(&tx_id) {
+            // The running protocol will resubmit the transaction on its next pass
+            if let Err(e) = self.rebroadcast_publisher.send(tx_id) {
+                trace!(
+                    target: LOG_TARGET,
+                    "No broadcast protocols subscribed to the rebroadcast request: {:?}", e
+                );
+            }
+            Ok(())
+        } else {
+            self.broadcast_completed_transaction(completed_tx, join_handles).await
+        }
+    }
+
     /// Start to protocol to Broadcast the specified Completed Transaction to the Base Node.
     async fn broadcast_completed_transaction(
         &mut self,
@@ -1605,6 +1664,7 @@ where
                         pk,
                         self.timeout_update_publisher.subscribe(),
                         self.base_node_update_publisher.subscribe(),
+                        self.rebroadcast_publisher.subscribe(),
                     );
                     let join_handle = tokio::spawn(protocol.execute());
                     join_handles.push(join_handle);
@@ -20,6 +20,7 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::{cmp, time::Duration};
 use tari_core::transactions::tari_amount::MicroTari;
 use tari_crypto::common::Blake256;
 
@@ -33,8 +34,59 @@ pub type KeyDigest = Blake256;
 /// Specify the Hash function used when constructing challenges during transaction building
 pub type HashDigest = Blake256;
 
-#[derive(Debug)]
+/// The longest time a `ValidationRetryStrategy::LimitedWithBackoff` waits between retries
+pub const MAX_VALIDATION_RETRY_BACKOFF: Duration = Duration::from_secs(300);
+
+#[derive(Debug, Clone, Copy)]
 pub enum ValidationRetryStrategy {
     Limited(u8),
     UntilSuccess,
+    /// Retry up to the given number of times, waiting before each retry. The wait starts at the given duration and
+    /// doubles with every retry, up to `MAX_VALIDATION_RETRY_BACKOFF`.
+    LimitedWithBackoff(u8, Duration),
+}
+
+impl ValidationRetryStrategy {
+    /// The maximum number of retries, or None if validation is retried until it succeeds
+    pub fn max_retries(&self) -> Option<u8> {
+        match self {
+            ValidationRetryStrategy::Limited(n) | ValidationRetryStrategy::LimitedWithBackoff(n, _) => Some(*n),
+            ValidationRetryStrategy::UntilSuccess => None,
+        }
+    }
+
+    /// The time to wait before making retry number `retries`, or None if the retry can be made immediately
+    pub fn backoff_delay(&self, retries: u8) -> Option<Duration> {
+        match self {
+            ValidationRetryStrategy::LimitedWithBackoff(_, initial_delay) if retries > 0 => {
+                let factor = 1u32.checked_shl(u32::from(retries - 1)).unwrap_or(u32::MAX);
+                let delay = initial_delay
+                    .checked_mul(factor)
+                    .unwrap_or(MAX_VALIDATION_RETRY_BACKOFF);
+                Some(cmp::min(delay, MAX_VALIDATION_RETRY_BACKOFF))
+            },
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_doubles_up_to_the_maximum() {
+        let strategy = ValidationRetryStrategy::LimitedWithBackoff(10, Duration::from_secs(5));
+        assert_eq!(strategy.max_retries(), Some(10));
+        assert_eq!(strategy.backoff_delay(0), None);
+        assert_eq!(strategy.backoff_delay(1), Some(Duration::from_secs(5)));
+        assert_eq!(strategy.backoff_delay(2), Some(Duration::from_secs(10)));
+        assert_eq!(strategy.backoff_delay(4), Some(Duration::from_secs(40)));
+        assert_eq!(strategy.backoff_delay(7), Some(MAX_VALIDATION_RETRY_BACKOFF));
+        assert_eq!(strategy.backoff_delay(u8::MAX), Some(MAX_VALIDATION_RETRY_BACKOFF));
+
+        assert_eq!(ValidationRetryStrategy::Limited(3).backoff_delay(2), None);
+        assert_eq!(ValidationRetryStrategy::UntilSuccess.max_retries(), None);
+        assert_eq!(ValidationRetryStrategy::UntilSuccess.backoff_delay(2), None);
+    }
 }
@@ -471,11 +471,16 @@ where TBackend: WalletBackend + 'static
                 .scan_outputs_for_one_sided_payments(outputs.clone())
                 .await?
                 .into_iter()
-                .map(|v| {
-                    (
-                        v,
-                        format!("Detected one-sided transaction on {}.", Utc::now().naive_utc()),
-                    )
+                .map(|(v, payment_id)| {
+                    let message = match payment_id {
+                        Some(payment_id) => format!(
+                            "Detected one-sided transaction with payment id {} on {}.",
+                            payment_id.to_hex(),
+                            Utc::now().naive_utc()
+                        ),
+                        None => format!("Detected one-sided transaction on {}.", Utc::now().naive_utc()),
+                    };
+                    (v, message)
                 })
                 .collect(),
         );
@@ -35,10 +35,10 @@ use tari_core::transactions::{
     transaction::OutputFeatures,
     types::{CryptoFactories, PrivateKey},
 };
-use tari_crypto::{commitment::HomomorphicCommitmentFactory, keys::SecretKey, script::TariScript};
+use tari_crypto::{commitment::HomomorphicCommitmentFactory, keys::SecretKey, script, script::TariScript};
 use tari_wallet::output_manager_service::{
     error::OutputManagerStorageError,
-    service::Balance,
+    service::{Balance, BalanceByOutputType},
     storage::{
         database::{KeyManagerState, OutputManagerBackend, OutputManagerDatabase, PendingTransactionOutputs},
         models::DbUnblindedOutput,
@@ -510,6 +510,52 @@ pub async fn test_short_term_encumberance() {
     );
 }
 
+#[tokio_macros::test]
+pub async fn test_balance_by_output_type() {
+    let factories = CryptoFactories::default();
+    let (connection, _tempdir) = get_temp_sqlite_database_connection();
+    let backend = OutputManagerSqliteDatabase::new(connection, None);
+    let db = OutputManagerDatabase::new(backend);
+
+    let outputs = vec![
+        create_unblinded_output(
+            script!(Nop),
+            OutputFeatures::default(),
+            TestParams::new(),
+            MicroTari::from(1000),
+        ),
+        create_unblinded_output(
+            script!(Nop),
+            OutputFeatures::default(),
+            TestParams::new(),
+            MicroTari::from(2000),
+        ),
+        create_unblinded_output(
+            script!(Nop),
+            OutputFeatures::create_coinbase(10),
+            TestParams::new(),
+            MicroTari::from(5000),
+        ),
+        create_unblinded_output(
+            script!(Nop Nop),
+            OutputFeatures::default(),
+            TestParams::new(),
+            MicroTari::from(300),
+        ),
+    ];
+    for output in outputs {
+        let output = DbUnblindedOutput::from_unblinded_output(output, &factories).unwrap();
+        db.add_unspent_output(output).await.unwrap();
+    }
+
+    let balance = db.get_balance_by_output_type().await.unwrap();
+    assert_eq!(balance, BalanceByOutputType {
+        normal: MicroTari::from(3000),
+        coinbase: MicroTari::from(5000),
+        scripted: MicroTari::from(300),
+    });
+}
+
 #[tokio_macros::test]
 pub async fn test_no_duplicate_outputs() {
     let factories = CryptoFactories::default();
@@ -43,6 +43,7 @@ use tari_core::{
             TxQueryResponse as TxQueryResponseProto,
             TxSubmissionResponse as TxSubmissionResponseProto,
         },
+        mempool::StatsResponse as StatsResponseProto,
         types::{
             Signature as SignatureProto,
             Transaction as TransactionProto,
@@ -83,6 +84,7 @@ pub struct BaseNodeWalletRpcMockState {
     submit_transaction_response: Arc<Mutex<TxSubmissionResponse>>,
     transaction_query_response: Arc<Mutex<TxQueryResponse>>,
     tip_info_response: Arc<Mutex<TipInfoResponse>>,
+    mempool_stats_response: Arc<Mutex<StatsResponseProto>>,
     fetch_utxos_calls: Arc<Mutex<Vec<Vec<Vec<u8>>>>>,
     response_delay: Arc<Mutex<Option<Duration>>>,
     rpc_status_error: Arc<Mutex<Option<RpcStatus>>>,
@@ -118,6 +120,7 @@ impl BaseNodeWalletRpcMockState {
                 }),
                 is_synced: true,
             })),
+            mempool_stats_response: Arc::new(Mutex::new(StatsResponseProto::default())),
             fetch_utxos_calls: Arc::new(Mutex::new(Vec::new())),
             response_delay: Arc::new(Mutex::new(None)),
             rpc_status_error: Arc::new(Mutex::new(None)),
@@ -131,6 +134,11 @@ impl BaseNodeWalletRpcMockState {
         *lock = response;
     }
 
+    pub fn set_mempool_stats_response(&self, response: StatsResponseProto) {
+        let mut lock = acquire_lock!(self.mempool_stats_response);
+        *lock = response;
+    }
+
     pub fn set_submit_transaction_response(&self, response: TxSubmissionResponse) {
         let mut lock = acquire_lock!(self.submit_transaction_response);
         *lock = response;
@@ -458,6 +466,24 @@ impl BaseNodeWalletService for BaseNodeWalletRpcMockService {
 
         Ok(Response::new(tip_info_response_lock.clone()))
     }
+
+    async fn get_mempool_stats(&self, _request: Request<()>) -> Result<Response<StatsResponseProto>, RpcStatus> {
+        let delay_lock = *acquire_lock!(self.state.response_delay);
+        if let Some(delay) = delay_lock {
+            delay_for(delay).await;
+        }
+
+        log::info!("Get mempool stats call received");
+
+        let status_lock = acquire_lock!(self.state.rpc_status_error);
+        if let Some(status) = (*status_lock).clone() {
+            return Err(status);
+        }
+
+        let mempool_stats_response_lock = acquire_lock!(self.state.mempool_stats_response);
+
+        Ok(Response::new(mempool_stats_response_lock.clone()))
+    }
 }
 
 #[cfg(test)]
@@ -902,14 +902,17 @@ fn recover_one_sided_transaction() {
 
     let message = "".to_string();
     let value = 1000.into();
+    let payment_id = b"invoice-42".to_vec();
     let mut alice_ts_clone = alice_ts.clone();
+    let sent_payment_id = payment_id.clone();
     let tx_id = runtime.block_on(async move {
         alice_ts_clone
-            .send_one_sided_transaction(
+            .send_one_sided_transaction_with_payment_id(
                 bob_node_identity.public_key().clone(),
                 value,
                 20.into(),
                 message.clone(),
+                Some(sent_payment_id),
             )
             .await
             .expect("Alice sending one-sided tx to Bob")
@@ -926,9 +929,10 @@ fn recover_one_sided_transaction() {
             .scan_outputs_for_one_sided_payments(outputs.clone())
             .await
             .unwrap();
-        // Bob should be able to claim 1 output.
+        // Bob should be able to claim 1 output, along with the payment id that Alice attached to it.
         assert_eq!(1, unblinded.len());
-        assert_eq!(value, unblinded[0].value);
+        assert_eq!(value, unblinded[0].0.value);
+        assert_eq!(Some(payment_id), unblinded[0].1);
 
         // Should ignore already existing outputs
         let unblinded = bob_oms.scan_outputs_for_one_sided_payments(outputs).await.unwrap();
@@ -1012,6 +1016,60 @@ fn send_one_sided_transaction_to_self() {
     });
 }
 
+#[test]
+fn send_one_sided_transaction_with_long_payment_id() {
+    let mut runtime = create_runtime();
+
+    let factories = CryptoFactories::default();
+    let alice_node_identity = Arc::new(NodeIdentity::random(
+        &mut OsRng,
+        get_next_memory_address(),
+        PeerFeatures::COMMUNICATION_NODE,
+    ));
+    let bob_node_identity = Arc::new(NodeIdentity::random(
+        &mut OsRng,
+        get_next_memory_address(),
+        PeerFeatures::COMMUNICATION_NODE,
+    ));
+
+    let temp_dir = tempdir().unwrap();
+    let database_path = temp_dir.path().to_str().unwrap().to_string();
+
+    let (alice_wallet_backend, alice_backend, alice_oms_backend, _, _tempdir) =
+        make_wallet_databases(Some(database_path.clone()));
+
+    let shutdown = Shutdown::new();
+    let (alice_ts, _alice_oms, _alice_comms) = setup_transaction_service(
+        &mut runtime,
+        alice_node_identity,
+        vec![],
+        factories,
+        alice_wallet_backend,
+        alice_backend,
+        alice_oms_backend,
+        database_path,
+        Duration::from_secs(0),
+        shutdown.to_signal(),
+    );
+
+    let mut alice_ts_clone = alice_ts;
+    runtime.block_on(async move {
+        match alice_ts_clone
+            .send_one_sided_transaction_with_payment_id(
+                bob_node_identity.public_key().clone(),
+                1000.into(),
+                20.into(),
+                "".to_string(),
+                Some(vec![1u8; 22]),
+            )
+            .await
+        {
+            Err(TransactionServiceError::OneSidedTransactionError(_)) => {},
+            _ => panic!("Expected an overly long payment id to be rejected"),
+        };
+    });
+}
+
 #[test]
 fn manage_multiple_transactions() {
     let mut runtime = create_runtime();
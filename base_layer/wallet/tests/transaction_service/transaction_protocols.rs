@@ -247,6 +247,7 @@ async fn tx_broadcast_protocol_submit_success_i() {
     ) = setup(TxProtocolTestConfig::WithConnection).await;
     let mut event_stream = resources.event_publisher.subscribe().fuse();
     let (base_node_update_publisher, _) = broadcast::channel(20);
+    let (rebroadcast_publisher, _) = broadcast::channel(20);
 
     let protocol = TransactionBroadcastProtocol::new(
         2,
@@ -255,6 +256,7 @@ async fn tx_broadcast_protocol_submit_success_i() {
         server_node_identity.public_key().clone(),
         timeout_update_publisher.subscribe(),
         base_node_update_publisher.subscribe(),
+        rebroadcast_publisher.subscribe(),
     );
     let join_handle = task::spawn(protocol.execute());
 
@@ -273,6 +275,7 @@ async fn tx_broadcast_protocol_submit_success_i() {
         server_node_identity.public_key().clone(),
         timeout_update_publisher.subscribe(),
         base_node_update_publisher.subscribe(),
+        rebroadcast_publisher.subscribe(),
     );
 
     let join_handle = task::spawn(protocol.execute());
@@ -443,6 +446,7 @@ async fn tx_broadcast_protocol_submit_rejection() {
     ) = setup(TxProtocolTestConfig::WithConnection).await;
     let mut event_stream = resources.event_publisher.subscribe().fuse();
     let (base_node_update_publisher, _) = broadcast::channel(20);
+    let (rebroadcast_publisher, _) = broadcast::channel(20);
 
     add_transaction_to_database(1, 1 * T, true, None, resources.db.clone()).await;
 
@@ -453,6 +457,7 @@ async fn tx_broadcast_protocol_submit_rejection() {
         server_node_identity.public_key().clone(),
         timeout_update_publisher.subscribe(),
         base_node_update_publisher.subscribe(),
+        rebroadcast_publisher.subscribe(),
     );
 
     rpc_service_state.set_submit_transaction_response(TxSubmissionResponse {
@@ -514,6 +519,7 @@ async fn tx_broadcast_protocol_restart_protocol_as_query() {
         _transaction_event_receiver,
     ) = setup(TxProtocolTestConfig::WithConnection).await;
     let (base_node_update_publisher, _) = broadcast::channel(20);
+    let (rebroadcast_publisher, _) = broadcast::channel(20);
 
     add_transaction_to_database(1, 1 * T, true, None, resources.db.clone()).await;
 
@@ -533,6 +539,7 @@ async fn tx_broadcast_protocol_restart_protocol_as_query() {
         server_node_identity.public_key().clone(),
         timeout_update_publisher.subscribe(),
         base_node_update_publisher.subscribe(),
+        rebroadcast_publisher.subscribe(),
     );
 
     let join_handle = task::spawn(protocol.execute());
@@ -602,6 +609,7 @@ async fn tx_broadcast_protocol_submit_success_followed_by_rejection() {
     ) = setup(TxProtocolTestConfig::WithConnection).await;
     let mut event_stream = resources.event_publisher.subscribe().fuse();
     let (base_node_update_publisher, _) = broadcast::channel(20);
+    let (rebroadcast_publisher, _) = broadcast::channel(20);
 
     add_transaction_to_database(1, 1 * T, true, None, resources.db.clone()).await;
 
@@ -612,6 +620,7 @@ async fn tx_broadcast_protocol_submit_success_followed_by_rejection() {
         server_node_identity.public_key().clone(),
         timeout_update_publisher.subscribe(),
         base_node_update_publisher.subscribe(),
+        rebroadcast_publisher.subscribe(),
     );
 
     let join_handle = task::spawn(protocol.execute());
@@ -702,6 +711,7 @@ async fn tx_broadcast_protocol_submit_mined_then_not_mined_resubmit_success() {
         mut transaction_event_receiver,
     ) = setup(TxProtocolTestConfig::WithConnection).await;
     let (base_node_update_publisher, _) = broadcast::channel(20);
+    let (rebroadcast_publisher, _) = broadcast::channel(20);
 
     add_transaction_to_database(1, 1 * T, true, None, resources.db.clone()).await;
 
@@ -712,6 +722,7 @@ async fn tx_broadcast_protocol_submit_mined_then_not_mined_resubmit_success() {
         server_node_identity.public_key().clone(),
         timeout_update_publisher.subscribe(),
         base_node_update_publisher.subscribe(),
+        rebroadcast_publisher.subscribe(),
     );
 
     let join_handle = task::spawn(protocol.execute());
@@ -822,6 +833,7 @@ async fn tx_broadcast_protocol_connection_problem() {
         _transaction_event_receiver,
     ) = setup(TxProtocolTestConfig::WithoutConnection).await;
     let (base_node_update_publisher, _) = broadcast::channel(20);
+    let (rebroadcast_publisher, _) = broadcast::channel(20);
 
     let mut event_stream = resources.event_publisher.subscribe().fuse();
 
@@ -834,6 +846,7 @@ async fn tx_broadcast_protocol_connection_problem() {
         server_node_identity.public_key().clone(),
         timeout_update_publisher.subscribe(),
         base_node_update_publisher.subscribe(),
+        rebroadcast_publisher.subscribe(),
     );
 
     let join_handle = task::spawn(protocol.execute());
@@ -894,6 +907,7 @@ async fn tx_broadcast_protocol_submit_already_mined() {
         _transaction_event_receiver,
     ) = setup(TxProtocolTestConfig::WithConnection).await;
     let (base_node_update_publisher, _) = broadcast::channel(20);
+    let (rebroadcast_publisher, _) = broadcast::channel(20);
 
     add_transaction_to_database(1, 1 * T, true, None, resources.db.clone()).await;
 
@@ -911,6 +925,7 @@ async fn tx_broadcast_protocol_submit_already_mined() {
         server_node_identity.public_key().clone(),
         timeout_update_publisher.subscribe(),
         base_node_update_publisher.subscribe(),
+        rebroadcast_publisher.subscribe(),
     );
 
     let join_handle = task::spawn(protocol.execute());
@@ -964,6 +979,7 @@ async fn tx_broadcast_protocol_submit_and_base_node_gets_changed() {
         _transaction_event_receiver,
     ) = setup(TxProtocolTestConfig::WithConnection).await;
     let (base_node_update_publisher, _) = broadcast::channel(20);
+    let (rebroadcast_publisher, _) = broadcast::channel(20);
 
     add_transaction_to_database(1, 1 * T, true, None, resources.db.clone()).await;
 
@@ -974,6 +990,7 @@ async fn tx_broadcast_protocol_submit_and_base_node_gets_changed() {
         server_node_identity.public_key().clone(),
         timeout_update_publisher.subscribe(),
         base_node_update_publisher.subscribe(),
+        rebroadcast_publisher.subscribe(),
     );
 
     let join_handle = task::spawn(protocol.execute());
@@ -1048,6 +1065,75 @@ async fn tx_broadcast_protocol_submit_and_base_node_gets_changed() {
     assert_eq!(db_completed_tx.status, TransactionStatus::MinedConfirmed);
 }
 
+/// A test to see that a running broadcast protocol resubmits its transaction when a rebroadcast is requested
+#[tokio_macros::test]
+#[allow(clippy::identity_op)]
+async fn tx_broadcast_protocol_rebroadcast_resubmits() {
+    let (
+        resources,
+        _connectivity_mock_state,
+        _outbound_mock_state,
+        _mock_rpc_server,
+        server_node_identity,
+        rpc_service_state,
+        timeout_update_publisher,
+        _shutdown,
+        _temp_dir,
+        _transaction_event_receiver,
+    ) = setup(TxProtocolTestConfig::WithConnection).await;
+    let (base_node_update_publisher, _) = broadcast::channel(20);
+    let (rebroadcast_publisher, _) = broadcast::channel(20);
+
+    add_transaction_to_database(1, 1 * T, true, None, resources.db.clone()).await;
+
+    let protocol = TransactionBroadcastProtocol::new(
+        1,
+        resources.clone(),
+        Duration::from_secs(1),
+        server_node_identity.public_key().clone(),
+        timeout_update_publisher.subscribe(),
+        base_node_update_publisher.subscribe(),
+        rebroadcast_publisher.subscribe(),
+    );
+
+    let join_handle = task::spawn(protocol.execute());
+
+    // Accepted in the mempool, after which the protocol only queries the transaction
+    let _ = rpc_service_state
+        .wait_pop_submit_transaction_calls(1, Duration::from_secs(5))
+        .await
+        .unwrap();
+    let _ = rpc_service_state
+        .wait_pop_transaction_query_calls(1, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    // A rebroadcast request for another transaction is ignored
+    rebroadcast_publisher.send(2).unwrap();
+    assert!(rpc_service_state
+        .wait_pop_submit_transaction_calls(1, Duration::from_secs(3))
+        .await
+        .is_err());
+
+    rebroadcast_publisher.send(1).unwrap();
+    let _ = rpc_service_state
+        .wait_pop_submit_transaction_calls(1, Duration::from_secs(5))
+        .await
+        .unwrap();
+
+    // Set base node response to mined and confirmed
+    rpc_service_state.set_transaction_query_response(TxQueryResponse {
+        location: TxLocation::Mined,
+        block_hash: None,
+        confirmations: resources.config.num_confirmations_required,
+        is_synced: true,
+        height_of_longest_chain: 0,
+    });
+
+    let result = join_handle.await.unwrap();
+    assert_eq!(result.unwrap(), 1);
+}
+
 /// Validate completed transactions, will check that valid ones stay valid and incorrectly marked invalid tx become
 /// valid.
 #[tokio_macros::test]
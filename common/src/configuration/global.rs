@@ -70,6 +70,10 @@ pub struct GlobalConfig {
     pub orphan_db_clean_out_threshold: usize,
     pub pruning_horizon: u64,
     pub pruned_mode_cleanup_interval: u64,
+    pub mempool_min_fee_per_gram: u64,
+    pub max_reorg_depth: Option<u64>,
+    pub empty_template_on_mempool_error: bool,
+    pub block_propagation_fanout: Option<usize>,
     pub core_threads: Option<usize>,
     pub max_threads: Option<usize>,
     pub base_node_identity_file: PathBuf,
@@ -77,6 +81,10 @@ pub struct GlobalConfig {
     pub grpc_enabled: bool,
     pub grpc_base_node_address: SocketAddr,
     pub grpc_console_wallet_address: SocketAddr,
+    pub grpc_get_difficulty_page_size: Option<usize>,
+    pub grpc_list_headers_page_size: Option<usize>,
+    pub grpc_get_blocks_page_size: Option<usize>,
+    pub grpc_max_concurrent_streams: Option<usize>,
     pub peer_seeds: Vec<String>,
     pub dns_seeds: Vec<String>,
     pub dns_seeds_name_server: SocketAddr,
@@ -111,9 +119,13 @@ pub struct GlobalConfig {
     pub console_wallet_password: Option<String>,
     pub wallet_command_send_wait_stage: String,
     pub wallet_command_send_wait_timeout: u64,
+    pub wallet_command_comms_wait_timeout: u64,
+    pub wallet_command_wait_for_base_node: bool,
     pub wallet_base_node_service_peers: Vec<String>,
     pub wallet_base_node_service_refresh_interval: u64,
     pub wallet_base_node_service_request_max_age: u64,
+    pub wallet_validation_retry_limit: u8,
+    pub wallet_validation_retry_backoff: u64,
     pub prevent_fee_gt_amount: bool,
     pub monerod_url: String,
     pub monerod_username: String,
@@ -258,6 +270,35 @@ fn convert_node_config(
         .get_int(&key)
         .map_err(|e| ConfigurationError::new(&key, &e.to_string()))? as u64;
 
+    let key = config_string("base_node", &net_str, "mempool_min_fee_per_gram");
+    let mempool_min_fee_per_gram = optional(cfg.get_int(&key))
+        .map_err(|e| ConfigurationError::new(&key, &e.to_string()))?
+        .map(|n| n as u64)
+        .unwrap_or(0);
+
+    let key = config_string("base_node", &net_str, "max_reorg_depth");
+    let max_reorg_depth = optional(cfg.get_int(&key))
+        .map_err(|e| ConfigurationError::new(&key, &e.to_string()))?
+        .map(|n| n as u64);
+
+    let key = config_string("base_node", &net_str, "empty_template_on_mempool_error");
+    let empty_template_on_mempool_error = optional(cfg.get_bool(&key))
+        .map_err(|e| ConfigurationError::new(&key, &e.to_string()))?
+        .unwrap_or(true);
+
+    let key = config_string("base_node", &net_str, "block_propagation_fanout");
+    let block_propagation_fanout =
+        match optional(cfg.get_int(&key)).map_err(|e| ConfigurationError::new(&key, &e.to_string()))? {
+            Some(n) if n <= 0 => {
+                return Err(ConfigurationError::new(
+                    &key,
+                    "The block propagation fanout must be greater than zero",
+                ))
+            },
+            Some(n) => Some(n as usize),
+            None => None,
+        };
+
     // Thread counts
     let key = config_string("base_node", &net_str, "core_threads");
     let core_threads =
@@ -350,6 +391,25 @@ fn convert_node_config(
                 .map_err(|e| ConfigurationError::new(&key, &e.to_string()))
         })?;
 
+    let key = config_string("base_node", &net_str, "grpc_get_difficulty_page_size");
+    let grpc_get_difficulty_page_size = optional_page_size(&cfg, &key)?;
+    let key = config_string("base_node", &net_str, "grpc_list_headers_page_size");
+    let grpc_list_headers_page_size = optional_page_size(&cfg, &key)?;
+    let key = config_string("base_node", &net_str, "grpc_get_blocks_page_size");
+    let grpc_get_blocks_page_size = optional_page_size(&cfg, &key)?;
+    let key = config_string("base_node", &net_str, "grpc_max_concurrent_streams");
+    let grpc_max_concurrent_streams =
+        match optional(cfg.get_int(&key)).map_err(|e| ConfigurationError::new(&key, &e.to_string()))? {
+            Some(n) if n <= 0 => {
+                return Err(ConfigurationError::new(
+                    &key,
+                    "The maximum number of concurrent gRPC streams must be greater than zero",
+                ))
+            },
+            Some(n) => Some(n as usize),
+            None => None,
+        };
+
     // Peer and DNS seeds
     let key = config_string("base_node", &net_str, "peer_seeds");
     // Peer seeds can be an array or a comma separated list (e.g. in an ENVVAR)
@@ -488,6 +548,12 @@ fn convert_node_config(
     let key = "wallet.command_send_wait_timeout";
     let wallet_command_send_wait_timeout = optional(cfg.get_int(key))?.map(|i| i as u64).unwrap_or(600);
 
+    let key = "wallet.command_comms_wait_timeout";
+    let wallet_command_comms_wait_timeout = optional(cfg.get_int(key))?.map(|i| i as u64).unwrap_or(30);
+
+    let key = "wallet.command_wait_for_base_node";
+    let wallet_command_wait_for_base_node = optional(cfg.get_bool(key))?.unwrap_or(false);
+
     let key = "wallet.base_node_service_peers";
     // Wallet base node service peers can be an array or a comma separated list (e.g. in an ENVVAR)
     let wallet_base_node_service_peers = match cfg.get_array(&key) {
@@ -518,6 +584,24 @@ fn convert_node_config(
         Err(e) => return Err(ConfigurationError::new(&key, &e.to_string())),
     };
 
+    let key = "wallet.validation_retry_limit";
+    let wallet_validation_retry_limit = match cfg.get_int(key) {
+        Ok(retries) => retries
+            .try_into()
+            .map_err(|e: TryFromIntError| ConfigurationError::new(&key, &e.to_string()))?,
+        Err(ConfigError::NotFound(_)) => 10,
+        Err(e) => return Err(ConfigurationError::new(&key, &e.to_string())),
+    };
+
+    let key = "wallet.validation_retry_backoff";
+    let wallet_validation_retry_backoff = match cfg.get_int(key) {
+        Ok(seconds) => seconds
+            .try_into()
+            .map_err(|e: TryFromIntError| ConfigurationError::new(&key, &e.to_string()))?,
+        Err(ConfigError::NotFound(_)) => 5,
+        Err(e) => return Err(ConfigurationError::new(&key, &e.to_string())),
+    };
+
     let key = "common.liveness_max_sessions";
     let liveness_max_sessions = cfg
         .get_int(key)
@@ -677,6 +761,10 @@ fn convert_node_config(
         orphan_db_clean_out_threshold,
         pruning_horizon,
         pruned_mode_cleanup_interval,
+        mempool_min_fee_per_gram,
+        max_reorg_depth,
+        empty_template_on_mempool_error,
+        block_propagation_fanout,
         core_threads,
         max_threads,
         base_node_identity_file,
@@ -684,6 +772,10 @@ fn convert_node_config(
         grpc_enabled,
         grpc_base_node_address,
         grpc_console_wallet_address,
+        grpc_get_difficulty_page_size,
+        grpc_list_headers_page_size,
+        grpc_get_blocks_page_size,
+        grpc_max_concurrent_streams,
         peer_seeds,
         dns_seeds,
         dns_seeds_name_server,
@@ -718,9 +810,13 @@ fn convert_node_config(
         console_wallet_password,
         wallet_command_send_wait_stage,
         wallet_command_send_wait_timeout,
+        wallet_command_comms_wait_timeout,
+        wallet_command_wait_for_base_node,
         wallet_base_node_service_peers,
         wallet_base_node_service_refresh_interval,
         wallet_base_node_service_request_max_age,
+        wallet_validation_retry_limit,
+        wallet_validation_retry_backoff,
         prevent_fee_gt_amount,
         proxy_host_address,
         proxy_submit_to_origin,
@@ -749,6 +845,15 @@ fn optional<T>(result: Result<T, ConfigError>) -> Result<Option<T>, ConfigError>
     }
 }
 
+/// Reads an optional page size, which must be greater than zero if it is set
+fn optional_page_size(cfg: &Config, key: &str) -> Result<Option<usize>, ConfigurationError> {
+    match optional(cfg.get_int(key)).map_err(|e| ConfigurationError::new(key, &e.to_string()))? {
+        Some(n) if n <= 0 => Err(ConfigurationError::new(key, "Page size must be greater than zero")),
+        Some(n) => Ok(Some(n as usize)),
+        None => Ok(None),
+    }
+}
+
 fn one_of<T>(cfg: &Config, keys: &[&str]) -> Result<T, ConfigError>
 where
     T: FromStr,
@@ -27,6 +27,39 @@ use std::{
     str::FromStr,
 };
 
+/// The peer seeds for the Weatherwax testnet, in the form `public_key::address`
+const WEATHERWAX_PEER_SEEDS: &[&str] = &[
+    "9a26e910288213d649b26f9a7a7ee51fe2b2a67ff7d42334523463bf4be94312::/onion3/\
+     56kq54ylttnbl5ikotqex3oqvtzlxdpn7zlx4v56rvzf4kq7eezlclid:18141",
+    "6afd5b3c7772ad7d4bb26e0c19668fe04f2d68f99de9e132bee50a6c1846946d::/onion3/\
+     may4ajbmcn4dlnzf6fanvqlklxzqiw6qwu6ywqwkjc3bb354rc2i5wid:18141",
+    "8e7beec9becdc44fe6015a00d97a77fa3dbafe65127dcc988df6326bd9fd040d::/onion3/\
+     3pise36l4imoopsbjic5rtw67adx7rms6w5pgjmccpdwiqx66j7oqcqd:18141",
+    "80bb590d943a46e63ae79af5dc2c7d35a3dcd7922c182b28f619dc4cfc366f44::/onion3/\
+     oaxwahri7r3h5qjlcdbveyjmg4jsttausik66bicmhixft73nmvecdad:18141",
+    "981cc8cd1e4fe2f99ea1bd3e0ab1e7821ca0bfab336a4967cfec053fee86254c::/onion3/\
+     7hxpnxrxycdfevirddau7ybofwedaamjrg2ijm57k2kevh5q46ixamid:18141",
+    "f2ce179fb733725961a5f7e1e45dacdd443dd43ba6237438d6abe344fb717058::/onion3/\
+     nvgdmjf4wucgatz7vemzvi2u4sw5o4gyzwuikagpepoj4w7mkii47zid:18141",
+    "909c0160f4d8e815aba5c2bbccfcceb448877e7b38759fb160f3e9494484d515::/onion3/\
+     qw5uxv533sqdn2qoncfyqo35dgecy4rt4x27rexi2her6q6pcpxbm4qd:18141",
+    "0c3fe3c23866ed3827e1cd72aae0c9d364d860d597993104e90d9a9401e52f05::/onion3/\
+     2m2xnylrsqbaozsndkbmfisxxbwh2vgvs6oyfak2qah4snnxykrf7zad:18141",
+    "b6b2c8a997ba3500d44b64a3b47203d922d614c01ebd9cad5563dc59086f9938::/onion3/\
+     kxtcwaoan6wvdl4wltguo4omo2san2ge727cfoc65hrzuwixprvop5id:18141",
+    "4471cec2b77b1608ff26b8695281e0b4ab35bad7f882eeaab043398644094a6c::/onion3/\
+     fqmbme5qvagh2tv722nrnwwcd2ooa4aqr43mxgiozo2yq33w74bop5qd:18141",
+    "e427d6007cb46e8c311fc75d2daaa95898a6162ce6a29cb4ab257c2ef70bfd62::/onion3/\
+     glicssekriwwwvfm2o5sk2mari4czbd7eklv4lsdn4wmy4ljss2t7zqd:18141",
+    "021baaee838a6c7a21ae3ed74f1cbb27e46351b78f0e00810c1a344a9042754d::/onion3/\
+     p2z2ptouqgi3agmw62uhz2tambqfzfdtetrfw443of3jnkkiw44e2zqd:18141",
+    "002a5b9d129401ca250d120b126caf067f8eb7a616252e630b6974da48def71a::/onion3/\
+     cldbbow3yzgxkvz4g2apoclrkbvg3mjxdvonoaeej3tarhlzkluodeyd:18141",
+];
+
+/// The DNS seeds for the Weatherwax testnet
+const WEATHERWAX_DNS_SEEDS: &[&str] = &["seeds.weatherwax.tari.com"];
+
 /// Represents the available Tari p2p networks. Only nodes with matching byte values will be able to connect, so these
 /// should never be changed once released.
 #[repr(u8)]
@@ -54,6 +87,53 @@ impl Network {
             LocalNet => "localnet",
         }
     }
+
+    pub fn is_mainnet(self) -> bool {
+        self == Network::MainNet
+    }
+
+    /// Every network except MainNet is a testnet
+    pub fn is_testnet(self) -> bool {
+        !self.is_mainnet()
+    }
+
+    /// Whether nodes on this network may be configured to accept test (e.g. local and private) addresses
+    pub fn allows_test_addresses(self) -> bool {
+        self.is_testnet()
+    }
+
+    /// A human readable name for this network, for display purposes only. Use `as_str` for anything that needs to be
+    /// parsed again.
+    pub const fn display_name(self) -> &'static str {
+        use Network::*;
+        match self {
+            MainNet => "Mainnet",
+            Ridcully => "Ridcully Testnet",
+            Stibbons => "Stibbons Testnet",
+            Weatherwax => "Weatherwax Testnet",
+            LocalNet => "Local Network",
+        }
+    }
+
+    /// The canonical peer seeds for this network, in the form `public_key::address`. These allow tools to bootstrap
+    /// connectivity knowing only the network. There are no production seeds for MainNet yet, and LocalNet never has
+    /// any.
+    pub fn seed_peers(self) -> Vec<&'static str> {
+        use Network::*;
+        match self {
+            Weatherwax => WEATHERWAX_PEER_SEEDS.to_vec(),
+            MainNet | Ridcully | Stibbons | LocalNet => Vec::new(),
+        }
+    }
+
+    /// The canonical DNS seed hostnames for this network, which provide peer seeds as TXT records
+    pub fn dns_seeds(self) -> Vec<&'static str> {
+        use Network::*;
+        match self {
+            Weatherwax => WEATHERWAX_DNS_SEEDS.to_vec(),
+            MainNet | Ridcully | Stibbons | LocalNet => Vec::new(),
+        }
+    }
 }
 
 impl Default for Network {
@@ -86,3 +166,37 @@ impl Display for Network {
         f.write_str(self.as_str())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ALL_NETWORKS: [Network; 5] = [
+        Network::MainNet,
+        Network::LocalNet,
+        Network::Ridcully,
+        Network::Stibbons,
+        Network::Weatherwax,
+    ];
+
+    #[test]
+    fn from_str_only_accepts_wire_names() {
+        for network in ALL_NETWORKS.iter() {
+            assert_eq!(Network::from_str(network.as_str()).unwrap(), *network);
+            assert!(!network.display_name().is_empty());
+            assert_ne!(network.display_name(), network.as_str());
+            assert!(Network::from_str(network.display_name()).is_err());
+        }
+    }
+
+    #[test]
+    fn testnet_predicates() {
+        for network in ALL_NETWORKS.iter() {
+            assert_ne!(network.is_mainnet(), network.is_testnet());
+            assert_eq!(network.allows_test_addresses(), network.is_testnet());
+        }
+        assert!(Network::MainNet.is_mainnet());
+        assert!(!Network::MainNet.allows_test_addresses());
+        assert!(Network::LocalNet.is_testnet());
+    }
+}
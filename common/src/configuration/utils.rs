@@ -1,5 +1,5 @@
 use crate::{
-    configuration::bootstrap::ApplicationType,
+    configuration::{bootstrap::ApplicationType, Network},
     dir_utils::default_subdir,
     ConfigBootstrap,
     ConfigError,
@@ -112,6 +112,8 @@ pub fn default_config(bootstrap: &ConfigBootstrap) -> Config {
         .unwrap();
     cfg.set_default("wallet.command_send_wait_stage", "Broadcast").unwrap();
     cfg.set_default("wallet.command_send_wait_timeout", 300).unwrap();
+    cfg.set_default("wallet.command_comms_wait_timeout", 30).unwrap();
+    cfg.set_default("wallet.command_wait_for_base_node", false).unwrap();
     cfg.set_default("wallet.base_node_service_peers", Vec::<String>::new())
         .unwrap();
 
@@ -128,9 +130,9 @@ pub fn default_config(bootstrap: &ConfigBootstrap) -> Config {
     cfg.set_default("base_node.mainnet.pruning_horizon", 0).unwrap();
     cfg.set_default("base_node.mainnet.pruned_mode_cleanup_interval", 50)
         .unwrap();
-    cfg.set_default("base_node.mainnet.peer_seeds", Vec::<String>::new())
+    cfg.set_default("base_node.mainnet.peer_seeds", Network::MainNet.seed_peers())
         .unwrap();
-    cfg.set_default("base_node.mainnet.dns_seeds", Vec::<String>::new())
+    cfg.set_default("base_node.mainnet.dns_seeds", Network::MainNet.dns_seeds())
         .unwrap();
     cfg.set_default("base_node.mainnet.dns_seeds_name_server", "1.1.1.1:53")
         .unwrap();
@@ -188,7 +190,9 @@ pub fn default_config(bootstrap: &ConfigBootstrap) -> Config {
         .unwrap();
     cfg.set_default("base_node.weatherwax.flood_ban_max_msg_count", 1000)
         .unwrap();
-    cfg.set_default("base_node.weatherwax.peer_seeds", Vec::<String>::new())
+    cfg.set_default("base_node.weatherwax.peer_seeds", Network::Weatherwax.seed_peers())
+        .unwrap();
+    cfg.set_default("base_node.weatherwax.dns_seeds", Network::Weatherwax.dns_seeds())
         .unwrap();
     cfg.set_default(
         "base_node.weatherwax.data_dir",
@@ -129,6 +129,13 @@ impl CommsBuilder {
         self
     }
 
+    /// Set the lowest minor version that peers must advertise for a connection to be established. Peers with a lower
+    /// minor version are rejected.
+    pub fn with_min_compatible_minor_version(mut self, min_minor_version: u32) -> Self {
+        self.connection_manager_config.network_info.min_compatible_minor_version = min_minor_version;
+        self
+    }
+
     /// Allow test addresses (memory addresses, local loopback etc). This should only be activated for tests.
     pub fn allow_test_addresses(mut self) -> Self {
         #[cfg(not(debug_assertions))]
@@ -167,6 +174,13 @@ impl CommsBuilder {
         self
     }
 
+    /// The maximum number of inbound connections per second to accept from a single source IP address. Connections
+    /// over the limit are dropped. Disabled by default.
+    pub fn with_max_inbound_connects_per_ip_per_second(mut self, max_inbound_connects_per_second: u32) -> Self {
+        self.connection_manager_config.max_inbound_connects_per_ip_per_second = Some(max_inbound_connects_per_second);
+        self
+    }
+
     /// The number of dial attempts to make before giving up.
     pub fn with_max_dial_attempts(mut self, max_dial_attempts: usize) -> Self {
         self.connection_manager_config.max_dial_attempts = max_dial_attempts;
@@ -48,6 +48,7 @@ pub async fn perform_identity_exchange<'p, P: IntoIterator<Item = &'p ProtocolId
     our_supported_protocols: P,
     network_info: NodeNetworkInfo,
 ) -> Result<PeerIdentityMsg, ConnectionManagerError> {
+    let min_minor_version = network_info.min_compatible_minor_version;
     let mut control = muxer.get_yamux_control();
     let stream = match direction {
         ConnectionDirection::Inbound => muxer
@@ -66,6 +67,19 @@ pub async fn perform_identity_exchange<'p, P: IntoIterator<Item = &'p ProtocolId
     let peer_identity =
         protocol::identity_exchange(node_identity, direction, our_supported_protocols, network_info, stream).await?;
 
+    if peer_identity.minor < min_minor_version {
+        warn!(
+            target: LOG_TARGET,
+            "Peer sent minor protocol version '{}' which is below the minimum compatible version '{}'",
+            peer_identity.minor,
+            min_minor_version
+        );
+        return Err(ConnectionManagerError::IncompatibleVersion {
+            peer_minor_version: peer_identity.minor,
+            min_minor_version,
+        });
+    }
+
     Ok(peer_identity)
 }
 
@@ -25,6 +25,7 @@ use crate::{
     peer_manager::Peer,
 };
 use futures::channel::oneshot;
+use std::time::{Duration, Instant};
 use tari_shutdown::ShutdownSignal;
 
 /// The state of the dial request
@@ -37,6 +38,8 @@ pub struct DialState {
     cancel_signal: ShutdownSignal,
     /// Reply channel for a connection result
     pub reply_tx: oneshot::Sender<Result<PeerConnection, ConnectionManagerError>>,
+    /// The time at which this dial was started
+    started_at: Instant,
 }
 
 impl DialState {
@@ -51,6 +54,7 @@ impl DialState {
             attempts: 0,
             reply_tx,
             cancel_signal,
+            started_at: Instant::now(),
         }
     }
 
@@ -68,4 +72,9 @@ impl DialState {
     pub fn num_attempts(&self) -> usize {
         self.attempts
     }
+
+    /// The time elapsed since this dial was started
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.elapsed()
+    }
 }
@@ -190,8 +190,11 @@ where
     async fn handle_dial_result(
         &mut self,
         dial_state: DialState,
-        dial_result: Result<PeerConnection, ConnectionManagerError>,
+        mut dial_result: Result<PeerConnection, ConnectionManagerError>,
     ) {
+        if let Ok(conn) = dial_result.as_mut() {
+            conn.set_setup_duration(dial_state.elapsed());
+        }
         let DialState { peer, reply_tx, .. } = dial_state;
 
         let node_id = peer.node_id.clone();
@@ -202,7 +205,12 @@ where
 
         match &dial_result {
             Ok(conn) => {
-                debug!(target: LOG_TARGET, "Successfully dialed peer '{}'", peer_id_short_str);
+                debug!(
+                    target: LOG_TARGET,
+                    "Successfully dialed peer '{}' in {:.2?}",
+                    peer_id_short_str,
+                    conn.setup_duration().unwrap_or_default()
+                );
                 self.notify_connection_manager(ConnectionManagerEvent::PeerConnected(conn.clone()))
                     .await
             },
@@ -70,6 +70,13 @@ pub enum ConnectionManagerError {
     PeerIdentityNoValidAddresses,
     #[error("Identity protocol failed: {0}")]
     IdentityProtocolError(#[from] IdentityProtocolError),
+    #[error(
+        "Peer minor protocol version {peer_minor_version} is below the minimum compatible version {min_minor_version}"
+    )]
+    IncompatibleVersion {
+        peer_minor_version: u32,
+        min_minor_version: u32,
+    },
     #[error("The dial was cancelled")]
     DialCancelled,
     #[error("Invalid multiaddr: {0}")]
@@ -80,6 +87,8 @@ pub enum ConnectionManagerError {
     NoiseProtocolTimeout,
     #[error("Listener oneshot cancelled")]
     ListenerOneshotCancelled,
+    #[error("Inbound connection rate limit exceeded")]
+    RateLimited,
 }
 
 impl From<yamux::ConnectionError> for ConnectionManagerError {
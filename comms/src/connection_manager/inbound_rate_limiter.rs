@@ -0,0 +1,124 @@
+// Copyright 2021, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+
+/// The number of tracked addresses above which idle addresses are pruned
+const PRUNE_THRESHOLD: usize = 1000;
+
+/// Token bucket rate limiter for inbound connections, keyed by source IP address. Each address may make up to `rate`
+/// connections in a burst, and regains one connection every `1 / rate` seconds.
+pub struct InboundRateLimiter {
+    rate: f64,
+    buckets: HashMap<IpAddr, Bucket>,
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl InboundRateLimiter {
+    /// Create a new rate limiter allowing `connections_per_second` inbound connections per source IP address
+    pub fn new(connections_per_second: u32) -> Self {
+        Self {
+            rate: f64::from(connections_per_second.max(1)),
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Returns true if a connection from `ip` is permitted, consuming a token, otherwise false
+    pub fn check(&mut self, ip: IpAddr) -> bool {
+        self.check_at(ip, Instant::now())
+    }
+
+    fn check_at(&mut self, ip: IpAddr, now: Instant) -> bool {
+        if self.buckets.len() > PRUNE_THRESHOLD {
+            self.prune(now);
+        }
+
+        let rate = self.rate;
+        let bucket = self.buckets.entry(ip).or_insert(Bucket {
+            tokens: rate,
+            last_refill: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(rate);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Remove buckets that have been idle long enough to refill completely, they are equivalent to a new bucket
+    fn prune(&mut self, now: Instant) {
+        let refill_time = Duration::from_secs(1);
+        self.buckets
+            .retain(|_, bucket| now.saturating_duration_since(bucket.last_refill) < refill_time);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn it_limits_connections_per_ip() {
+        let mut limiter = InboundRateLimiter::new(2);
+        let ip1 = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let ip2 = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let now = Instant::now();
+
+        assert!(limiter.check_at(ip1, now));
+        assert!(limiter.check_at(ip1, now));
+        assert!(!limiter.check_at(ip1, now));
+        // Other addresses have their own bucket
+        assert!(limiter.check_at(ip2, now));
+
+        // One token is regained every half a second
+        assert!(limiter.check_at(ip1, now + Duration::from_millis(500)));
+        assert!(!limiter.check_at(ip1, now + Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn it_prunes_idle_addresses() {
+        let mut limiter = InboundRateLimiter::new(1);
+        let now = Instant::now();
+        for i in 0..=PRUNE_THRESHOLD {
+            assert!(limiter.check_at(IpAddr::V4(Ipv4Addr::from(i as u32)), now));
+        }
+        assert_eq!(limiter.buckets.len(), PRUNE_THRESHOLD + 1);
+
+        let later = now + Duration::from_secs(1);
+        assert!(limiter.check_at(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), later));
+        assert_eq!(limiter.buckets.len(), 1);
+    }
+}
@@ -30,7 +30,12 @@ use super::{
 };
 use crate::{
     bounded_executor::BoundedExecutor,
-    connection_manager::{liveness::LivenessSession, types::OneshotTrigger, wire_mode::WireMode},
+    connection_manager::{
+        inbound_rate_limiter::InboundRateLimiter,
+        liveness::LivenessSession,
+        types::OneshotTrigger,
+        wire_mode::WireMode,
+    },
     multiaddr::Multiaddr,
     multiplexing::Yamux,
     noise::NoiseConfig,
@@ -79,6 +84,7 @@ pub struct PeerListener<TTransport> {
     node_identity: Arc<NodeIdentity>,
     our_supported_protocols: Vec<ProtocolId>,
     liveness_session_count: Arc<AtomicUsize>,
+    rate_limiter: Option<InboundRateLimiter>,
     on_listening: OneshotTrigger<Result<Multiaddr, ConnectionManagerError>>,
 }
 
@@ -109,6 +115,9 @@ where
             our_supported_protocols: Vec::new(),
             bounded_executor: BoundedExecutor::from_current(config.max_simultaneous_inbound_connects),
             liveness_session_count: Arc::new(AtomicUsize::new(config.liveness_max_sessions)),
+            rate_limiter: config
+                .max_inbound_connects_per_ip_per_second
+                .map(InboundRateLimiter::new),
             config,
             on_listening: OneshotTrigger::new(),
         }
@@ -151,7 +160,11 @@ where
                     futures::select! {
                         inbound_result = inbound.select_next_some() => {
                             if let Some((socket, peer_addr)) = log_if_error!(target: LOG_TARGET, inbound_result, "Inbound connection failed because '{error}'",) {
-                                self.spawn_listen_task(socket, peer_addr).await;
+                                if self.is_rate_limited(&peer_addr) {
+                                    self.reject_rate_limited(socket, peer_addr).await;
+                                } else {
+                                    self.spawn_listen_task(socket, peer_addr).await;
+                                }
                             }
                         },
                         _ = shutdown_signal => {
@@ -168,6 +181,35 @@ where
         }
     }
 
+    /// Returns true if the inbound connection rate limit has been exceeded for the source IP address of `peer_addr`.
+    /// Addresses that are not TCP/IP addresses are never rate limited.
+    fn is_rate_limited(&mut self, peer_addr: &Multiaddr) -> bool {
+        match self.rate_limiter.as_mut() {
+            Some(rate_limiter) => match multiaddr_to_socketaddr(peer_addr) {
+                Ok(socket_addr) => !rate_limiter.check(socket_addr.ip()),
+                Err(_) => false,
+            },
+            None => false,
+        }
+    }
+
+    async fn reject_rate_limited(&mut self, mut socket: TTransport::Output, peer_addr: Multiaddr) {
+        debug!(
+            target: LOG_TARGET,
+            "Dropping inbound connection from '{}' because the rate limit was exceeded", peer_addr
+        );
+        let _ = socket.close().await;
+        log_if_error!(
+            target: LOG_TARGET,
+            self.conn_man_notifier
+                .send(ConnectionManagerEvent::PeerInboundConnectFailed(
+                    ConnectionManagerError::RateLimited
+                ))
+                .await,
+            "Failed to publish event because '{error}'",
+        );
+    }
+
     async fn read_wire_format(socket: &mut TTransport::Output, time_to_first_byte: Duration) -> Option<WireMode> {
         let mut buf = [0u8; 1];
         match time::timeout(time_to_first_byte, socket.read_exact(&mut buf))
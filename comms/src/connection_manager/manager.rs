@@ -26,6 +26,7 @@ use super::{
     listener::PeerListener,
     peer_connection::PeerConnection,
     requester::ConnectionManagerRequest,
+    types::ConnectionDirection,
 };
 use crate::{
     backoff::Backoff,
@@ -45,8 +46,8 @@ use futures::{
     StreamExt,
 };
 use log::*;
-use multiaddr::Multiaddr;
-use std::{fmt, sync::Arc};
+use multiaddr::{Multiaddr, Protocol};
+use std::{collections::HashSet, fmt, sync::Arc};
 use tari_shutdown::{Shutdown, ShutdownSignal};
 use time::Duration;
 use tokio::{sync::broadcast, task, time};
@@ -72,7 +73,10 @@ impl fmt::Display for ConnectionManagerEvent {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         use ConnectionManagerEvent::*;
         match self {
-            PeerConnected(conn) => write!(f, "PeerConnected({})", conn),
+            PeerConnected(conn) => match conn.setup_duration() {
+                Some(duration) => write!(f, "PeerConnected({}, Setup = {:.2?})", conn, duration),
+                None => write!(f, "PeerConnected({})", conn),
+            },
             PeerDisconnected(node_id) => write!(f, "PeerDisconnected({})", node_id.short_str()),
             PeerConnectFailed(node_id, err) => write!(f, "PeerConnectFailed({}, {:?})", node_id.short_str(), err),
             PeerInboundConnectFailed(err) => write!(f, "PeerInboundConnectFailed({:?})", err),
@@ -96,6 +100,10 @@ pub struct ConnectionManagerConfig {
     /// The maximum number of connection tasks that will be spawned at the same time. Once this limit is reached, peers
     /// attempting to connect will have to wait for another connection attempt to complete. Default: 20
     pub max_simultaneous_inbound_connects: usize,
+    /// The maximum number of inbound connections per second that are accepted from a single source IP address. Further
+    /// connections from that address are dropped before the connection upgrade. Note that all onion service
+    /// connections appear to come from the local tor proxy. Default: None (disabled)
+    pub max_inbound_connects_per_ip_per_second: Option<u32>,
     /// Set to true to allow peers to send loopback, local-link and other addresses normally not considered valid for
     /// peer-to-peer comms. Default: false
     pub allow_test_addresses: bool,
@@ -103,6 +111,9 @@ pub struct ConnectionManagerConfig {
     pub network_info: NodeNetworkInfo,
     /// The maximum time to wait for the first byte before closing the connection. Default: 7s
     pub time_to_first_byte: Duration,
+    /// The maximum time to wait for a protocol handler to accept a new inbound substream notification. If the handler
+    /// does not accept the notification in time, the substream is dropped. Default: 5s
+    pub protocol_notification_timeout: Duration,
     /// The number of liveness check sessions to allow. Default: 0
     pub liveness_max_sessions: usize,
     /// CIDR blocks that allowlist liveness checks. Default: Localhost only (127.0.0.1/32)
@@ -123,6 +134,7 @@ impl Default for ConnectionManagerConfig {
             listener_address: "/memory/0".parse().unwrap(),
             max_dial_attempts: 3,
             max_simultaneous_inbound_connects: 20,
+            max_inbound_connects_per_ip_per_second: None,
             network_info: Default::default(),
             #[cfg(not(test))]
             allow_test_addresses: false,
@@ -131,6 +143,7 @@ impl Default for ConnectionManagerConfig {
             allow_test_addresses: true,
             liveness_max_sessions: 0,
             time_to_first_byte: Duration::from_secs(7),
+            protocol_notification_timeout: Duration::from_secs(5),
             liveness_cidr_allowlist: vec![cidr::AnyIpCidr::V4("127.0.0.1/32".parse().unwrap())],
             auxilary_tcp_listener_address: None,
         }
@@ -152,6 +165,15 @@ impl ListenerInfo {
     pub fn auxilary_bind_address(&self) -> Option<&Multiaddr> {
         self.aux_bind_address.as_ref()
     }
+
+    /// Returns the TCP port of the bound listener address, if the listener is bound to a TCP address. When listening
+    /// on port 0, this is the port assigned by the OS.
+    pub fn tcp_port(&self) -> Option<u16> {
+        self.bind_address.iter().find_map(|p| match p {
+            Protocol::Tcp(port) => Some(port),
+            _ => None,
+        })
+    }
 }
 
 pub struct ConnectionManager<TTransport, TBackoff> {
@@ -166,6 +188,9 @@ pub struct ConnectionManager<TTransport, TBackoff> {
     protocols: Protocols<Substream>,
     listener_info: Option<ListenerInfo>,
     listening_notifiers: Vec<oneshot::Sender<ListenerInfo>>,
+    active_connections: Vec<PeerConnection>,
+    pending_dials: HashSet<NodeId>,
+    protocol_notification_timeout: Duration,
     connection_manager_events_tx: broadcast::Sender<Arc<ConnectionManagerEvent>>,
     complete_trigger: Shutdown,
 }
@@ -215,6 +240,8 @@ where
             )
         });
 
+        let protocol_notification_timeout = config.protocol_notification_timeout;
+
         let dialer = Dialer::new(
             config,
             node_identity,
@@ -239,6 +266,9 @@ where
             listener_info: None,
             aux_listener,
             listening_notifiers: Vec::new(),
+            active_connections: Vec::new(),
+            pending_dials: HashSet::new(),
+            protocol_notification_timeout,
             connection_manager_events_tx,
             complete_trigger: Shutdown::new(),
         }
@@ -367,9 +397,22 @@ where
                     self.listening_notifiers.push(reply);
                 },
             },
+            GetActiveConnectionCount(reply) => {
+                let _ = reply.send(self.active_connection_count());
+            },
+            GetPendingDials(reply) => {
+                let _ = reply.send(self.pending_dials.iter().cloned().collect());
+            },
         }
     }
 
+    /// Returns the number of connections that are still connected. Connections that have been closed (including
+    /// silent disconnects, which do not publish a PeerDisconnected event) are pruned.
+    fn active_connection_count(&mut self) -> usize {
+        self.active_connections.retain(|conn| conn.is_connected());
+        self.active_connections.len()
+    }
+
     fn notify_all_ready(&mut self) {
         let info = self
             .listener_info
@@ -392,16 +435,46 @@ where
                     node_id.short_str(),
                     proto_str
                 );
-                if let Err(err) = self
+                let notify_fut = self
                     .protocols
-                    .notify(&protocol, ProtocolEvent::NewInboundSubstream(*node_id, stream))
-                    .await
-                {
-                    error!(
-                        target: LOG_TARGET,
-                        "Error sending NewSubstream notification for protocol '{}' because '{:?}'", proto_str, err
-                    );
+                    .notify(&protocol, ProtocolEvent::NewInboundSubstream(*node_id, stream));
+                // A slow or stalled protocol handler must not block the connection manager. In that case the
+                // notification (and therefore the substream) is dropped.
+                match time::timeout(self.protocol_notification_timeout, notify_fut).await {
+                    Ok(Ok(_)) => {},
+                    Ok(Err(err)) => {
+                        error!(
+                            target: LOG_TARGET,
+                            "Error sending NewSubstream notification for protocol '{}' because '{:?}'", proto_str, err
+                        );
+                    },
+                    Err(_) => {
+                        error!(
+                            target: LOG_TARGET,
+                            "Protocol '{}' did not accept the NewSubstream notification within {:.2?}. The substream \
+                             has been dropped.",
+                            proto_str,
+                            self.protocol_notification_timeout
+                        );
+                    },
+                }
+            },
+
+            PeerConnected(conn) => {
+                if conn.direction() == ConnectionDirection::Outbound {
+                    self.pending_dials.remove(conn.peer_node_id());
                 }
+                self.active_connections.retain(|c| c.is_connected());
+                self.active_connections.push(conn.clone());
+                self.publish_event(PeerConnected(conn));
+            },
+            PeerDisconnected(node_id) => {
+                self.active_connections.retain(|c| c.is_connected());
+                self.publish_event(PeerDisconnected(node_id));
+            },
+            PeerConnectFailed(node_id, err) => {
+                self.pending_dials.remove(&node_id);
+                self.publish_event(PeerConnectFailed(node_id, err));
             },
 
             event => {
@@ -429,6 +502,7 @@ where
     ) {
         match self.peer_manager.find_by_node_id(&node_id).await {
             Ok(peer) => {
+                self.pending_dials.insert(node_id);
                 self.send_dialer_request(DialerRequest::Dial(Box::new(peer), reply))
                     .await;
             },
@@ -22,6 +22,7 @@
 
 mod dial_state;
 mod dialer;
+mod inbound_rate_limiter;
 mod listener;
 
 mod common;
@@ -123,6 +123,7 @@ pub struct PeerConnection {
     address: Multiaddr,
     direction: ConnectionDirection,
     started_at: Instant,
+    setup_duration: Option<Duration>,
     substream_counter: SubstreamCounter,
 }
 
@@ -144,6 +145,7 @@ impl PeerConnection {
             address,
             direction,
             started_at: Instant::now(),
+            setup_duration: None,
             substream_counter,
         }
     }
@@ -180,6 +182,17 @@ impl PeerConnection {
         self.substream_counter.get()
     }
 
+    /// The time it took to establish this connection, measured from the start of the dial until the connection was
+    /// upgraded. This is only available for outbound connections.
+    pub fn setup_duration(&self) -> Option<Duration> {
+        self.setup_duration
+    }
+
+    pub(crate) fn set_setup_duration(&mut self, duration: Duration) -> &mut Self {
+        self.setup_duration = Some(duration);
+        self
+    }
+
     pub async fn open_substream(
         &mut self,
         protocol_id: &ProtocolId,
@@ -41,6 +41,10 @@ pub enum ConnectionManagerRequest {
     CancelDial(NodeId),
     /// Register a oneshot to get triggered when the node is listening, or has failed to listen
     NotifyListening(oneshot::Sender<ListenerInfo>),
+    /// Get the number of currently active peer connections
+    GetActiveConnectionCount(oneshot::Sender<usize>),
+    /// Get the node ids of peers that are currently being dialed
+    GetPendingDials(oneshot::Sender<Vec<NodeId>>),
 }
 
 /// Responsible for constructing requests to the ConnectionManagerService
@@ -123,4 +127,25 @@ impl ConnectionManagerRequester {
             .map_err(|_| ConnectionManagerError::SendToActorFailed)?;
         reply_rx.await.map_err(|_| ConnectionManagerError::ActorRequestCanceled)
     }
+
+    /// Returns the number of currently active (inbound and outbound) peer connections.
+    pub async fn get_active_connection_count(&mut self) -> Result<usize, ConnectionManagerError> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(ConnectionManagerRequest::GetActiveConnectionCount(reply_tx))
+            .await
+            .map_err(|_| ConnectionManagerError::SendToActorFailed)?;
+        reply_rx.await.map_err(|_| ConnectionManagerError::ActorRequestCanceled)
+    }
+
+    /// Returns the node ids of peers for which a dial is in progress. A dial is considered in progress until it
+    /// results in a `PeerConnected` or `PeerConnectFailed` event.
+    pub async fn get_pending_dials(&mut self) -> Result<Vec<NodeId>, ConnectionManagerError> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.sender
+            .send(ConnectionManagerRequest::GetPendingDials(reply_tx))
+            .await
+            .map_err(|_| ConnectionManagerError::SendToActorFailed)?;
+        reply_rx.await.map_err(|_| ConnectionManagerError::ActorRequestCanceled)
+    }
 }
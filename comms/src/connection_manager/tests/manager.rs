@@ -51,7 +51,7 @@ use futures::{
 use std::time::Duration;
 use tari_shutdown::Shutdown;
 use tari_test_utils::{collect_stream, unpack_enum};
-use tokio::{runtime::Handle, sync::broadcast};
+use tokio::{runtime::Handle, sync::broadcast, time};
 
 #[runtime::test_basic]
 async fn connect_to_nonexistent_peer() {
@@ -155,6 +155,7 @@ async fn dial_success() {
 
     let mut conn_out = conn_man1.dial_peer(node_identity2.node_id().clone()).await.unwrap();
     assert_eq!(conn_out.peer_node_id(), node_identity2.node_id());
+    assert!(conn_out.setup_duration().is_some());
     let peer2 = peer_manager1.find_by_node_id(conn_out.peer_node_id()).await.unwrap();
     assert_eq!(peer2.supported_protocols, [&IDENTITY_PROTOCOL, &TEST_PROTO]);
     assert_eq!(peer2.user_agent, "node2");
@@ -162,11 +163,15 @@ async fn dial_success() {
     let event = subscription2.next().await.unwrap().unwrap();
     unpack_enum!(ConnectionManagerEvent::PeerConnected(conn_in) = &*event);
     assert_eq!(conn_in.peer_node_id(), node_identity1.node_id());
+    assert!(conn_in.setup_duration().is_none());
 
     let peer1 = peer_manager2.find_by_node_id(node_identity1.node_id()).await.unwrap();
     assert_eq!(peer1.supported_protocols(), [&IDENTITY_PROTOCOL, &TEST_PROTO]);
     assert_eq!(peer1.user_agent, "node1");
 
+    assert_eq!(conn_man1.get_active_connection_count().await.unwrap(), 1);
+    assert_eq!(conn_man2.get_active_connection_count().await.unwrap(), 1);
+
     let err = conn_out
         .open_substream(&ProtocolId::from_static(b"/tari/invalid"))
         .await
@@ -189,6 +194,78 @@ async fn dial_success() {
     assert_eq!(buf, MSG);
 }
 
+#[runtime::test_basic]
+async fn dial_incompatible_minor_version() {
+    let shutdown = Shutdown::new();
+
+    let node_identity1 = build_node_identity(PeerFeatures::empty());
+    let node_identity2 = build_node_identity(PeerFeatures::empty());
+
+    let peer_manager1 = build_peer_manager();
+    let mut conn_man1 = build_connection_manager(
+        {
+            let mut config = TestNodeConfig {
+                node_identity: node_identity1.clone(),
+                ..Default::default()
+            };
+            config.connection_manager_config.network_info.minor_version = 1;
+            config
+                .connection_manager_config
+                .network_info
+                .min_compatible_minor_version = 1;
+            config
+        },
+        MemoryTransport,
+        peer_manager1.clone(),
+        Protocols::new(),
+        shutdown.to_signal(),
+    );
+    let mut subscription1 = conn_man1.get_event_subscription();
+    conn_man1.wait_until_listening().await.unwrap();
+
+    let peer_manager2 = build_peer_manager();
+    let mut conn_man2 = build_connection_manager(
+        TestNodeConfig {
+            node_identity: node_identity2.clone(),
+            ..Default::default()
+        },
+        MemoryTransport,
+        peer_manager2.clone(),
+        Protocols::new(),
+        shutdown.to_signal(),
+    );
+    let listener_info = conn_man2.wait_until_listening().await.unwrap();
+    let public_address2 = listener_info.bind_address().clone();
+
+    peer_manager1
+        .add_peer(Peer::new(
+            node_identity2.public_key().clone(),
+            node_identity2.node_id().clone(),
+            vec![public_address2].into(),
+            PeerFlags::empty(),
+            PeerFeatures::COMMUNICATION_CLIENT,
+            Default::default(),
+            Default::default(),
+        ))
+        .await
+        .unwrap();
+
+    let err = conn_man1.dial_peer(node_identity2.node_id().clone()).await.unwrap_err();
+    unpack_enum!(
+        ConnectionManagerError::IncompatibleVersion {
+            peer_minor_version,
+            min_minor_version
+        } = err
+    );
+    assert_eq!(peer_minor_version, 0);
+    assert_eq!(min_minor_version, 1);
+
+    let event = subscription1.next().await.unwrap().unwrap();
+    unpack_enum!(ConnectionManagerEvent::PeerConnectFailed(node_id, err) = &*event);
+    assert_eq!(&**node_id, node_identity2.node_id());
+    unpack_enum!(ConnectionManagerError::IncompatibleVersion { .. } = err);
+}
+
 #[runtime::test_basic]
 async fn dial_success_aux_tcp_listener() {
     static TEST_PROTO: ProtocolId = ProtocolId::from_static(b"/test/valid");
@@ -425,6 +502,8 @@ async fn dial_cancelled() {
     });
 
     ready_rx.await.unwrap();
+    let pending_dials = conn_man1.get_pending_dials().await.unwrap();
+    assert_eq!(pending_dials, vec![node_identity2.node_id().clone()]);
     conn_man1.cancel_dial(node_identity2.node_id().clone()).await.unwrap();
     let err = dial_result.await.unwrap().unwrap_err();
     unpack_enum!(ConnectionManagerError::DialCancelled = err);
@@ -442,3 +521,92 @@ async fn dial_cancelled() {
     assert_eq!(&**node_id, node_identity2.node_id());
     unpack_enum!(ConnectionManagerError::DialCancelled = err);
 }
+
+#[runtime::test_basic]
+async fn stalled_protocol_notification_does_not_block() {
+    static TEST_PROTO: ProtocolId = ProtocolId::from_static(b"/test/stalled");
+    let shutdown = Shutdown::new();
+
+    let node_identity1 = build_node_identity(PeerFeatures::empty());
+    let node_identity2 = build_node_identity(PeerFeatures::empty());
+
+    let peer_manager1 = build_peer_manager();
+    let mut conn_man1 = build_connection_manager(
+        TestNodeConfig {
+            node_identity: node_identity1.clone(),
+            ..Default::default()
+        },
+        MemoryTransport,
+        peer_manager1.clone(),
+        Default::default(),
+        shutdown.to_signal(),
+    );
+    conn_man1.wait_until_listening().await.unwrap();
+
+    // The receiver is never read, simulating a hung protocol handler
+    let (proto_tx2, _proto_rx2) = mpsc::channel(0);
+    let mut protocols = Protocols::new();
+    protocols.add([TEST_PROTO.clone()], proto_tx2);
+    let peer_manager2 = build_peer_manager();
+    let mut conn_man2 = build_connection_manager(
+        {
+            let mut config = TestNodeConfig {
+                node_identity: node_identity2.clone(),
+                ..Default::default()
+            };
+            config.connection_manager_config.protocol_notification_timeout = Duration::from_millis(100);
+            config
+        },
+        MemoryTransport,
+        peer_manager2.clone(),
+        protocols,
+        shutdown.to_signal(),
+    );
+    let mut subscription2 = conn_man2.get_event_subscription();
+    let listener_info = conn_man2.wait_until_listening().await.unwrap();
+    let public_address2 = listener_info.bind_address().clone();
+
+    let mut peer2 = node_identity2.to_peer();
+    peer2.addresses = vec![public_address2].into();
+    peer_manager1.add_peer(peer2).await.unwrap();
+
+    let mut conn_out = conn_man1.dial_peer(node_identity2.node_id().clone()).await.unwrap();
+    let event = subscription2.next().await.unwrap().unwrap();
+    unpack_enum!(ConnectionManagerEvent::PeerConnected(_conn_in) = &*event);
+
+    for _ in 0..3 {
+        conn_out.open_substream(&TEST_PROTO).await.unwrap();
+    }
+
+    // The connection manager event loop should still be responsive
+    let count = time::timeout(Duration::from_secs(5), conn_man2.get_active_connection_count())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(count, 1);
+}
+
+#[runtime::test_basic]
+async fn listen_on_ephemeral_tcp_port() {
+    let shutdown = Shutdown::new();
+
+    let mut conn_man = build_connection_manager(
+        {
+            let mut config = TestNodeConfig::default();
+            config.connection_manager_config.listener_address = "/ip4/127.0.0.1/tcp/0".parse().unwrap();
+            config
+        },
+        TcpTransport::new(),
+        build_peer_manager(),
+        Default::default(),
+        shutdown.to_signal(),
+    );
+
+    let listener_info = conn_man.wait_until_listening().await.unwrap();
+    let port = listener_info.tcp_port().unwrap();
+    assert_ne!(port, 0);
+    assert_eq!(
+        listener_info.bind_address().to_string(),
+        format!("/ip4/127.0.0.1/tcp/{}", port)
+    );
+}
@@ -108,6 +108,12 @@ impl PeerManager {
         self.peer_storage.read().await.all()
     }
 
+    /// Returns at most `limit` peers in `PeerId` order, starting after the peer with the `after` id. Pass the id of the
+    /// last peer returned to fetch the next page.
+    pub async fn fetch_page(&self, after: Option<PeerId>, limit: usize) -> Result<Vec<Peer>, PeerManagerError> {
+        self.peer_storage.read().await.fetch_page(after, limit)
+    }
+
     /// Adds or updates a peer and sets the last connection as successful.
     /// If the peer is marked as offline, it will be unmarked.
     pub async fn add_or_update_online_peer(
@@ -35,7 +35,10 @@ use crate::{
 use log::*;
 use multiaddr::Multiaddr;
 use rand::{rngs::OsRng, seq::SliceRandom};
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
 use tari_storage::{IterationResult, KeyValueStore};
 
 const LOG_TARGET: &str = "comms::peer_manager::peer_storage";
@@ -282,6 +285,28 @@ where DS: KeyValueStore<PeerId, Peer>
         Ok(peers)
     }
 
+    /// Return at most `limit` peers in `PeerId` order, starting after the peer with the `after` id, or from the first
+    /// peer if `after` is `None`. The id of the last peer returned is the cursor for the next page, so peers that are
+    /// added or removed between calls do not shift the following pages. No more than `limit` peers are held in memory.
+    pub fn fetch_page(&self, after: Option<PeerId>, limit: usize) -> Result<Vec<Peer>, PeerManagerError> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+        let mut page = BTreeMap::new();
+        self.peer_db.for_each_ok(|(peer_id, peer)| {
+            if after.map(|after| peer_id > after).unwrap_or(true) {
+                page.insert(peer_id, peer);
+                // Keep the peers with the lowest ids
+                if page.len() > limit {
+                    let highest = *page.keys().next_back().expect("page is not empty");
+                    page.remove(&highest);
+                }
+            }
+            IterationResult::Continue
+        })?;
+        Ok(page.into_iter().map(|(_, peer)| peer).collect())
+    }
+
     /// Compile a list of all known peers
     pub fn flood_peers(&self) -> Result<Vec<Peer>, PeerManagerError> {
         self.peer_db
@@ -775,6 +800,44 @@ mod test {
         assert!(peer_storage.find_by_public_key(&peer3.public_key).is_ok());
     }
 
+    #[test]
+    fn test_fetch_page() {
+        let mut peer_storage = PeerStorage::new_indexed(HashmapDatabase::new()).unwrap();
+        let peers = repeat_with(|| create_test_peer(PeerFeatures::COMMUNICATION_NODE, false, false))
+            .take(5)
+            .collect::<Vec<_>>();
+        for peer in &peers {
+            peer_storage.add_peer(peer.clone()).unwrap();
+        }
+
+        let mut fetched = Vec::<Peer>::new();
+        loop {
+            let page = peer_storage.fetch_page(fetched.last().map(Peer::id), 2).unwrap();
+            assert!(page.len() <= 2);
+            if page.is_empty() {
+                break;
+            }
+            fetched.extend(page);
+        }
+        assert_eq!(fetched.len(), peers.len());
+        assert!(fetched.windows(2).all(|pair| pair[0].id() < pair[1].id()));
+        for peer in &peers {
+            assert!(fetched.iter().any(|p| p.node_id == peer.node_id));
+        }
+
+        // Deleting a peer that was already returned does not cause the next page to skip a peer
+        let first_page = peer_storage.fetch_page(None, 2).unwrap();
+        peer_storage.delete_peer(&first_page[0].node_id).unwrap();
+        let next_page = peer_storage.fetch_page(Some(first_page[1].id()), 2).unwrap();
+        assert_eq!(next_page[0].node_id, fetched[2].node_id);
+
+        assert!(peer_storage
+            .fetch_page(Some(fetched.last().unwrap().id()), 2)
+            .unwrap()
+            .is_empty());
+        assert!(peer_storage.fetch_page(None, 0).unwrap().is_empty());
+    }
+
     fn create_test_peer(features: PeerFeatures, ban: bool, offline: bool) -> Peer {
         let mut rng = rand::rngs::OsRng;
         let (_sk, pk) = RistrettoPublicKey::random_keypair(&mut rng);
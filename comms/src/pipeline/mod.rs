@@ -36,7 +36,10 @@ mod builder;
 pub use builder::{Builder, Config, PipelineBuilderError};
 
 mod sink;
-pub use sink::SinkService;
+pub use sink::{InstrumentedSinkService, LossySinkService, SinkService};
+
+mod retry_sink;
+pub use retry_sink::RetrySinkService;
 
 mod inbound;
 pub(crate) use inbound::Inbound;
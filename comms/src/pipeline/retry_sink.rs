@@ -0,0 +1,190 @@
+// Copyright 2021, The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::PipelineError;
+use crate::backoff::Backoff;
+use futures::{future::BoxFuture, task::Context, FutureExt, Sink, SinkExt};
+use log::*;
+use std::{pin::Pin, sync::Arc, task::Poll};
+use tokio::time;
+use tower::Service;
+
+const LOG_TARGET: &str = "comms::pipeline::retry_sink";
+
+/// A service which forwards messages it gets to the given Sink. If sending to the sink fails with an error that is
+/// considered retryable, sending is retried up to `max_retries` times, waiting for the given backoff between attempts.
+#[derive(Clone)]
+pub struct RetrySinkService<TSink, TBackoff> {
+    sink: TSink,
+    max_retries: usize,
+    backoff: Arc<TBackoff>,
+    is_retryable: fn(&PipelineError) -> bool,
+}
+
+impl<TSink, TBackoff> RetrySinkService<TSink, TBackoff> {
+    /// Create a new RetrySinkService. By default, all errors are considered retryable.
+    pub fn new(sink: TSink, max_retries: usize, backoff: TBackoff) -> Self {
+        Self {
+            sink,
+            max_retries,
+            backoff: Arc::new(backoff),
+            is_retryable: |_| true,
+        }
+    }
+
+    /// Set the predicate used to determine if an error returned from the sink should be retried
+    pub fn with_retry_predicate(mut self, is_retryable: fn(&PipelineError) -> bool) -> Self {
+        self.is_retryable = is_retryable;
+        self
+    }
+}
+
+impl<T, TSink, TBackoff> Service<T> for RetrySinkService<TSink, TBackoff>
+where
+    T: Clone + Send + 'static,
+    TSink: Sink<T> + Unpin + Clone + Send + 'static,
+    TSink::Error: Into<PipelineError> + Send + 'static,
+    TBackoff: Backoff + Send + Sync + 'static,
+{
+    type Error = PipelineError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+    type Response = ();
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.sink).poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, item: T) -> Self::Future {
+        let mut sink = self.sink.clone();
+        let max_retries = self.max_retries;
+        let backoff = self.backoff.clone();
+        let is_retryable = self.is_retryable;
+        async move {
+            let mut attempts = 0;
+            loop {
+                attempts += 1;
+                match sink.send(item.clone()).await.map_err(Into::into) {
+                    Ok(_) => return Ok(()),
+                    Err(err) if attempts <= max_retries && is_retryable(&err) => {
+                        let delay = backoff.calculate_backoff(attempts);
+                        debug!(
+                            target: LOG_TARGET,
+                            "Sending to sink failed because '{}'. Retrying in {:.2?} (attempt {} of {})",
+                            err,
+                            delay,
+                            attempts,
+                            max_retries
+                        );
+                        time::delay_for(delay).await;
+                    },
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{backoff::ConstantBackoff, runtime};
+    use anyhow::anyhow;
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Mutex,
+        },
+        time::Duration,
+    };
+    use tower::ServiceExt;
+
+    /// A sink that fails the first `num_failures` sends
+    #[derive(Clone)]
+    struct FlakySink {
+        num_failures: usize,
+        attempts: Arc<AtomicUsize>,
+        items: Arc<Mutex<Vec<u32>>>,
+    }
+
+    impl FlakySink {
+        fn new(num_failures: usize) -> Self {
+            Self {
+                num_failures,
+                attempts: Arc::new(AtomicUsize::new(0)),
+                items: Arc::new(Mutex::new(Vec::new())),
+            }
+        }
+    }
+
+    impl Sink<u32> for FlakySink {
+        type Error = PipelineError;
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: u32) -> Result<(), Self::Error> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            if attempt <= self.num_failures {
+                return Err(anyhow!("Send failure #{}", attempt));
+            }
+            self.items.lock().unwrap().push(item);
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    #[runtime::test_basic]
+    async fn it_retries_until_success() {
+        let sink = FlakySink::new(2);
+        let service = RetrySinkService::new(sink.clone(), 3, ConstantBackoff::new(Duration::from_millis(1)));
+        service.oneshot(123u32).await.unwrap();
+        assert_eq!(sink.attempts.load(Ordering::SeqCst), 3);
+        assert_eq!(*sink.items.lock().unwrap(), vec![123]);
+    }
+
+    #[runtime::test_basic]
+    async fn it_errors_when_retries_are_exhausted() {
+        let sink = FlakySink::new(3);
+        let service = RetrySinkService::new(sink.clone(), 2, ConstantBackoff::new(Duration::from_millis(1)));
+        service.oneshot(123u32).await.unwrap_err();
+        assert_eq!(sink.attempts.load(Ordering::SeqCst), 3);
+        assert!(sink.items.lock().unwrap().is_empty());
+    }
+
+    #[runtime::test_basic]
+    async fn it_does_not_retry_non_retryable_errors() {
+        let sink = FlakySink::new(1);
+        let service = RetrySinkService::new(sink.clone(), 2, ConstantBackoff::new(Duration::from_millis(1)))
+            .with_retry_predicate(|_| false);
+        service.oneshot(123u32).await.unwrap_err();
+        assert_eq!(sink.attempts.load(Ordering::SeqCst), 1);
+    }
+}
@@ -21,8 +21,16 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use super::PipelineError;
-use futures::{future::BoxFuture, task::Context, FutureExt, Sink, SinkExt};
-use std::{pin::Pin, task::Poll};
+use futures::{future, future::BoxFuture, lock::Mutex, task::Context, FutureExt, Sink, SinkExt};
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    task::Poll,
+    time::{Duration, Instant},
+};
 use tower::Service;
 
 /// A service which forwards and messages it gets to the given Sink
@@ -54,3 +62,178 @@ where
         async move { sink.send(item).await.map_err(Into::into) }.boxed()
     }
 }
+
+/// A service which forwards messages it gets to the given Sink if the sink is ready to receive them. If the sink is
+/// not ready, or a previous message is still being flushed, the message is dropped rather than waiting for the sink.
+/// This is useful for non-critical streams (e.g. telemetry) where a slow consumer should not apply backpressure to the
+/// rest of the pipeline.
+pub struct LossySinkService<TSink> {
+    sink: Arc<Mutex<TSink>>,
+    dropped_count: Arc<AtomicUsize>,
+}
+
+impl<TSink> LossySinkService<TSink> {
+    pub fn new(sink: TSink) -> Self {
+        Self {
+            sink: Arc::new(Mutex::new(sink)),
+            dropped_count: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Returns the number of messages that have been dropped because the sink was not ready
+    pub fn dropped_count(&self) -> usize {
+        self.dropped_count.load(Ordering::Relaxed)
+    }
+}
+
+impl<TSink> Clone for LossySinkService<TSink> {
+    fn clone(&self) -> Self {
+        Self {
+            sink: self.sink.clone(),
+            dropped_count: self.dropped_count.clone(),
+        }
+    }
+}
+
+impl<T, TSink> Service<T> for LossySinkService<TSink>
+where
+    T: Send + 'static,
+    TSink: Sink<T> + Unpin + Send + 'static,
+    TSink::Error: Into<PipelineError> + Send + 'static,
+{
+    type Error = PipelineError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+    type Response = ();
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // This service is always ready, messages are dropped in `call` if the sink is not
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, item: T) -> Self::Future {
+        let sink = self.sink.clone();
+        let dropped_count = self.dropped_count.clone();
+        async move {
+            // The lock is held while a message is flushed, so the sink is busy if it cannot be acquired
+            let mut sink = match sink.try_lock() {
+                Some(sink) => sink,
+                None => {
+                    dropped_count.fetch_add(1, Ordering::Relaxed);
+                    return Ok(());
+                },
+            };
+            // Check if the sink is ready without waiting for it
+            let is_ready = future::poll_fn(|cx| Poll::Ready(Pin::new(&mut *sink).poll_ready(cx))).await;
+            match is_ready {
+                Poll::Ready(Ok(_)) => {
+                    Pin::new(&mut *sink).start_send(item).map_err(Into::into)?;
+                    sink.flush().await.map_err(Into::into)
+                },
+                Poll::Ready(Err(err)) => Err(err.into()),
+                Poll::Pending => {
+                    dropped_count.fetch_add(1, Ordering::Relaxed);
+                    Ok(())
+                },
+            }
+        }
+        .boxed()
+    }
+}
+
+/// A service which forwards messages it gets to the given Sink, and reports the time taken to send each message to the
+/// `on_sent` callback. This is useful for finding slow stages in a pipeline. When instrumentation is not required, use
+/// `SinkService` which has no additional overhead.
+#[derive(Clone)]
+pub struct InstrumentedSinkService<TSink, F> {
+    sink: TSink,
+    on_sent: Arc<F>,
+}
+
+impl<TSink, F> InstrumentedSinkService<TSink, F>
+where F: Fn(Duration) + Send + Sync + 'static
+{
+    pub fn new(sink: TSink, on_sent: F) -> Self {
+        Self {
+            sink,
+            on_sent: Arc::new(on_sent),
+        }
+    }
+}
+
+impl<T, TSink, F> Service<T> for InstrumentedSinkService<TSink, F>
+where
+    T: Send + 'static,
+    TSink: Sink<T> + Unpin + Clone + Send + 'static,
+    TSink::Error: Into<PipelineError> + Send + 'static,
+    F: Fn(Duration) + Send + Sync + 'static,
+{
+    type Error = PipelineError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+    type Response = ();
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.sink).poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, item: T) -> Self::Future {
+        let mut sink = self.sink.clone();
+        let on_sent = self.on_sent.clone();
+        let timer = Instant::now();
+        async move {
+            sink.send(item).await.map_err(Into::into)?;
+            (*on_sent)(timer.elapsed());
+            Ok(())
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runtime;
+    use futures::{channel::mpsc, StreamExt};
+    use std::sync::Mutex;
+    use tower::ServiceExt;
+
+    #[runtime::test_basic]
+    async fn lossy_sink_drops_when_not_ready() {
+        // A channel with a buffer of 0 only has capacity for a single message per sender, and flushing waits until
+        // that message has been read
+        let (tx, mut rx) = mpsc::channel::<u32>(0);
+        let mut service = LossySinkService::new(tx);
+
+        let mut first = service.call(0);
+        assert!(futures::poll!(&mut first).is_pending());
+
+        // Messages are dropped while the first message is being flushed
+        service.call(1).await.unwrap();
+        service.call(2).await.unwrap();
+        assert_eq!(service.dropped_count(), 2);
+        assert_eq!(rx.next().await.unwrap(), 0);
+        first.await.unwrap();
+
+        // The sink is ready again once the message has been read
+        let (result, msg) = future::join(service.call(3), rx.next()).await;
+        result.unwrap();
+        assert_eq!(msg.unwrap(), 3);
+        assert_eq!(service.dropped_count(), 2);
+    }
+
+    #[runtime::test_basic]
+    async fn instrumented_sink_reports_send_latency() {
+        let (tx, mut rx) = mpsc::channel(10);
+        let latencies = Arc::new(Mutex::new(Vec::new()));
+        let service = InstrumentedSinkService::new(tx, {
+            let latencies = latencies.clone();
+            move |latency| latencies.lock().unwrap().push(latency)
+        });
+
+        for i in 0..3u32 {
+            service.clone().oneshot(i).await.unwrap();
+        }
+
+        assert_eq!(latencies.lock().unwrap().len(), 3);
+        assert_eq!(rx.next().await.unwrap(), 0);
+    }
+}
@@ -29,6 +29,10 @@ pub struct NodeNetworkInfo {
     /// Minor protocol version. A version number that represents backward-compatible protocol changes. A peer SHOULD
     /// NOT reject the connection if a remote peer advertises a different minor version number.
     pub minor_version: u32,
+    /// The lowest minor protocol version that a peer must advertise for a connection to be established. Peers with the
+    /// same major version but a lower minor version are rejected, which allows upgrades to be enforced.
+    /// Default: 0 (any minor version is accepted)
+    pub min_compatible_minor_version: u32,
     /// The byte that MUST be sent (outbound connections) or MUST be received (inbound connections) for a connection to
     /// be established. This byte cannot be 0x46 (E) because that is reserved for liveness.
     /// Default: 0x00
@@ -145,6 +145,12 @@ impl ConnectionManagerMock {
             },
             CancelDial(_) => {},
             NotifyListening(_reply_tx) => {},
+            GetActiveConnectionCount(reply_tx) => {
+                let _ = reply_tx.send(self.state.active_conns.lock().await.len());
+            },
+            GetPendingDials(reply_tx) => {
+                let _ = reply_tx.send(Vec::new());
+            },
         }
     }
 }